@@ -0,0 +1,61 @@
+//! Micro-benchmarks for the inner loops every algorithm runs: grading, filtering candidates,
+//! and bucketing them by pattern.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use roget::{Bitset, Correctness, Dictionary, Guess, Matrix, Word};
+
+fn answers(dictionary: &Dictionary) -> Vec<Word> {
+    dictionary.answers().iter().map(|&(w, _)| w).collect()
+}
+
+fn compute(c: &mut Criterion) {
+    let dictionary = Dictionary::official();
+    let answers = answers(&dictionary);
+    let guess = Word::new("crane");
+
+    c.bench_function("compute one guess against every answer", |b| {
+        let mut out = Vec::with_capacity(answers.len());
+        b.iter(|| {
+            out.clear();
+            Correctness::compute_packed(black_box(guess), black_box(&answers), &mut out);
+            black_box(&out);
+        })
+    });
+}
+
+fn filter(c: &mut Criterion) {
+    let dictionary = Dictionary::official();
+    let answers = answers(&dictionary);
+    let guess = Guess {
+        word: Word::new("crane"),
+        mask: [
+            Correctness::Wrong,
+            Correctness::Misplaced,
+            Correctness::Wrong,
+            Correctness::Wrong,
+            Correctness::Correct,
+        ],
+    };
+
+    c.bench_function("filter candidates by one guess", |b| {
+        b.iter(|| {
+            let mut remaining = Bitset::full(answers.len());
+            remaining.retain(|i| guess.matches(answers[i]));
+            black_box(remaining.count())
+        })
+    });
+}
+
+fn partition(c: &mut Criterion) {
+    let dictionary = Dictionary::official();
+    let matrix = Matrix::compute(&dictionary);
+    let guess = matrix.guess_index(Word::new("crane")).unwrap();
+    let answers = Bitset::full(matrix.answers().len());
+
+    c.bench_function("bucket every answer by pattern", |b| {
+        b.iter(|| black_box(matrix.partition(black_box(guess), answers.iter())))
+    });
+}
+
+criterion_group!(benches, compute, filter, partition);
+criterion_main!(benches);
@@ -0,0 +1,32 @@
+//! Macro-benchmarks: whole games, one group per algorithm, over the first 100 official
+//! answers.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use roget::algorithms::{Naive, Normalization};
+use roget::{Dictionary, Wordle};
+
+const GAMES: usize = 100;
+
+fn naive(c: &mut Criterion) {
+    let dictionary = Dictionary::official();
+    let wordle = Wordle::with_dictionary(dictionary.clone());
+    let priors = Normalization::default().weights(dictionary.answers());
+    let answers: Vec<&str> = roget::dictionary::ANSWERS
+        .split_whitespace()
+        .take(GAMES)
+        .collect();
+
+    let mut group = c.benchmark_group("games");
+    group.sample_size(10);
+    group.bench_function("naive", |b| {
+        b.iter(|| {
+            for answer in &answers {
+                black_box(wordle.play(answer, Naive::from_priors(&priors)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, naive);
+criterion_main!(benches);
@@ -0,0 +1,117 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Bytes per word in `dictionary.bin`: five Latin-1 letters, then the count as a
+/// little-endian `u64`. Must match `RECORD` in `src/dictionary.rs`.
+const RECORD: usize = 13;
+
+/// Fixed-size records, for the fastest decoding.
+fn records(words: &[([u8; 5], u64)]) -> Vec<u8> {
+    let mut blob = Vec::with_capacity(words.len() * RECORD);
+    for (word, count) in words {
+        blob.extend_from_slice(word);
+        blob.extend_from_slice(&count.to_le_bytes());
+    }
+    blob
+}
+
+/// Each word as how many leading letters it shares with the one before, then the rest of
+/// its letters, then its count as an LEB128 varint. Must match `from_compressed` in
+/// `src/dictionary.rs`.
+fn compressed(words: &[([u8; 5], u64)]) -> Vec<u8> {
+    let mut blob = Vec::new();
+    let mut previous = [0; 5];
+    for &(word, count) in words {
+        let shared = word
+            .iter()
+            .zip(&previous)
+            .take_while(|(a, b)| a == b)
+            .count();
+        blob.push(shared as u8);
+        blob.extend_from_slice(&word[shared..]);
+        let mut count = count;
+        loop {
+            let byte = (count & 0x7f) as u8;
+            count >>= 7;
+            if count == 0 {
+                blob.push(byte);
+                break;
+            }
+            blob.push(byte | 0x80);
+        }
+        previous = word;
+    }
+    blob
+}
+
+/// The word `algorithms::Naive` opens with on the embedded dictionary: the most common one,
+/// with ties going to the alphabetically first. `words` must be sorted.
+fn opener(words: &[([u8; 5], u64)]) -> [u8; 5] {
+    let mut best = words[0];
+    for &(word, count) in words {
+        if count > best.1 {
+            best = (word, count);
+        }
+    }
+    best.0
+}
+
+/// The gRPC service for `roget grpc`, compiled with a pure-Rust protobuf compiler so
+/// building does not need `protoc`.
+#[cfg(feature = "grpc")]
+fn grpc() {
+    println!("cargo:rerun-if-changed=proto/roget.proto");
+    let descriptors =
+        protox::compile(["proto/roget.proto"], ["proto"]).expect("proto/roget.proto is valid");
+    tonic_build::configure()
+        .build_client(false)
+        .compile_fds(descriptors)
+        .expect("generating the gRPC service");
+}
+
+fn main() {
+    #[cfg(feature = "grpc")]
+    grpc();
+
+    if env::var_os("CARGO_FEATURE_EMBEDDED_DICTIONARY").is_none() {
+        return;
+    }
+    println!("cargo:rerun-if-changed=dictionary.txt");
+    let text = fs::read_to_string("dictionary.txt").expect("dictionary.txt is readable");
+
+    let mut words: Vec<([u8; 5], u64)> = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let (word, count) = line
+                .trim()
+                .split_once(' ')
+                .unwrap_or_else(|| panic!("dictionary.txt:{}: expected `word count`", i + 1));
+            let letters: Vec<u8> = word
+                .chars()
+                .map(|c| u8::try_from(c).expect("dictionary words are Latin-1"))
+                .collect();
+            let word = letters
+                .try_into()
+                .unwrap_or_else(|_| panic!("dictionary.txt:{}: {word} is not five letters", i + 1));
+            let count = count
+                .trim()
+                .parse()
+                .unwrap_or_else(|_| panic!("dictionary.txt:{}: bad count", i + 1));
+            (word, count)
+        })
+        .collect();
+    words.sort();
+    words.dedup_by_key(|&mut (word, _)| word);
+
+    let blob = if env::var_os("CARGO_FEATURE_COMPRESSED_DICTIONARY").is_some() {
+        compressed(&words)
+    } else {
+        records(&words)
+    };
+    let out = PathBuf::from(env::var("OUT_DIR").expect("cargo sets OUT_DIR"));
+    fs::write(out.join("dictionary.bin"), blob).expect("OUT_DIR is writable");
+    fs::write(out.join("opener.bin"), opener(&words)).expect("OUT_DIR is writable");
+}
@@ -0,0 +1,4 @@
+//! `roget`'s C API, built as `libroget_ffi.so` (`.dylib` on macOS, `roget_ffi.dll` on
+//! Windows) and `libroget_ffi.a`; see [`roget::ffi`] for how to use it.
+
+pub use roget::ffi::*;
@@ -0,0 +1,77 @@
+//! Compiles a C program against `include/roget.h` and links it to the shared library, as a
+//! C user would.
+#![cfg(unix)]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const GAME: &str = r#"
+#include <stdio.h>
+#include <string.h>
+
+#include "roget.h"
+
+int main(void) {
+    char first[11], second[11];
+    RogetSolver *solver = roget_solver_new();
+    if (solver == NULL) return 1;
+
+    if (roget_solver_next_guess(solver, first, sizeof first) != ROGET_STATUS_OK) return 2;
+    if (strlen(first) != 5) return 3;
+    if (roget_solver_next_guess(solver, first, 3) != ROGET_STATUS_BUFFER_TOO_SMALL) return 4;
+    if (roget_solver_next_guess(solver, first, sizeof first) != ROGET_STATUS_OK) return 5;
+
+    if (roget_solver_apply_feedback(solver, first, "xxxxx") != ROGET_STATUS_OK) return 6;
+    if (roget_solver_apply_feedback(solver, first, "xxxx") != ROGET_STATUS_INVALID_MASK) return 7;
+    if (roget_solver_next_guess(solver, second, sizeof second) != ROGET_STATUS_OK) return 8;
+    if (strcmp(first, second) == 0) return 9;
+
+    roget_solver_free(solver);
+    if (roget_solver_next_guess(NULL, first, sizeof first) != ROGET_STATUS_NULL_POINTER) return 10;
+    printf("%s %s\n", first, second);
+    return 0;
+}
+"#;
+
+/// Where cargo put the library: next to this test, in `target/<profile>/deps`.
+fn library_dir() -> PathBuf {
+    let exe = std::env::current_exe().expect("the test knows where it is");
+    exe.parent()
+        .expect("tests are in a directory")
+        .to_path_buf()
+}
+
+#[test]
+fn links_against_the_header() {
+    let include = Path::new(env!("CARGO_MANIFEST_DIR")).join("../include");
+    let lib = library_dir();
+    let out = std::env::temp_dir().join(format!("roget-ffi-{}", std::process::id()));
+    std::fs::create_dir_all(&out).unwrap();
+    let source = out.join("game.c");
+    let program = out.join("game");
+    std::fs::write(&source, GAME).unwrap();
+
+    let cc = std::env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(cc)
+        .arg("-std=c99")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg(&source)
+        .arg("-I")
+        .arg(&include)
+        .arg("-L")
+        .arg(&lib)
+        .arg(format!("-Wl,-rpath,{}", lib.display()))
+        .arg("-lroget_ffi")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .expect("a C compiler is installed");
+    assert!(status.success(), "compiling {}", source.display());
+
+    let output = Command::new(&program).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}: {output:?}", program.display());
+    assert_eq!(stdout.split_whitespace().count(), 2, "{stdout}");
+    std::fs::remove_dir_all(&out).unwrap();
+}
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use roget::{Correctness, Guess, Word};
+
+// Words come from text, so anything that is not five Latin-1 letters must be turned away by
+// parsing rather than reach grading.
+fuzz_target!(|s: &str| {
+    let words: Vec<Word> = s.split_whitespace().filter_map(|w| w.parse().ok()).collect();
+    let mut packed = Vec::new();
+    for pair in words.windows(2) {
+        let (answer, guess) = (pair[0], pair[1]);
+        packed.clear();
+        Correctness::compute_packed(guess, &[answer], &mut packed);
+        let mask = Correctness::unpack(packed[0]);
+        assert!(Guess { word: guess, mask }.matches(answer));
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use roget::Dictionary;
+
+fuzz_target!(|s: &str| {
+    let Ok(dictionary) = Dictionary::parse(s) else {
+        return;
+    };
+    for (i, &(word, _)) in dictionary.words().iter().enumerate() {
+        assert!(dictionary.contains(word));
+        assert_eq!(dictionary.index_of(word), Some(i));
+        assert!(dictionary.percentile(word).is_some());
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use roget::{parse_mask, Correctness};
+
+fuzz_target!(|s: &str| {
+    if let Ok(mask) = parse_mask(s) {
+        assert_eq!(Correctness::unpack(Correctness::pack(mask)), mask);
+    }
+});
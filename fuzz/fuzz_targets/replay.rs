@@ -0,0 +1,23 @@
+#![no_main]
+
+use std::sync::Arc;
+
+use libfuzzer_sys::fuzz_target;
+use roget::algorithms::{Naive, Normalization};
+use roget::{Dictionary, Word, Wordle};
+
+// Replay a game from untrusted text: a word list whose first word is the answer and whose
+// other words are the opening guesses.
+fuzz_target!(|s: &str| {
+    let Ok(dictionary) = Dictionary::parse(s) else {
+        return;
+    };
+    let words: Vec<Word> = dictionary.words().iter().map(|&(w, _)| w).collect();
+    let Some((&answer, seed)) = words.split_first() else {
+        return;
+    };
+    let priors = Normalization::default().weights(dictionary.answers());
+    let wordle = Wordle::with_dictionary(Arc::new(dictionary));
+    let result = wordle.play_seeded(answer, Naive::from_priors(&priors), seed);
+    assert!(result.guesses.iter().all(|guess| guess.matches(answer)));
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use roget::SharedGame;
+
+fuzz_target!(|s: &str| {
+    let _ = s.parse::<SharedGame>();
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use roget::DecisionTree;
+
+fuzz_target!(|s: &str| {
+    if let Ok(tree) = s.parse::<DecisionTree>() {
+        // whatever parses must also render
+        let _ = tree.dot().to_string();
+    }
+});
@@ -0,0 +1,55 @@
+#ifndef ROGET_H
+#define ROGET_H
+
+/* Generated by cbindgen from src/ffi.rs; do not edit by hand. */
+
+#include <stddef.h>
+
+// What a call did, as every function returns.
+typedef enum RogetStatus {
+  ROGET_STATUS_OK = 0,
+  // A pointer argument was null.
+  ROGET_STATUS_NULL_POINTER,
+  // The word is not valid UTF-8 or not a five-letter word.
+  ROGET_STATUS_INVALID_WORD,
+  // The mask is not five of `g`, `y`, and `x`.
+  ROGET_STATUS_INVALID_MASK,
+  // The output buffer cannot hold the word and its terminating NUL.
+  ROGET_STATUS_BUFFER_TOO_SMALL,
+  // No answer is consistent with the feedback given so far.
+  ROGET_STATUS_NO_CANDIDATES,
+} RogetStatus;
+
+// One game in progress against the embedded dictionary and its official answers.
+typedef struct RogetSolver RogetSolver;
+
+// Start a game. Release the solver with [`roget_solver_free`].
+struct RogetSolver *roget_solver_new(void);
+
+// Write the word to play next into `out` as NUL-terminated UTF-8. Eleven bytes is always
+// enough, since each letter takes at most two.
+//
+// # Safety
+//
+// `solver` must come from [`roget_solver_new`], and `out` must be valid for writes of `len`
+// bytes.
+enum RogetStatus roget_solver_next_guess(const struct RogetSolver *solver, char *out, size_t len);
+
+// Record that `word` was played and the game showed `mask`, such as `gxxyy`, for it.
+//
+// # Safety
+//
+// `solver` must come from [`roget_solver_new`], and `word` and `mask` must be
+// NUL-terminated strings.
+enum RogetStatus roget_solver_apply_feedback(struct RogetSolver *solver,
+                                             const char *word,
+                                             const char *mask);
+
+// Release a solver. Passing null does nothing.
+//
+// # Safety
+//
+// `solver` must come from [`roget_solver_new`] and not be used again afterwards.
+void roget_solver_free(struct RogetSolver *solver);
+
+#endif  /* ROGET_H */
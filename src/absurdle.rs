@@ -0,0 +1,93 @@
+use alloc::vec::Vec;
+
+use crate::collections::Map;
+use crate::{Correctness, Oracle, Word};
+
+/// An adversarial host in the style of Absurdle: it never commits to an answer, and grades
+/// each guess with whichever mask leaves the most answers possible.
+///
+/// Ties go to the mask that gives away the least, fewest greens and then fewest yellows, so
+/// the host is deterministic. A guesser only wins once a single answer is left and it guesses
+/// it, which makes this a worst-case test for any strategy.
+#[derive(Debug, Clone)]
+pub struct Absurdle {
+    remaining: Vec<Word>,
+}
+
+impl Absurdle {
+    /// Start with every one of `answers` still possible.
+    pub fn new(answers: impl IntoIterator<Item = Word>) -> Self {
+        Self {
+            remaining: answers.into_iter().collect(),
+        }
+    }
+
+    /// The answers consistent with every mask given so far.
+    pub fn remaining(&self) -> &[Word] {
+        &self.remaining
+    }
+}
+
+impl Oracle for Absurdle {
+    fn grade(&mut self, guess: Word) -> [Correctness; 5] {
+        let mut buckets: Map<[Correctness; 5], Vec<Word>> = Map::default();
+        for &answer in &self.remaining {
+            buckets
+                .entry(Correctness::compute(answer, guess))
+                .or_default()
+                .push(answer);
+        }
+
+        let revealed = |mask: &[Correctness; 5]| {
+            let count = |c| mask.iter().filter(|&&m| m == c).count();
+            (count(Correctness::Correct), count(Correctness::Misplaced))
+        };
+        let Some((mask, answers)) = buckets.into_iter().max_by(|(a, x), (b, y)| {
+            x.len()
+                .cmp(&y.len())
+                .then_with(|| revealed(b).cmp(&revealed(a)))
+                .then_with(|| Correctness::pack(*b).cmp(&Correctness::pack(*a)))
+        }) else {
+            // nothing was possible to begin with, so nothing can be right
+            return [Correctness::Wrong; 5];
+        };
+        self.remaining = answers;
+        mask
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::Absurdle;
+    use crate::{Correctness, Oracle, Word};
+
+    fn words(words: &[&str]) -> Vec<Word> {
+        words.iter().map(|&w| Word::new(w)).collect()
+    }
+
+    #[test]
+    fn keeps_the_largest_bucket() {
+        let mut host = Absurdle::new(words(&["crane", "crate", "grate", "slosh"]));
+        // three answers share an `a` in the middle, only one does not
+        let mask = host.grade(Word::new("plaid"));
+        assert_eq!(mask[2], Correctness::Correct);
+        assert_eq!(host.remaining(), words(&["crane", "crate", "grate"]));
+    }
+
+    #[test]
+    fn ties_reveal_the_least() {
+        let mut host = Absurdle::new(words(&["crane", "slosh"]));
+        assert_eq!(host.grade(Word::new("crane")), [Correctness::Wrong; 5]);
+        assert_eq!(host.remaining(), words(&["slosh"]));
+    }
+
+    #[test]
+    fn only_a_lone_answer_can_be_won() {
+        let mut host = Absurdle::new(words(&["crane", "trace"]));
+        assert_ne!(host.grade(Word::new("crane")), [Correctness::Correct; 5]);
+        assert_eq!(host.remaining(), words(&["trace"]));
+        assert_eq!(host.grade(Word::new("trace")), [Correctness::Correct; 5]);
+    }
+}
@@ -1,2 +1,19 @@
+mod candidates;
+mod evader;
+mod mixed;
 mod naive;
+mod normalization;
+mod precomputed_tree;
+mod random;
+pub use candidates::Candidates;
+pub use evader::Evader;
+pub use mixed::Mixed;
 pub use naive::Naive;
+pub use normalization::Normalization;
+pub use precomputed_tree::PrecomputedTree;
+pub use random::Random;
+
+/// [`Naive`]'s first guess on the embedded dictionary, precomputed when the crate is built.
+#[cfg(feature = "embedded-dictionary")]
+pub const NAIVE_OPENER: crate::Word =
+    crate::Word::from_latin1(*include_bytes!(concat!(env!("OUT_DIR"), "/opener.bin")));
@@ -0,0 +1,144 @@
+use alloc::vec::Vec;
+
+use crate::{Bitset, Correctness, Guess, Word};
+
+/// The answers still possible, laid out for scoring loops.
+///
+/// Words and their prior weights sit in two contiguous arrays sorted most likely first,
+/// with ties in alphabetical order, so walking the candidates reads memory front to back
+/// and the most likely candidate is always the first one. A loop that only cares about most
+/// of the probability mass can stop early with [`Candidates::covering`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Candidates {
+    words: Vec<Word>,
+    weights: Vec<f64>,
+    /// Indices into `words` of the answers still possible.
+    remaining: Bitset,
+}
+
+impl Candidates {
+    /// Every word in `priors` is a candidate to begin with.
+    pub fn from_priors(priors: &[(Word, f64)]) -> Self {
+        let mut sorted = priors.to_vec();
+        sorted.sort_by(|(a, x), (b, y)| y.total_cmp(x).then(a.cmp(b)));
+        Self {
+            words: sorted.iter().map(|&(word, _)| word).collect(),
+            weights: sorted.iter().map(|&(_, weight)| weight).collect(),
+            remaining: Bitset::full(sorted.len()),
+        }
+    }
+
+    /// Drop the candidates that would not have given `guess` its mask.
+    pub fn filter(&mut self, guess: &Guess) {
+        let words = &self.words;
+        self.remaining
+            .retain(|i| Correctness::compute(words[i], guess.word) == guess.mask);
+    }
+
+    /// The remaining candidates with their weights, most likely first.
+    pub fn iter(&self) -> impl Iterator<Item = (Word, f64)> + '_ {
+        self.remaining
+            .iter()
+            .map(|i| (self.words[i], self.weights[i]))
+    }
+
+    /// The most likely candidates whose weights add up to at least `share` (between 0 and
+    /// 1) of the remaining total, most likely first.
+    pub fn covering(&self, share: f64) -> impl Iterator<Item = (Word, f64)> + '_ {
+        let target = share * self.total();
+        let mut covered = 0.0;
+        self.iter().take_while(move |&(_, weight)| {
+            let more = covered < target;
+            covered += weight;
+            more
+        })
+    }
+
+    /// The most likely remaining candidate.
+    pub fn best(&self) -> Option<Word> {
+        self.iter().next().map(|(word, _)| word)
+    }
+
+    /// The least likely remaining candidate.
+    pub fn least_likely(&self) -> Option<Word> {
+        self.iter().last().map(|(word, _)| word)
+    }
+
+    /// The weight of every remaining candidate added up.
+    pub fn total(&self) -> f64 {
+        self.iter().map(|(_, weight)| weight).sum()
+    }
+
+    pub fn len(&self) -> usize {
+        self.remaining.count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.remaining.is_empty()
+    }
+
+    /// The set of remaining candidates, by their index in likelihood order.
+    pub fn remaining(&self) -> &Bitset {
+        &self.remaining
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::Candidates;
+    use crate::{Correctness, Guess, Word};
+
+    fn candidates() -> Candidates {
+        Candidates::from_priors(&[
+            (Word::new("slate"), 1.0),
+            (Word::new("crane"), 5.0),
+            (Word::new("trace"), 3.0),
+            (Word::new("react"), 1.0),
+        ])
+    }
+
+    fn words(iter: impl Iterator<Item = (Word, f64)>) -> Vec<Word> {
+        iter.map(|(w, _)| w).collect()
+    }
+
+    #[test]
+    fn most_likely_first() {
+        let candidates = candidates();
+        assert_eq!(
+            words(candidates.iter()),
+            ["crane", "trace", "react", "slate"]
+        );
+        assert_eq!(candidates.best(), Some(Word::new("crane")));
+        assert_eq!(candidates.least_likely(), Some(Word::new("slate")));
+    }
+
+    #[test]
+    fn filter() {
+        let mut gray = candidates();
+        gray.filter(&Guess {
+            word: Word::new("crane"),
+            mask: [Correctness::Wrong; 5],
+        });
+        assert!(gray.is_empty());
+
+        let mut trace = candidates();
+        trace.filter(&Guess {
+            word: Word::new("slate"),
+            mask: Correctness::compute(Word::new("trace"), Word::new("slate")),
+        });
+        assert_eq!(words(trace.iter()), ["trace"]);
+    }
+
+    #[test]
+    fn covering() {
+        let candidates = candidates();
+        // crane alone is half of the weight
+        assert_eq!(words(candidates.covering(0.5)), ["crane"]);
+        assert_eq!(words(candidates.covering(0.6)), ["crane", "trace"]);
+        assert_eq!(candidates.covering(1.0).count(), 4);
+        assert_eq!(candidates.covering(0.0).count(), 0);
+    }
+}
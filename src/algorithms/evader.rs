@@ -0,0 +1,68 @@
+use alloc::vec::Vec;
+
+use super::{Candidates, Normalization};
+use crate::{Dictionary, Guess, Guesser, Word};
+
+/// A guesser for [`AntiWordle`](crate::AntiWordle), which tries not to find the answer.
+///
+/// It only ever plays a word the answer could still be, as the rules require, and of those
+/// the least likely one: the reverse of what [`Naive`](super::Naive) plays.
+pub struct Evader {
+    candidates: Candidates,
+}
+
+impl Evader {
+    pub fn new(dictionary: &Dictionary) -> Self {
+        Self::from_priors(&Normalization::default().weights(dictionary.answers()))
+    }
+
+    /// Start from prior weights computed once, as by [`Normalization::weights`].
+    pub fn from_priors(priors: &[(Word, f64)]) -> Self {
+        Self {
+            candidates: Candidates::from_priors(priors),
+        }
+    }
+}
+
+impl Guesser for Evader {
+    fn guess(&mut self, history: &[Guess]) -> Word {
+        if let Some(last) = history.last() {
+            self.candidates.filter(last);
+        }
+        let guess = self
+            .candidates
+            .least_likely()
+            .expect("the answer is always a candidate");
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            %guess,
+            candidates = self.candidates.len(),
+            "chose the least likely candidate"
+        );
+        guess
+    }
+
+    /// Every candidate, from least to most likely.
+    fn ranked(&mut self, history: &[Guess]) -> Vec<Word> {
+        self.guess(history);
+        let mut ranked: Vec<Word> = self.candidates.iter().map(|(word, _)| word).collect();
+        ranked.reverse();
+        ranked
+    }
+}
+
+#[cfg(all(test, feature = "embedded-dictionary"))]
+mod tests {
+    use super::Evader;
+    use crate::{Dictionary, Word, Wordle};
+
+    #[test]
+    fn follows_the_rules() {
+        let wordle = Wordle::new();
+        let evader = Evader::new(&Dictionary::embedded());
+        let result = wordle.play_anti(Word::new("crane"), evader, usize::MAX);
+        // it only stops once nothing but the answer is left
+        assert!(result.score.is_some_and(|n| n > 1));
+        assert_eq!(result.guesses.last().unwrap().word, "crane");
+    }
+}
@@ -0,0 +1,184 @@
+use alloc::vec::Vec;
+
+use super::{Candidates, Normalization};
+use crate::rng::SplitMix64;
+use crate::{Dictionary, Guess, Guesser, Word};
+
+/// Plays one of the few most likely candidates at random rather than always the most likely,
+/// so repeated runs explore different lines of play while scoring close to [`Naive`].
+///
+/// Each turn it picks among the [`top`](Mixed::top) candidates, with chances following their
+/// prior weights sharpened or flattened by the [`temperature`](Mixed::temperature): at 0 it
+/// always plays the most likely candidate, at 1 in proportion to the weights, and the higher
+/// it goes the closer to uniform. As with [`Random`], the same seed always plays the same
+/// game against the same answer.
+///
+/// [`Naive`]: super::Naive
+/// [`Random`]: super::Random
+///
+/// ```
+/// use roget::algorithms::Mixed;
+/// use roget::{Dictionary, Wordle};
+///
+/// let wordle = Wordle::new();
+/// let dictionary = Dictionary::embedded();
+/// let games: Vec<_> = (0..4)
+///     .map(|seed| wordle.play_game("crane", Mixed::new(&dictionary, seed).top(3)))
+///     .collect();
+/// assert!(games.iter().all(|game| game.score.is_some()));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mixed {
+    candidates: Candidates,
+    top: usize,
+    temperature: f64,
+    rng: SplitMix64,
+}
+
+impl Mixed {
+    /// Pick among the possible answers in `dictionary`, starting from `seed`.
+    pub fn new(dictionary: &Dictionary, seed: u64) -> Self {
+        Self::from_priors(
+            &Normalization::default().weights(dictionary.answers()),
+            seed,
+        )
+    }
+
+    /// Start from prior weights computed once, as by [`Normalization::weights`].
+    pub fn from_priors(priors: &[(Word, f64)], seed: u64) -> Self {
+        Self {
+            candidates: Candidates::from_priors(priors),
+            top: 5,
+            temperature: 1.0,
+            rng: SplitMix64::new(seed),
+        }
+    }
+
+    /// Only pick among the `top` most likely candidates; 5 by default. At least one always
+    /// is.
+    pub fn top(mut self, top: usize) -> Self {
+        self.top = top.max(1);
+        self
+    }
+
+    /// How far from the weights the chances stray: 0 always picks the most likely, 1 (the
+    /// default) follows the weights, and higher evens the chances out.
+    pub fn temperature(mut self, temperature: f64) -> Self {
+        self.temperature = temperature.max(0.0);
+        self
+    }
+
+    /// The chance of each of the top candidates, not yet scaled to add up to 1.
+    fn chances(&self) -> Vec<(Word, f64)> {
+        let top: Vec<(Word, f64)> = self.candidates.iter().take(self.top).collect();
+        let Some(&(_, best)) = top.first() else {
+            return top;
+        };
+        top.into_iter()
+            .map(|(word, weight)| {
+                let chance = match self.temperature > 0.0 {
+                    // relative to the best, so small weights do not underflow
+                    true => libm::pow(weight / best, 1.0 / self.temperature),
+                    false => f64::from(u8::from(weight == best)),
+                };
+                (word, chance)
+            })
+            .collect()
+    }
+}
+
+impl Guesser for Mixed {
+    fn guess(&mut self, history: &[Guess]) -> Word {
+        if let Some(last) = history.last() {
+            self.candidates.filter(last);
+        }
+        let chances = self.chances();
+        let total: f64 = chances.iter().map(|&(_, chance)| chance).sum();
+        let mut target = self.rng.unit() * total;
+        let guess = chances
+            .iter()
+            .find(|&&(_, chance)| {
+                target -= chance;
+                target < 0.0
+            })
+            // rounding can leave a sliver past the last word
+            .or_else(|| chances.first())
+            .map(|&(word, _)| word)
+            .expect("the answer is always a candidate");
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            %guess,
+            candidates = self.candidates.len(),
+            top = chances.len(),
+            "chose among the top candidates"
+        );
+        guess
+    }
+
+    /// The guess, then every other candidate from most to least likely.
+    fn ranked(&mut self, history: &[Guess]) -> Vec<Word> {
+        let guess = self.guess(history);
+        let others = self.candidates.iter().map(|(word, _)| word);
+        core::iter::once(guess)
+            .chain(others.filter(|&word| word != guess))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::Mixed;
+    use crate::{Guesser, Word};
+
+    fn priors() -> [(Word, f64); 4] {
+        [
+            (Word::new("crane"), 8.0),
+            (Word::new("slate"), 4.0),
+            (Word::new("trace"), 2.0),
+            (Word::new("lousy"), 1.0),
+        ]
+    }
+
+    fn openers(top: usize, temperature: f64) -> Vec<Word> {
+        (0..200)
+            .map(|seed| {
+                Mixed::from_priors(&priors(), seed)
+                    .top(top)
+                    .temperature(temperature)
+                    .guess(&[])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn stays_in_the_top() {
+        let openers = openers(2, 1.0);
+        assert!(openers.iter().all(|&w| w == "crane" || w == "slate"));
+        assert!(openers.contains(&Word::new("slate")));
+        // crane is twice as likely as slate
+        let cranes = openers.iter().filter(|&&w| w == "crane").count();
+        assert!((110..160).contains(&cranes), "{cranes}");
+    }
+
+    #[test]
+    fn temperature() {
+        assert!(openers(4, 0.0).iter().all(|&w| w == "crane"));
+        assert!(openers(1, 100.0).iter().all(|&w| w == "crane"));
+        let count = |openers: &[Word]| openers.iter().filter(|&&w| w == "lousy").count();
+        // lousy is 1/15 of the weight, but nearly 1/4 of the picks once hot
+        assert!(count(&openers(4, 100.0)) > 2 * count(&openers(4, 1.0)));
+    }
+
+    #[test]
+    fn seeded() {
+        let play = |seed| {
+            let mut guesser = Mixed::from_priors(&priors(), seed);
+            (0..8).map(|_| guesser.guess(&[])).collect::<Vec<_>>()
+        };
+        assert_eq!(play(3), play(3));
+        assert_eq!(Mixed::from_priors(&priors(), 3).ranked(&[]).len(), 4);
+    }
+}
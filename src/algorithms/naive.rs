@@ -1,52 +1,144 @@
-use std::collections::HashMap;
+use alloc::vec::Vec;
 
-use crate::{Guess, Guesser, DICTIONARY};
+use super::{Candidates, Normalization};
+use crate::{Dictionary, Guess, Guesser, Word};
 
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Naive {
-    remaining: HashMap<&'static str, usize>,
+    candidates: Candidates,
+    /// The first guess, if it is already known.
+    opener: Option<Word>,
+}
+
+#[cfg(feature = "embedded-dictionary")]
+impl Default for Naive {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Naive {
+    #[cfg(feature = "embedded-dictionary")]
     pub fn new() -> Self {
-        Naive {
-            remaining: HashMap::from_iter(DICTIONARY.lines().map(|line| {
-                let (word, count) = line.split_once(" ").expect("Expected: Line + Space + Freq");
-                let count: usize = count.parse().expect("Every Count is a number");
-                (word, count)
-            })),
+        Self {
+            opener: Some(super::NAIVE_OPENER),
+            ..Self::with_dictionary(&Dictionary::embedded())
         }
     }
-}
 
-#[derive(Debug, Copy, Clone)]
-struct Candidate {
-    word: &'static str,
-    count: usize,
-    goodness: f64,
+    pub fn with_dictionary(dictionary: &Dictionary) -> Self {
+        Self::with_normalization(dictionary, Normalization::default())
+    }
+
+    /// Weigh the possible answers by their counts in `dictionary` after applying
+    /// `normalization`.
+    pub fn with_normalization(dictionary: &Dictionary, normalization: Normalization) -> Self {
+        Self::from_priors(&normalization.weights(dictionary.answers()))
+    }
+
+    /// Start from prior weights computed once, as by [`Normalization::weights`], so a batch
+    /// of games does not redo that work for each one.
+    pub fn from_priors(priors: &[(Word, f64)]) -> Self {
+        Naive {
+            candidates: Candidates::from_priors(priors),
+            opener: None,
+        }
+    }
 }
 
 impl Guesser for Naive {
-    fn guess(&mut self, _history: &[Guess]) -> String {
-        let mut best: Option<Candidate> = None;
-        for (&word, &count) in &self.remaining {
-            let goodness = 6.9;
-            if let Some(c) = best {
-                // is this one better?
-                if goodness > c.goodness {
-                    best = Some(Candidate {
-                        word,
-                        count,
-                        goodness,
-                    });
-                }
-            } else {
-                best = Some(Candidate {
-                    word,
-                    count,
-                    goodness,
-                });
+    fn guess(&mut self, history: &[Guess]) -> Word {
+        if history.is_empty() {
+            if let Some(opener) = self.opener {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(%opener, "playing the precomputed opener");
+                return opener;
             }
         }
-        todo!();
+        if let Some(last) = history.last() {
+            self.candidates.filter(last);
+        }
+
+        // placeholder: play the most likely word until real scoring lands; candidates are
+        // kept in that order, ties alphabetical, so it is simply the first
+        let (guess, _weight) = self
+            .candidates
+            .iter()
+            .next()
+            .expect("dictionary is not empty");
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            %guess,
+            weight = _weight,
+            candidates = self.candidates.len(),
+            "chose the most likely candidate"
+        );
+        guess
+    }
+
+    /// The guess, then every other candidate from most to least likely.
+    fn ranked(&mut self, history: &[Guess]) -> Vec<Word> {
+        let guess = self.guess(history);
+        let others = self.candidates.iter().map(|(word, _)| word);
+        core::iter::once(guess)
+            .chain(others.filter(|&word| word != guess))
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "embedded-dictionary"))]
+mod tests {
+    use super::Naive;
+    use crate::algorithms::Normalization;
+    use crate::{Dictionary, Guesser, Wordle};
+
+    #[test]
+    fn normalization_changes_the_opener() {
+        let dictionary = Dictionary::embedded();
+        let opener = |n| Naive::with_normalization(&dictionary, n).guess(&[]);
+        assert_eq!(opener(Normalization::Raw), "which");
+        assert_eq!(opener(Normalization::Log), "which");
+        // once every word is capped to the same weight, the tie-break decides
+        assert_eq!(opener(Normalization::Capped(1)), "aahed");
+    }
+
+    #[test]
+    fn precomputed_opener_matches_scoring() {
+        let dictionary = Dictionary::embedded();
+        assert_eq!(
+            Naive::with_dictionary(&dictionary).guess(&[]),
+            crate::algorithms::NAIVE_OPENER
+        );
+        assert_eq!(Naive::new().guess(&[]), "which");
+    }
+
+    #[test]
+    fn ranked_starts_with_the_guess() {
+        let dictionary = Dictionary::embedded();
+        let ranked = Naive::with_dictionary(&dictionary).ranked(&[]);
+        assert_eq!(ranked[0], Naive::with_dictionary(&dictionary).guess(&[]));
+        assert_eq!(ranked.len(), dictionary.answers().len());
+        assert_eq!(Naive::new().ranked(&[])[0], crate::algorithms::NAIVE_OPENER);
+    }
+
+    #[test]
+    fn golden_scores() {
+        // every answer is checked by `roget golden`; a sample keeps this quick in debug builds
+        let dictionary = Dictionary::embedded();
+        let priors = Normalization::default().weights(dictionary.answers());
+        let wordle = Wordle::new();
+        let lines = include_str!("../../golden/naive.txt")
+            .lines()
+            .filter(|line| !line.starts_with('#'));
+        for line in lines.step_by(50) {
+            let (answer, score) = line.split_once(' ').expect("`answer score` lines");
+            let score = score.parse().ok();
+            assert_eq!(
+                wordle.play(answer, Naive::from_priors(&priors)),
+                score,
+                "{answer}"
+            );
+        }
     }
 }
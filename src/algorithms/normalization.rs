@@ -0,0 +1,113 @@
+use alloc::vec::Vec;
+
+use crate::Word;
+
+/// How raw word counts are turned into the prior weights an algorithm ranks words by.
+///
+/// Counts in the embedded dictionary run from single digits to billions, so used as-is a
+/// handful of very common words drown out everything else.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Normalization {
+    /// The counts themselves.
+    #[default]
+    Raw,
+    /// `ln(1 + count)`, which keeps the order but shrinks the gaps between common and rare
+    /// words.
+    Log,
+    /// Counts above the cap are treated as the cap, so every sufficiently common word is
+    /// considered equally likely.
+    Capped(usize),
+    /// Each word's position when sorted by count, rarest first, so the weights are evenly
+    /// spaced no matter how skewed the counts are. Equal counts share a rank.
+    Rank,
+}
+
+impl Normalization {
+    /// The prior weight of every word, in the same order as `words`.
+    pub fn weights(self, words: &[(Word, usize)]) -> Vec<(Word, f64)> {
+        match self {
+            Self::Raw => words.iter().map(|&(w, c)| (w, c as f64)).collect(),
+            Self::Log => words
+                .iter()
+                .map(|&(w, c)| (w, libm::log1p(c as f64)))
+                .collect(),
+            Self::Capped(cap) => words.iter().map(|&(w, c)| (w, c.min(cap) as f64)).collect(),
+            Self::Rank => {
+                let mut counts: Vec<usize> = words.iter().map(|&(_, c)| c).collect();
+                counts.sort_unstable();
+                counts.dedup();
+                words
+                    .iter()
+                    .map(|&(w, c)| {
+                        let rank = counts.binary_search(&c).expect("every count was collected");
+                        (w, (rank + 1) as f64)
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::Normalization;
+    use crate::Word;
+
+    const WORDS: [(Word, usize); 4] = [
+        (Word::new("which"), 12_682_836_830),
+        (Word::new("crane"), 7_000_000),
+        (Word::new("slate"), 2_000_000),
+        (Word::new("aahed"), 49_650),
+    ];
+
+    /// The words from most to least likely under `normalization`, alphabetical on ties.
+    fn ordering(normalization: Normalization) -> Vec<Word> {
+        let mut weights = normalization.weights(&WORDS);
+        weights.sort_by(|(a, x), (b, y)| y.total_cmp(x).then(a.cmp(b)));
+        weights.into_iter().map(|(w, _)| w).collect()
+    }
+
+    fn weight(normalization: Normalization, word: &str) -> f64 {
+        let weights = normalization.weights(&WORDS);
+        weights.iter().find(|(w, _)| *w == word).unwrap().1
+    }
+
+    #[test]
+    fn monotone_keeps_ordering() {
+        let raw = ordering(Normalization::Raw);
+        assert_eq!(raw, ["which", "crane", "slate", "aahed"]);
+        assert_eq!(ordering(Normalization::Log), raw);
+        assert_eq!(ordering(Normalization::Rank), raw);
+    }
+
+    #[test]
+    fn capping_ties_common_words() {
+        assert_eq!(
+            ordering(Normalization::Capped(1_000_000)),
+            ["crane", "slate", "which", "aahed"]
+        );
+    }
+
+    #[test]
+    fn compresses_the_range() {
+        let spread = |n| weight(n, "which") / weight(n, "aahed");
+        assert!(spread(Normalization::Raw) > 250_000.0);
+        assert!(spread(Normalization::Log) < 3.0);
+        assert_eq!(spread(Normalization::Rank), 4.0);
+    }
+
+    #[test]
+    fn equal_counts_share_a_rank() {
+        let words = [
+            (Word::new("crane"), 5),
+            (Word::new("slate"), 5),
+            (Word::new("aahed"), 1),
+        ];
+        let weights = Normalization::Rank.weights(&words);
+        assert_eq!(weights[0].1, 2.0);
+        assert_eq!(weights[1].1, 2.0);
+        assert_eq!(weights[2].1, 1.0);
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::sync::Arc;
+
+use super::Candidates;
+use crate::{DecisionTree, Guess, Guesser, Word};
+
+/// Plays a strategy worked out ahead of time, such as a published optimal tree.
+///
+/// Once a game leaves the tree, because an answer the tree does not cover came up, it falls
+/// back to guessing the most likely remaining candidate.
+pub struct PrecomputedTree {
+    tree: Arc<DecisionTree>,
+    candidates: Candidates,
+}
+
+impl PrecomputedTree {
+    /// Follow `tree`, which every game of a batch can share, falling back to `priors`.
+    pub fn new(tree: Arc<DecisionTree>, priors: &[(Word, f64)]) -> Self {
+        Self {
+            tree,
+            candidates: Candidates::from_priors(priors),
+        }
+    }
+}
+
+impl Guesser for PrecomputedTree {
+    fn guess(&mut self, history: &[Guess]) -> Word {
+        if let Some(last) = history.last() {
+            self.candidates.filter(last);
+        }
+        match self.tree.follow(history) {
+            Some(node) => {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(guess = %node.guess, "following the tree");
+                node.guess
+            }
+            None => {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(
+                    candidates = self.candidates.len(),
+                    "left the tree, falling back to the most likely candidate"
+                );
+                self.candidates.best().expect("dictionary is not empty")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use super::PrecomputedTree;
+    use crate::{Correctness, DecisionTree, Guess, Guesser, Word};
+
+    #[test]
+    fn follows_then_falls_back() {
+        let tree: DecisionTree = "crane YGGBG1 trace GGGGG2".parse().unwrap();
+        let priors = [(Word::new("trace"), 1.0), (Word::new("slate"), 2.0)];
+        let mut guesser = PrecomputedTree::new(Arc::new(tree), &priors);
+        assert_eq!(guesser.guess(&[]), "crane");
+
+        let mut history = [Guess {
+            word: Word::new("crane"),
+            mask: Correctness::compute(Word::new("trace"), Word::new("crane")),
+        }];
+        assert_eq!(guesser.guess(&history), "trace");
+
+        // slate is not in the tree, so the mask it shows has no plan
+        history[0].mask = Correctness::compute(Word::new("slate"), Word::new("crane"));
+        let mut guesser = PrecomputedTree::new(guesser.tree.clone(), &priors);
+        assert_eq!(guesser.guess(&history), "slate");
+    }
+}
@@ -0,0 +1,127 @@
+use super::{Candidates, Normalization};
+use crate::rng::SplitMix64;
+use crate::{Dictionary, Guess, Guesser, Word};
+
+/// A baseline that plays a random word the answer could still be.
+///
+/// By default every candidate is as likely as any other; [`Random::weighted`] picks common
+/// words more often instead. The same seed always plays the same game against the same
+/// answer, on any platform and with any version of this crate, so results stay reproducible.
+///
+/// ```
+/// use roget::algorithms::Random;
+/// use roget::{Dictionary, Wordle};
+///
+/// let wordle = Wordle::new();
+/// let dictionary = Dictionary::embedded();
+/// let first = wordle.play_game("crane", Random::new(&dictionary, 7));
+/// let again = wordle.play_game("crane", Random::new(&dictionary, 7));
+/// assert_eq!(first.guesses, again.guesses);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Random {
+    candidates: Candidates,
+    weighted: bool,
+    rng: SplitMix64,
+}
+
+impl Random {
+    /// Pick among the possible answers in `dictionary`, starting from `seed`.
+    pub fn new(dictionary: &Dictionary, seed: u64) -> Self {
+        Self::from_priors(
+            &Normalization::default().weights(dictionary.answers()),
+            seed,
+        )
+    }
+
+    /// Start from prior weights computed once, as by [`Normalization::weights`]. They only
+    /// matter once [`Random::weighted`] is on.
+    pub fn from_priors(priors: &[(Word, f64)], seed: u64) -> Self {
+        Self {
+            candidates: Candidates::from_priors(priors),
+            weighted: false,
+            rng: SplitMix64::new(seed),
+        }
+    }
+
+    /// Pick each candidate with a chance in proportion to its prior weight, rather than
+    /// uniformly.
+    pub fn weighted(mut self, weighted: bool) -> Self {
+        self.weighted = weighted;
+        self
+    }
+}
+
+impl Guesser for Random {
+    fn guess(&mut self, history: &[Guess]) -> Word {
+        if let Some(last) = history.last() {
+            self.candidates.filter(last);
+        }
+        let guess = match self.weighted {
+            true => {
+                let mut target = self.rng.unit() * self.candidates.total();
+                self.candidates
+                    .iter()
+                    .find(|&(_, weight)| {
+                        target -= weight;
+                        target < 0.0
+                    })
+                    // rounding can leave a sliver past the last word
+                    .or_else(|| self.candidates.iter().last())
+                    .map(|(word, _)| word)
+            }
+            false => {
+                let n = self.rng.below(self.candidates.len());
+                self.candidates.iter().nth(n).map(|(word, _)| word)
+            }
+        }
+        .expect("the answer is always a candidate");
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            %guess,
+            candidates = self.candidates.len(),
+            weighted = self.weighted,
+            "chose a random candidate"
+        );
+        guess
+    }
+}
+
+#[cfg(all(test, feature = "embedded-dictionary"))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::Random;
+    use crate::{Dictionary, Guesser, Word, Wordle};
+
+    #[test]
+    fn seeded() {
+        let wordle = Wordle::new();
+        let dictionary = Dictionary::embedded();
+        let play = |seed| wordle.play_game("right", Random::new(&dictionary, seed));
+        let game = play(1);
+        assert_eq!(game.score, Some(game.guesses.len()));
+        assert_eq!(play(1).guesses, game.guesses);
+        // only candidates are played, so every miss rules itself out
+        assert!(game
+            .progress
+            .iter()
+            .all(|p| p.after < p.before || p.before == 1));
+
+        let openers: Vec<Word> = (0..8).map(|seed| play(seed).guesses[0].word).collect();
+        assert!(openers.iter().any(|&opener| opener != openers[0]));
+    }
+
+    #[test]
+    fn weighted() {
+        let priors = [(Word::new("crane"), 1000.0), (Word::new("slate"), 1e-9)];
+        let pick = |seed, weighted| {
+            Random::from_priors(&priors, seed)
+                .weighted(weighted)
+                .guess(&[])
+        };
+        assert!((0..20).all(|seed| pick(seed, true) == "crane"));
+        assert!((0..20).any(|seed| pick(seed, false) == "slate"));
+    }
+}
@@ -0,0 +1,119 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Correctness, Guess, Word};
+
+/// Wordle played backwards, as in Antiwordle: the goal is to put off guessing the answer for
+/// as long as possible.
+///
+/// To keep that from being trivial, every guess has to be one the answer could still be:
+/// it must agree with the mask of each guess before it. That is stricter than hard mode,
+/// which only requires revealed letters to be reused. The game ends when the answer is
+/// guessed, and the guesses made before it are the score.
+#[derive(Debug, Clone)]
+pub struct AntiWordle {
+    answer: Word,
+    history: Vec<Guess>,
+}
+
+impl AntiWordle {
+    pub fn new(answer: Word) -> Self {
+        Self {
+            answer,
+            history: Vec::new(),
+        }
+    }
+
+    /// Grade `word`, unless the rules do not allow it.
+    pub fn guess(&mut self, word: Word) -> Result<[Correctness; 5], AntiWordleError> {
+        if self.is_over() {
+            return Err(AntiWordleError::Over);
+        }
+        if let Some(turn) = self.history.iter().position(|guess| !guess.matches(word)) {
+            return Err(AntiWordleError::Contradicts(turn + 1));
+        }
+        let mask = Correctness::compute(self.answer, word);
+        self.history.push(Guess { word, mask });
+        Ok(mask)
+    }
+
+    pub fn answer(&self) -> Word {
+        self.answer
+    }
+
+    /// Every guess so far, including the answer once it has been hit.
+    pub fn history(&self) -> &[Guess] {
+        &self.history
+    }
+
+    /// How many guesses missed the answer.
+    pub fn dodged(&self) -> usize {
+        match self.is_over() {
+            true => self.history.len() - 1,
+            false => self.history.len(),
+        }
+    }
+
+    /// Whether the answer has been guessed.
+    pub fn is_over(&self) -> bool {
+        self.history
+            .last()
+            .is_some_and(|guess| guess.word == self.answer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AntiWordleError {
+    /// The answer has already been guessed.
+    Over,
+    /// The guess could not be the answer given the mask of this earlier guess (1-based).
+    Contradicts(usize),
+}
+
+impl fmt::Display for AntiWordleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Over => f.write_str("the answer has already been guessed"),
+            Self::Contradicts(n) => write!(f, "that contradicts the colors of guess {n}"),
+        }
+    }
+}
+
+impl core::error::Error for AntiWordleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{AntiWordle, AntiWordleError};
+    use crate::{Correctness, Word};
+
+    #[test]
+    fn guesses_must_stay_possible() {
+        let mut game = AntiWordle::new(Word::new("crane"));
+        let mask = game.guess(Word::new("trace")).unwrap();
+        assert_eq!(
+            mask,
+            Correctness::compute(Word::new("crane"), Word::new("trace"))
+        );
+
+        // slate has no r, which trace showed the answer has
+        assert_eq!(
+            game.guess(Word::new("slate")),
+            Err(AntiWordleError::Contradicts(1))
+        );
+        assert_eq!(game.dodged(), 1);
+        assert!(!game.is_over());
+    }
+
+    #[test]
+    fn ends_on_the_answer() {
+        let mut game = AntiWordle::new(Word::new("crane"));
+        game.guess(Word::new("grace")).unwrap();
+        assert_eq!(
+            game.guess(Word::new("crane")),
+            Ok([Correctness::Correct; 5])
+        );
+        assert!(game.is_over());
+        assert_eq!(game.dodged(), 1);
+        assert_eq!(game.guess(Word::new("grace")), Err(AntiWordleError::Over));
+    }
+}
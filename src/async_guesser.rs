@@ -0,0 +1,81 @@
+use core::future::Future;
+
+use crate::{Cancel, Guess, Word};
+
+/// A [`Guesser`](crate::Guesser) that waits on something else for its words, such as a
+/// remote engine or a language model behind an HTTP API, played with
+/// [`Wordle::play_async`](crate::Wordle::play_async) so that no thread blocks while it
+/// waits.
+///
+/// The futures must be `Send`, so games can run on tokio's multi-threaded runtime.
+pub trait AsyncGuesser {
+    /// The next word to play, which must be in the game's dictionary.
+    fn guess(&mut self, history: &[Guess]) -> impl Future<Output = Word> + Send;
+
+    /// Like [`AsyncGuesser::guess`], but a guesser should answer with the best word it has
+    /// so far once `cancel` is cancelled; see [`Guesser::guess_until`].
+    ///
+    /// A game with a time limit drops the future when the limit passes instead.
+    ///
+    /// [`Guesser::guess_until`]: crate::Guesser::guess_until
+    fn guess_until(
+        &mut self,
+        history: &[Guess],
+        cancel: &Cancel,
+    ) -> impl Future<Output = Word> + Send {
+        let _ = cancel;
+        self.guess(history)
+    }
+}
+
+#[cfg(all(test, feature = "embedded-dictionary"))]
+mod tests {
+    use core::future::{self, Future};
+    use core::time::Duration;
+
+    use super::AsyncGuesser;
+    use crate::{GameConfig, Guess, Word, Wordle};
+
+    /// Plays `words` in order, then waits forever.
+    struct Script(&'static [&'static str]);
+
+    impl AsyncGuesser for Script {
+        fn guess(&mut self, history: &[Guess]) -> impl Future<Output = Word> + Send {
+            let next = self.0.get(history.len()).map(|&word| Word::new(word));
+            async move {
+                match next {
+                    Some(word) => word,
+                    None => future::pending().await,
+                }
+            }
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn play() {
+        let wordle = Wordle::new();
+        let guesser = Script(&["crane", "right"]);
+        let result = block_on(wordle.play_async(Word::new("right"), guesser, &GameConfig::new()));
+        assert_eq!(result.score, Some(2));
+        assert_eq!(result.guess_times.len(), 2);
+        assert_eq!(result.progress.len(), 2);
+    }
+
+    #[test]
+    fn time_limit_drops_the_guess() {
+        let wordle = Wordle::new();
+        let config = GameConfig::new().time_limit(Duration::from_millis(10));
+        let result = block_on(wordle.play_async(Word::new("right"), Script(&["crane"]), &config));
+        assert_eq!(result.score, None);
+        assert_eq!(result.guesses.len(), 1);
+        assert_eq!(result.guess_times.len(), 1);
+    }
+}
@@ -0,0 +1,162 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A set of word indices, such as the answers still possible, stored one bit per word.
+///
+/// Indices are positions in a fixed word list, usually [`Dictionary::answers`] or the
+/// answer columns of a [`Matrix`]. Narrowing the set down never allocates, and two sets over
+/// the same list are equal exactly when they hold the same words, so a set can key a memo
+/// table as it is.
+///
+/// [`Dictionary::answers`]: crate::Dictionary::answers
+/// [`Matrix`]: crate::Matrix
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bitset {
+    blocks: Vec<u64>,
+    /// How many indices the set ranges over.
+    len: usize,
+}
+
+impl Bitset {
+    /// A set over `len` indices holding none of them.
+    pub fn empty(len: usize) -> Self {
+        Self {
+            blocks: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    /// A set over `len` indices holding all of them.
+    pub fn full(len: usize) -> Self {
+        let mut set = Self {
+            blocks: vec![u64::MAX; len.div_ceil(64)],
+            len,
+        };
+        // keep the bits past the end clear, so equality and counting only see real indices
+        if !len.is_multiple_of(64) {
+            if let Some(last) = set.blocks.last_mut() {
+                *last = (1 << (len % 64)) - 1;
+            }
+        }
+        set
+    }
+
+    /// How many indices the set ranges over, whether or not they are in it.
+    pub fn capacity(&self) -> usize {
+        self.len
+    }
+
+    /// Panics if `index` is out of range.
+    pub fn contains(&self, index: usize) -> bool {
+        assert!(index < self.len, "index {index} out of range");
+        self.blocks[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Panics if `index` is out of range.
+    pub fn insert(&mut self, index: usize) {
+        assert!(index < self.len, "index {index} out of range");
+        self.blocks[index / 64] |= 1 << (index % 64);
+    }
+
+    /// Panics if `index` is out of range.
+    pub fn remove(&mut self, index: usize) {
+        assert!(index < self.len, "index {index} out of range");
+        self.blocks[index / 64] &= !(1 << (index % 64));
+    }
+
+    /// How many indices are in the set.
+    pub fn count(&self) -> usize {
+        self.blocks.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.iter().all(|&b| b == 0)
+    }
+
+    /// Keep only the indices also in `other`.
+    ///
+    /// Panics if the sets range over different numbers of indices.
+    pub fn intersect_with(&mut self, other: &Self) {
+        assert_eq!(self.len, other.len, "sets over different word lists");
+        for (a, b) in self.blocks.iter_mut().zip(&other.blocks) {
+            *a &= b;
+        }
+    }
+
+    /// Keep only the indices for which `keep` returns true.
+    pub fn retain(&mut self, mut keep: impl FnMut(usize) -> bool) {
+        for (i, block) in self.blocks.iter_mut().enumerate() {
+            let mut bits = *block;
+            while bits != 0 {
+                let bit = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                if !keep(i * 64 + bit) {
+                    *block &= !(1 << bit);
+                }
+            }
+        }
+    }
+
+    /// The indices in the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.blocks.iter().enumerate().flat_map(|(i, &block)| {
+            let mut bits = block;
+            core::iter::from_fn(move || {
+                if bits == 0 {
+                    return None;
+                }
+                let bit = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::Bitset;
+
+    #[test]
+    fn full_and_empty() {
+        for len in [0, 1, 63, 64, 65, 130] {
+            assert_eq!(Bitset::full(len).count(), len);
+            assert_eq!(Bitset::full(len).iter().count(), len);
+            assert!(Bitset::empty(len).is_empty());
+            assert_eq!(Bitset::full(len).is_empty(), len == 0);
+        }
+    }
+
+    #[test]
+    fn insert_remove_and_iterate() {
+        let mut set = Bitset::empty(130);
+        for i in [0, 5, 63, 64, 129] {
+            set.insert(i);
+        }
+        set.remove(5);
+        assert!(set.contains(64));
+        assert!(!set.contains(5));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 63, 64, 129]);
+        assert_eq!(set.count(), 4);
+    }
+
+    #[test]
+    fn retain_and_intersect() {
+        let mut evens = Bitset::full(100);
+        evens.retain(|i| i % 2 == 0);
+        let mut threes = Bitset::full(100);
+        threes.retain(|i| i % 3 == 0);
+
+        evens.intersect_with(&threes);
+        assert_eq!(evens.count(), 17);
+        assert!(evens.iter().all(|i| i % 6 == 0));
+
+        // sets with the same members are equal however they got there
+        let mut sixes = Bitset::empty(100);
+        (0..100).step_by(6).for_each(|i| sixes.insert(i));
+        assert_eq!(evens, sixes);
+    }
+}
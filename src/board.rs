@@ -0,0 +1,88 @@
+use core::fmt;
+
+use crate::Guess;
+
+/// A game's guesses as a board, one row each: the word, then the tiles it got.
+///
+/// ```text
+/// crane ⬛🟨⬛⬛⬛
+/// sport ⬛⬛🟨🟨⬛
+/// rough 🟩🟩🟩🟩🟩
+/// ```
+///
+/// With `{:#}` the tiles are written as the letters [`parse_mask`](crate::parse_mask)
+/// accepts instead, as in `crane xyxxx`, so the board can be typed back in.
+///
+/// ```
+/// use roget::{parse_mask, Board, Guess, Word};
+///
+/// let guesses = [Guess {
+///     word: Word::new("crane"),
+///     mask: parse_mask("xyxxx").unwrap(),
+/// }];
+/// assert_eq!(Board::new(&guesses).to_string(), "crane ⬛🟨⬛⬛⬛");
+/// assert_eq!(format!("{:#}", Board::new(&guesses)), "crane xyxxx");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Board<'a> {
+    guesses: &'a [Guess],
+}
+
+impl<'a> Board<'a> {
+    pub fn new(guesses: &'a [Guess]) -> Self {
+        Self { guesses }
+    }
+}
+
+impl fmt::Display for Board<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, guess) in self.guesses.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{} ", guess.word)?;
+            for c in guess.mask {
+                match f.alternate() {
+                    true => write!(f, "{c:#}")?,
+                    false => write!(f, "{c}")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::Board;
+    use crate::{parse_mask, Correctness, Guess, Word};
+
+    #[test]
+    fn board() {
+        let answer = Word::new("rough");
+        let guesses: alloc::vec::Vec<Guess> = ["crane", "sport", "rough"]
+            .into_iter()
+            .map(|word| {
+                let word = Word::new(word);
+                Guess {
+                    word,
+                    mask: Correctness::compute(answer, word),
+                }
+            })
+            .collect();
+        assert_eq!(
+            Board::new(&guesses).to_string(),
+            "crane ⬛🟨⬛⬛⬛\nsport ⬛⬛🟨🟨⬛\nrough 🟩🟩🟩🟩🟩"
+        );
+        assert_eq!(Board::new(&[]).to_string(), "");
+
+        let plain = alloc::format!("{:#}", Board::new(&guesses));
+        for (line, guess) in plain.lines().zip(&guesses) {
+            let (word, mask) = line.split_once(' ').unwrap();
+            assert_eq!(guess.word, word);
+            assert_eq!(parse_mask(mask).unwrap(), guess.mask);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use alloc::sync::Arc;
+use core::fmt;
+use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+use std::time::Instant;
+
+/// Tells a guesser to stop searching and answer with the best word it has found so far.
+///
+/// A token is cancelled when [`Cancel::cancel`] is called on it or any clone of it, such as
+/// by a server shutting down, or once its deadline passes. Guessers see one through
+/// [`Guesser::guess_until`](crate::Guesser::guess_until) and should poll
+/// [`Cancel::is_cancelled`] now and then during a long search; nothing stops a guesser that
+/// does not.
+///
+/// Deadlines need a clock, so they are only available with the `std` feature and off
+/// WebAssembly.
+#[derive(Clone, Default)]
+pub struct Cancel {
+    cancelled: Arc<AtomicBool>,
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    deadline: Option<Instant>,
+}
+
+impl Cancel {
+    /// A token that is only cancelled by calling [`Cancel::cancel`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel this token and every clone of it.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return true;
+        }
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return true;
+        }
+        false
+    }
+
+    /// A token cancelled along with this one, and also at `deadline` if that comes first.
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    pub fn until(&self, deadline: Instant) -> Self {
+        Self {
+            cancelled: Arc::clone(&self.cancelled),
+            deadline: Some(self.deadline.map_or(deadline, |d| d.min(deadline))),
+        }
+    }
+
+    /// When the token cancels itself, if it has a deadline.
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+}
+
+impl fmt::Debug for Cancel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Cancel");
+        debug.field("cancelled", &self.cancelled.load(Ordering::Relaxed));
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        debug.field("deadline", &self.deadline);
+        debug.finish()
+    }
+}
+
+/// Tokens are equal when cancelling one cancels the other and they share a deadline.
+impl PartialEq for Cancel {
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        if self.deadline != other.deadline {
+            return false;
+        }
+        Arc::ptr_eq(&self.cancelled, &other.cancelled)
+    }
+}
+
+impl Eq for Cancel {}
+
+#[cfg(test)]
+mod tests {
+    use super::Cancel;
+
+    #[test]
+    fn cancel_reaches_clones() {
+        let token = Cancel::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+        token.cancel();
+        assert!(clone.is_cancelled());
+        assert_eq!(token, clone);
+        assert_ne!(token, Cancel::new());
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    fn deadlines() {
+        use std::time::{Duration, Instant};
+
+        let token = Cancel::new();
+        let now = Instant::now();
+        assert!(token.until(now).is_cancelled());
+
+        let later = token.until(now + Duration::from_secs(60));
+        assert!(!later.is_cancelled());
+        // the earlier deadline wins
+        assert_eq!(
+            later.until(now + Duration::from_secs(120)).deadline(),
+            later.deadline()
+        );
+        token.cancel();
+        assert!(later.is_cancelled());
+    }
+}
@@ -0,0 +1,48 @@
+pub mod anti;
+pub mod assist;
+pub mod batch;
+pub mod bench;
+pub mod cache;
+pub mod check;
+pub mod checkpoint;
+pub mod compare;
+pub mod config;
+pub mod daily;
+#[cfg(feature = "sqlite")]
+pub mod db;
+pub mod dict;
+pub mod difficulty;
+pub mod endgame;
+pub mod engine;
+pub mod golden;
+pub mod grade;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod heatmap;
+pub mod hint;
+pub mod html;
+pub mod hurdle;
+pub mod matrix;
+pub mod openers;
+pub mod output;
+pub mod play;
+pub mod plugin;
+pub mod race;
+pub mod records;
+#[cfg(feature = "network")]
+pub mod remote;
+pub mod render;
+pub mod report;
+pub mod reverse;
+#[cfg(feature = "server")]
+pub mod serve;
+#[cfg(any(feature = "server", feature = "grpc"))]
+pub mod service;
+pub mod solve;
+pub mod streak;
+pub mod tournament;
+pub mod traced;
+pub mod tree;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod verify;
@@ -0,0 +1,35 @@
+use rayon::prelude::*;
+use roget::algorithms::{Evader, Normalization};
+use roget::{Word, Wordle};
+
+/// Play Antiwordle on every one of `answers` with [`Evader`] and report how many guesses it
+/// gets through before it has to guess the answer.
+pub fn anti(wordle: &Wordle, answers: &[&str]) {
+    let priors = Normalization::default().weights(wordle.dictionary().answers());
+    let dodged: Vec<(Word, usize)> = answers
+        .par_iter()
+        .map(|answer| {
+            let answer: Word = answer.parse().expect("answers are five-letter words");
+            let result = wordle.play_anti(answer, Evader::from_priors(&priors), usize::MAX);
+            let score = result
+                .score
+                .expect("the answer is guessed once it is all that is left");
+            (answer, score - 1)
+        })
+        .collect();
+
+    let (Some(fewest), Some(most)) = (
+        dodged.iter().min_by_key(|&&(_, n)| n),
+        dodged.iter().max_by_key(|&&(_, n)| n),
+    ) else {
+        println!("no answers to play");
+        return;
+    };
+    let average = dodged.iter().map(|&(_, n)| n).sum::<usize>() as f64 / dodged.len() as f64;
+    println!(
+        "dodged the answer for {average:.4} guesses on average over {} answers",
+        dodged.len()
+    );
+    println!("fewest: {} ({})", fewest.1, fewest.0);
+    println!("most: {} ({})", most.1, most.0);
+}
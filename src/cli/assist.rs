@@ -0,0 +1,115 @@
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use anyhow::Context;
+use roget::{parse_mask, Board, Explanation, Guess, Guesser, Session, Word, Wordle};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Suggest guesses for a game being played elsewhere, reading back the colors it showed.
+///
+/// With `save`, the session is written there after every guess, and removed once solved.
+/// With `teach`, each suggestion comes with an [`Explanation`] of why it beat the next few.
+pub fn assist<G>(
+    wordle: &Wordle,
+    mut session: Session<G>,
+    save: Option<&Path>,
+    teach: bool,
+) -> anyhow::Result<()>
+where
+    G: Guesser + Serialize,
+{
+    let mut lines = io::stdin().lock().lines();
+
+    if !session.history.is_empty() {
+        println!("picking up after {} guesses:", session.history.len());
+        println!("{}", Board::new(&session.history));
+    }
+    println!("enter the colors you got as g (green), y (yellow), x (gray), e.g. gyxxy");
+    println!("emoji (🟩🟨⬛) and digits (2 1 0) work too");
+    println!("if you played a different word, enter it first: crane gyxxy");
+    loop {
+        let remaining: Vec<Word> = wordle
+            .candidates(&session.history)
+            .map(|(word, _)| word)
+            .collect();
+        if remaining.is_empty() {
+            println!("no dictionary words match that feedback");
+            return Ok(());
+        }
+
+        let suggestion = match teach {
+            true => {
+                let ranked = session.ranked();
+                let ranked = &ranked[..ranked.len().min(1 + ALTERNATIVES)];
+                let explanation = Explanation::new(&session.history, &remaining, ranked)
+                    .context("the guesser ranked no words")?;
+                println!();
+                println!("{explanation}");
+                explanation.guess
+            }
+            false => session.suggest(),
+        };
+        println!(
+            "guess {}: {suggestion} ({} candidates)",
+            session.history.len() + 1,
+            remaining.len()
+        );
+
+        let guess = loop {
+            print!("> ");
+            io::stdout().flush()?;
+            let Some(line) = lines.next().transpose()? else {
+                return Ok(());
+            };
+            match read_feedback(&line, suggestion) {
+                Ok(guess) => break guess,
+                Err(e) => println!("{e}"),
+            }
+        };
+
+        session.record(guess);
+        if session.is_solved() {
+            println!("solved in {}!", session.history.len());
+            if let Some(path) = save {
+                std::fs::remove_file(path)
+                    .with_context(|| format!("removing {}", path.display()))?;
+            }
+            return Ok(());
+        }
+        if let Some(path) = save {
+            let json = serde_json::to_string(&session)?;
+            std::fs::write(path, json).with_context(|| format!("writing {}", path.display()))?;
+        }
+    }
+}
+
+/// How many of the words ranked after a suggestion are explained away when teaching.
+const ALTERNATIVES: usize = 3;
+
+/// The session saved at `path` by [`assist`], or `None` if there is none yet.
+pub fn load<G: DeserializeOwned>(path: &Path) -> anyhow::Result<Option<Session<G>>> {
+    let json = match std::fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e).with_context(|| format!("reading {}", path.display())),
+    };
+    let session = serde_json::from_str(&json)
+        .with_context(|| format!("{} is not a saved game", path.display()))?;
+    Ok(Some(session))
+}
+
+/// Parse either `<mask>` for the suggested word or `<word> <mask>` for a different one.
+pub fn read_feedback(line: &str, suggestion: Word) -> Result<Guess, String> {
+    let mut parts = line.split_whitespace();
+    let (word, mask) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(mask), None, None) => (suggestion, mask),
+        (Some(word), Some(mask), None) => {
+            let word = word.parse().map_err(|e| format!("{word}: {e}"))?;
+            (word, mask)
+        }
+        _ => return Err("expected `<colors>` or `<word> <colors>`".to_string()),
+    };
+    let mask = parse_mask(mask).map_err(|e| e.to_string())?;
+    Ok(Guess { word, mask })
+}
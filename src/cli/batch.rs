@@ -0,0 +1,403 @@
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use clap::ValueEnum;
+use indicatif::{ProgressBar, ProgressStyle};
+use rayon::prelude::*;
+use roget::{GameResult, GameStats, Guesser, Paired, Recorder, Rules, Wordle};
+use serde::{Deserialize, Serialize};
+
+use tracing::{debug, info, info_span};
+
+use super::checkpoint::{Checkpoint, Header};
+use super::output::{CallLog, CsvOut};
+use super::traced::Traced;
+use crate::Implementation;
+
+/// How to report the outcome of a batch run.
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum Format {
+    /// A score histogram and average.
+    #[default]
+    Summary,
+    /// A JSON array with one object per game.
+    Json,
+    /// One JSON object per line, the format `roget report` reads; see [`Record`].
+    Jsonl,
+    /// One CSV row per game.
+    Csv,
+}
+
+/// The version of the [`Record`] format. Fields may be added without changing it, but not
+/// removed or given a new meaning.
+pub const SCHEMA: u32 = 1;
+
+/// One game's result along with which algorithm played it and how long it took.
+///
+/// As JSON this is one line of `--format jsonl`, a checkpoint, or a file for `roget report`:
+///
+/// ```text
+/// {"schema":1,"algorithm":"naive","answer":"crane",
+///  "guesses":[{"word":"which","mask":["Wrong","Wrong","Wrong","Misplaced","Wrong"]},...],
+///  "score":5,"time":0.0012}
+/// ```
+///
+/// `score` is `null` for an unsolved game and `time` is in seconds.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Record {
+    /// See [`SCHEMA`].
+    pub schema: u32,
+    pub algorithm: Implementation,
+    #[serde(flatten)]
+    pub result: GameResult,
+    #[serde(serialize_with = "as_secs", deserialize_with = "from_secs")]
+    pub time: Duration,
+}
+
+impl Record {
+    pub const CSV_HEADER: [&'static str; 4] = ["answer", "guesses", "score", "time"];
+
+    /// This game as a CSV row matching [`Record::CSV_HEADER`].
+    pub fn csv_row(&self) -> [String; 4] {
+        let guesses: Vec<_> = self
+            .result
+            .guesses
+            .iter()
+            .map(|g| g.word.to_string())
+            .collect();
+        [
+            self.result.answer.to_string(),
+            guesses.join(" "),
+            self.result
+                .score
+                .map_or_else(String::new, |s| s.to_string()),
+            self.time.as_secs_f64().to_string(),
+        ]
+    }
+}
+
+/// Somewhere finished games are written as the run goes, so they survive an interruption.
+pub trait Sink: Sync {
+    fn save(&self, record: &Record);
+}
+
+fn as_secs<S: serde::Serializer>(time: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(time.as_secs_f64())
+}
+
+fn from_secs<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    let secs = f64::deserialize(deserializer)?;
+    Duration::try_from_secs_f64(secs).map_err(serde::de::Error::custom)
+}
+
+/// Knobs for a batch run.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub algorithm: Implementation,
+    pub format: Format,
+    /// Trace every guess of every game to stderr.
+    pub verbose: bool,
+    /// Save finished games here as the run goes.
+    pub checkpoint: Option<PathBuf>,
+    /// Skip games already saved in the checkpoint file.
+    pub resume: bool,
+    /// The rules games are played by, and the endgame size, if any; a checkpoint records
+    /// them along with the algorithm and dictionary.
+    pub rules: Rules,
+    pub endgame: Option<usize>,
+    /// Write a CSV row for every game here as it finishes.
+    pub out: Option<PathBuf>,
+    /// Log every word asked of the guesser here, as JSON lines.
+    pub record: Option<PathBuf>,
+    /// Store every game and its guesses in this SQLite database as it finishes.
+    #[cfg(feature = "sqlite")]
+    pub db: Option<PathBuf>,
+    pub thresholds: Thresholds,
+}
+
+pub fn play<G>(
+    wordle: &Wordle,
+    mk: impl Fn() -> G + Sync,
+    answers: &[&str],
+    options: Options,
+) -> anyhow::Result<()>
+where
+    G: Guesser,
+{
+    let (checkpoint, mut records) = match &options.checkpoint {
+        Some(path) => {
+            let header = Header {
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                algorithm: options.algorithm,
+                dictionary: format!("{:016x}", wordle.dictionary().fingerprint()),
+                rules: options.rules,
+                endgame: options.endgame,
+            };
+            let (checkpoint, done) = Checkpoint::open(path, options.resume, &header)?;
+            if !done.is_empty() {
+                eprintln!("resuming: {} games already done", done.len());
+            }
+            (Some(checkpoint), done)
+        }
+        None => (None, Vec::new()),
+    };
+    let out = options.out.as_deref().map(CsvOut::create).transpose()?;
+    let calls = options.record.as_deref().map(CallLog::create).transpose()?;
+    #[cfg(feature = "sqlite")]
+    let db = options
+        .db
+        .as_deref()
+        .map(super::db::Db::create)
+        .transpose()?;
+
+    let mut sinks: Vec<&dyn Sink> = Vec::new();
+    if let Some(checkpoint) = &checkpoint {
+        sinks.push(checkpoint);
+    }
+    if let Some(out) = &out {
+        // games carried over from a checkpoint belong in the results file too
+        for record in &records {
+            out.save(record);
+        }
+        sinks.push(out);
+    }
+    // unlike --out, games carried over from a checkpoint are not stored again: they went
+    // into the database with the run that played them
+    #[cfg(feature = "sqlite")]
+    if let Some(db) = &db {
+        sinks.push(db);
+    }
+
+    let finished = Checkpoint::finished(&records);
+    let answers: Vec<_> = answers
+        .iter()
+        .copied()
+        .filter(|answer| !finished.contains(*answer))
+        .collect();
+    records.extend(run(
+        wordle,
+        options.algorithm,
+        mk,
+        &answers,
+        &sinks,
+        calls.as_ref(),
+    ));
+    if let Some(checkpoint) = checkpoint {
+        checkpoint.finish()?;
+    }
+    if let Some(calls) = calls {
+        calls.finish()?;
+    }
+    if let Some(out) = out {
+        out.finish()?;
+    }
+    #[cfg(feature = "sqlite")]
+    if let Some(db) = db {
+        db.finish()?;
+    }
+
+    if options.verbose {
+        let mut err = io::stderr().lock();
+        for record in &records {
+            writeln!(err, "{}:", record.result.answer)?;
+            super::solve::trace(wordle, &record.result, &mut err)?;
+        }
+    }
+
+    match options.format {
+        Format::Summary => {
+            print_stats(&stats(&records));
+            print_failures(&records);
+        }
+        Format::Json => {
+            serde_json::to_writer(io::stdout().lock(), &records)?;
+            println!();
+        }
+        Format::Jsonl => super::records::write(&records, io::stdout().lock())?,
+        Format::Csv => {
+            let mut out = csv::Writer::from_writer(io::stdout().lock());
+            out.write_record(Record::CSV_HEADER)?;
+            for record in &records {
+                out.write_record(record.csv_row())?;
+            }
+            out.flush()?;
+        }
+    }
+
+    let violations = options.thresholds.check(&stats(&records));
+    if !violations.is_empty() {
+        anyhow::bail!("run missed its thresholds: {}", violations.join("; "));
+    }
+    Ok(())
+}
+
+/// Limits a run must stay within, so scripts can catch regressions by exit code.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Thresholds {
+    pub max_average: Option<f64>,
+    /// Unsolved games count as worse than any score.
+    pub max_worst: Option<usize>,
+}
+
+impl Thresholds {
+    /// Describe every limit `stats` exceeds.
+    fn check(&self, stats: &GameStats) -> Vec<String> {
+        let mut violations = Vec::new();
+        if let (Some(max), Some(average)) = (self.max_average, stats.mean()) {
+            if average > max {
+                violations.push(format!("average {average:.4} is above {max}"));
+            }
+        }
+        if let Some(max) = self.max_worst {
+            if stats.dnf() > 0 {
+                violations.push(format!("{} games were never solved", stats.dnf()));
+            } else if let Some(worst) = stats.worst().filter(|&worst| worst > max) {
+                violations.push(format!("worst game took {worst} guesses, more than {max}"));
+            }
+        }
+        violations
+    }
+}
+
+/// Real Wordle only allows this many guesses.
+const LIMIT: usize = 6;
+
+/// List every game that was not solved within [`LIMIT`] guesses, with how it went.
+pub fn print_failures(records: &[Record]) {
+    let failures: Vec<_> = records
+        .iter()
+        .filter(|r| r.result.score.is_none_or(|score| score > LIMIT))
+        .collect();
+    if failures.is_empty() {
+        return;
+    }
+
+    println!();
+    println!("{} games not solved in {LIMIT}:", failures.len());
+    for Record { result, .. } in failures {
+        let guesses: Vec<_> = result.guesses.iter().map(|g| g.word.to_string()).collect();
+        let score = result.score.map_or("DNF".to_string(), |s| s.to_string());
+        println!("  {} ({score}): {}", result.answer, guesses.join(" "));
+    }
+}
+
+/// Play every answer in parallel, showing progress as games finish and saving each one to
+/// every sink, and log every guess to `calls` if given.
+pub fn run<G>(
+    wordle: &Wordle,
+    algorithm: Implementation,
+    mk: impl Fn() -> G + Sync,
+    answers: &[&str],
+    sinks: &[&dyn Sink],
+    calls: Option<&CallLog>,
+) -> Vec<Record>
+where
+    G: Guesser,
+{
+    info!(games = answers.len(), "starting batch");
+    let progress = ProgressBar::new(answers.len() as u64).with_style(
+        ProgressStyle::with_template("{bar:40} {pos}/{len} games [{elapsed_precise}<{eta}] {msg}")
+            .expect("progress template is valid"),
+    );
+    let solved = AtomicUsize::new(0);
+    let total = AtomicUsize::new(0);
+
+    // every game is independent, so spread them over all cores
+    let records: Vec<_> = answers
+        .par_iter()
+        .map(|answer| {
+            let _span = info_span!("game", %answer).entered();
+            let start = Instant::now();
+            let result = match calls {
+                Some(calls) => {
+                    wordle.play_game(answer, Recorder::new(Traced(mk()), calls.game(answer)))
+                }
+                None => wordle.play_game(answer, Traced(mk())),
+            };
+            let time = start.elapsed();
+            debug!(score = ?result.score, ?time, "finished");
+            if let Some(score) = result.score {
+                let total = total.fetch_add(score, Ordering::Relaxed) + score;
+                let solved = solved.fetch_add(1, Ordering::Relaxed) + 1;
+                progress.set_message(format!("average {:.3}", total as f64 / solved as f64));
+            }
+            progress.inc(1);
+            let record = Record {
+                schema: SCHEMA,
+                algorithm,
+                result,
+                time,
+            };
+            for sink in sinks {
+                sink.save(&record);
+            }
+            record
+        })
+        .collect();
+    progress.finish_and_clear();
+    records
+}
+
+/// How `second` did against `first` on the same answers; both must come from runs over the
+/// same answers, which keep their order.
+pub fn paired(first: &[Record], second: &[Record]) -> Paired {
+    let scores =
+        |records: &[Record]| -> Vec<_> { records.iter().map(|r| r.result.score).collect() };
+    Paired::new(&scores(first), &scores(second))
+}
+
+/// Describe a paired comparison in a line, from the first algorithm's side.
+pub fn describe(paired: &Paired) -> String {
+    let (low, high) = paired.interval;
+    format!(
+        "mean difference {:+.4} guesses (95% CI {low:+.4} to {high:+.4}{}), \
+         {} wins, {} losses, {} ties, sign test p = {:.3}",
+        paired.mean_difference,
+        match paired.is_significant() {
+            true => "",
+            false => ", could be noise",
+        },
+        paired.wins,
+        paired.losses,
+        paired.ties,
+        paired.p_value
+    )
+}
+
+/// The score distribution of `records`.
+pub fn stats(records: &[Record]) -> GameStats {
+    records.iter().map(|record| &record.result).collect()
+}
+
+pub fn print_stats(stats: &GameStats) {
+    const BAR: usize = 40;
+
+    let widest = stats.histogram().iter().copied().max().unwrap_or(0);
+    let widest = widest.max(stats.dnf()).max(1);
+    let bar = |n: usize| "#".repeat((n * BAR).div_ceil(widest));
+
+    println!("games played: {}", stats.games());
+    for (i, &n) in stats.histogram().iter().enumerate() {
+        println!("{:>3}: {n:>5} {}", i + 1, bar(n));
+    }
+    println!("DNF: {:>5} {}", stats.dnf(), bar(stats.dnf()));
+
+    if let (Some(mean), Some(median), Some(p90)) =
+        (stats.mean(), stats.median(), stats.percentile(0.9))
+    {
+        println!("average score: {mean:.4} (over solved games)");
+        println!("median {median}, 90th percentile {p90}");
+    }
+    if let (Some(fastest), Some(mean), Some(slowest)) = (
+        stats.fastest_guess(),
+        stats.mean_guess_time(),
+        stats.slowest_guess(),
+    ) {
+        println!(
+            "time per guess: fastest {fastest:.2?}, average {mean:.2?}, slowest {slowest:.2?}"
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use std::time::{Duration, Instant};
+
+use clap::ValueEnum;
+use roget::Wordle;
+
+use super::batch;
+use crate::Implementation;
+
+/// Play the same answers with every algorithm and compare scores and speed.
+pub fn bench(wordle: &Wordle, answers: &[&str]) {
+    println!(
+        "{:<12} {:>6} {:>8} {:>6} {:>4} {:>10} {:>10}",
+        "algorithm", "games", "average", "worst", "DNF", "wall time", "per guess"
+    );
+    for &implementation in Implementation::value_variants() {
+        let start = Instant::now();
+        let factory = implementation.factory(wordle.dictionary());
+        let records = batch::run(wordle, implementation, &factory, answers, &[], None);
+        let wall = start.elapsed();
+
+        let solved: Vec<_> = records.iter().filter_map(|r| r.result.score).collect();
+        let dnf = records.len() - solved.len();
+        let average = solved.iter().sum::<usize>() as f64 / solved.len().max(1) as f64;
+        let worst = solved
+            .iter()
+            .max()
+            .map_or("-".to_string(), |w| w.to_string());
+        let guesses: usize = records.iter().map(|r| r.result.guesses.len()).sum();
+        let thinking: Duration = records.iter().map(|r| r.time).sum();
+        let per_guess = thinking / guesses.max(1) as u32;
+
+        let name = implementation
+            .to_possible_value()
+            .expect("no variants are skipped");
+        println!(
+            "{:<12} {:>6} {:>8.4} {:>6} {:>4} {:>10} {:>10}",
+            name.get_name(),
+            records.len(),
+            average,
+            worst,
+            dnf,
+            format!("{:.2?}", wall),
+            format!("{:.2?}", per_guess),
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! Results too slow to work out on every run, kept in the platform's cache directory
+//! (`$XDG_CACHE_HOME/roget` or `~/.cache/roget` on Linux) and named after the dictionary and
+//! settings they were computed for, so a different dictionary never reuses them.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use roget::{Dictionary, Matrix};
+
+/// The directory everything is cached in.
+pub fn dir() -> anyhow::Result<PathBuf> {
+    Ok(dirs::cache_dir()
+        .context("no cache directory on this system")?
+        .join("roget"))
+}
+
+/// Where `kind` is cached for `dictionary`, computed with `params`, as a `.extension` file.
+///
+/// The name covers how often each word occurs as well as the words, since most results
+/// weigh the answers by it; see [`words_path`] for those that do not.
+pub fn path(
+    kind: &str,
+    dictionary: &Dictionary,
+    params: &[&str],
+    extension: &str,
+) -> anyhow::Result<PathBuf> {
+    named(kind, dictionary.fingerprint(), params, extension)
+}
+
+/// Like [`path`], for results that only depend on which words the dictionary has, such as
+/// the [`Matrix`], so other counts or `--past-answers` do not compute them again.
+pub fn words_path(
+    kind: &str,
+    dictionary: &Dictionary,
+    params: &[&str],
+    extension: &str,
+) -> anyhow::Result<PathBuf> {
+    named(kind, Matrix::fingerprint(dictionary), params, extension)
+}
+
+fn named(
+    kind: &str,
+    fingerprint: u64,
+    params: &[&str],
+    extension: &str,
+) -> anyhow::Result<PathBuf> {
+    let mut name = format!("{kind}-{fingerprint:016x}");
+    for param in params {
+        name.push('-');
+        name.push_str(param);
+    }
+    name.push('.');
+    name.push_str(extension);
+    Ok(dir()?.join(name))
+}
+
+/// Read what is cached at `path` with `load`, or `compute` it and `save` it there first if it
+/// is missing or cannot be read.
+pub fn cached<T>(
+    path: &Path,
+    load: impl FnOnce(&Path) -> io::Result<T>,
+    compute: impl FnOnce() -> anyhow::Result<T>,
+    save: impl FnOnce(&T, &Path) -> io::Result<()>,
+) -> anyhow::Result<T> {
+    match load(path) {
+        Ok(value) => return Ok(value),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => tracing::warn!(path = %path.display(), "recomputing: {e}"),
+    }
+
+    let value = compute()?;
+    let dir = path.parent().expect("cache paths are in a directory");
+    std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+    save(&value, path).with_context(|| format!("writing {}", path.display()))?;
+    Ok(value)
+}
+
+/// List everything cached, with its size.
+pub fn info() -> anyhow::Result<()> {
+    let dir = dir()?;
+    println!("{}", dir.display());
+    let files = files(&dir)?;
+    let width = files
+        .iter()
+        .map(|(path, _)| name(path).len())
+        .max()
+        .unwrap_or(0);
+    for (path, size) in &files {
+        println!("  {:<width$} {:>10}", name(path), human(*size));
+    }
+    let total = files.iter().map(|&(_, size)| size).sum();
+    println!("{}, {}", count(files.len()), human(total));
+    Ok(())
+}
+
+/// Delete everything cached.
+pub fn clear() -> anyhow::Result<()> {
+    let dir = dir()?;
+    let files = files(&dir)?;
+    for (path, _) in &files {
+        std::fs::remove_file(path).with_context(|| format!("removing {}", path.display()))?;
+    }
+    let total = files.iter().map(|&(_, size)| size).sum();
+    println!(
+        "removed {}, {}, from {}",
+        count(files.len()),
+        human(total),
+        dir.display()
+    );
+    Ok(())
+}
+
+/// The files in `dir` with their sizes, by name; none if it does not exist yet.
+fn files(dir: &Path) -> anyhow::Result<Vec<(PathBuf, u64)>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("reading {}", dir.display())),
+    };
+    let mut files = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.is_file() {
+            files.push((entry.path(), metadata.len()));
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn count(files: usize) -> String {
+    match files {
+        1 => "1 file".to_string(),
+        n => format!("{n} files"),
+    }
+}
+
+fn name(path: &Path) -> String {
+    path.file_name()
+        .expect("directory entries have names")
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// `bytes` in the largest unit that keeps it at least 1.
+fn human(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use roget::Dictionary;
+
+    #[test]
+    fn counts_change_the_path() {
+        let dictionary = Dictionary::parse("crane 100\nslate 100\n").unwrap();
+        let recounted = Dictionary::parse("crane 100\nslate 50\n").unwrap();
+        let path = |dictionary| super::path("openers", dictionary, &["fast"], "txt").unwrap();
+        assert_ne!(path(&dictionary), path(&recounted));
+        assert_eq!(path(&dictionary), path(&dictionary.clone()));
+
+        let words_path = |dictionary| super::words_path("matrix", dictionary, &[], "bin").unwrap();
+        assert_eq!(words_path(&dictionary), words_path(&recounted));
+    }
+}
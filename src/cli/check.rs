@@ -0,0 +1,39 @@
+use std::io::{self, Read};
+
+use anyhow::Context;
+use roget::{SharedGame, Word, Wordle};
+
+use super::render::board;
+
+/// Check whether a posted share grid could have come from a real game against `answer`,
+/// and if so show guesses that give it. Reads the grid from stdin when `grid` is `None`.
+///
+/// Fails if the grid cannot be parsed or no game gives it, so scripts can tell by the exit
+/// code.
+pub fn check(wordle: &Wordle, answer: Word, grid: Option<&str>) -> anyhow::Result<()> {
+    let text = match grid {
+        Some(grid) => grid.to_string(),
+        None => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .context("reading the grid from stdin")?;
+            text
+        }
+    };
+    let game: SharedGame = text.parse()?;
+    match game.replay(answer, wordle.dictionary()) {
+        Ok(guesses) => {
+            println!("possible; for example:");
+            print!("{}", board(&guesses));
+            Ok(())
+        }
+        Err(impossible) => {
+            let mode = match game.hard_mode {
+                true => " in hard mode",
+                false => "",
+            };
+            anyhow::bail!("impossible against {answer}{mode}: {impossible}")
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use roget::Rules;
+use serde::{Deserialize, Serialize};
+
+use super::batch::{Record, Sink};
+use crate::Implementation;
+
+/// How often buffered results are flushed to disk.
+const INTERVAL: Duration = Duration::from_secs(5);
+
+/// What the games in a checkpoint were played with, written as its first line so that a
+/// run with other settings does not resume from it and mix the two.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Header {
+    /// The roget version, since a new one may play differently.
+    pub version: String,
+    pub algorithm: Implementation,
+    /// The dictionary's [`fingerprint`](roget::Dictionary::fingerprint), in hex.
+    pub dictionary: String,
+    pub rules: Rules,
+    pub endgame: Option<usize>,
+}
+
+/// How a [`Header`] appears in the file, telling it apart from the records after it.
+#[derive(Serialize, Deserialize)]
+struct Line {
+    checkpoint: Header,
+}
+
+impl Header {
+    /// The header on `line`, if that is what it holds.
+    pub fn parse(line: &str) -> Option<Self> {
+        serde_json::from_str::<Line>(line)
+            .ok()
+            .map(|line| line.checkpoint)
+    }
+}
+
+impl fmt::Display for Header {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let algorithm =
+            clap::ValueEnum::to_possible_value(&self.algorithm).expect("no variants are skipped");
+        write!(
+            f,
+            "roget {}, {}, dictionary {}, {} rules",
+            self.version,
+            algorithm.get_name(),
+            self.dictionary,
+            self.rules,
+        )?;
+        match self.endgame {
+            Some(max) => write!(f, ", endgame {max}"),
+            None => write!(f, ", no endgame"),
+        }
+    }
+}
+
+/// A JSON-lines file of finished games that a later run can pick up from.
+pub struct Checkpoint {
+    state: Mutex<State>,
+}
+
+struct State {
+    out: BufWriter<File>,
+    last_flush: Instant,
+    /// The first write error; later saves are skipped and it is reported by `finish`.
+    error: Option<io::Error>,
+}
+
+impl Checkpoint {
+    /// Start checkpointing to `path` the games played with the settings in `header`.
+    ///
+    /// With `resume`, the games already in the file are returned and new ones are appended;
+    /// otherwise the file is started over. Resuming fails with
+    /// [`io::ErrorKind::InvalidData`] if the file was written with other settings.
+    pub fn open(path: &Path, resume: bool, header: &Header) -> io::Result<(Self, Vec<Record>)> {
+        let mut text = String::new();
+        if resume {
+            match File::open(path) {
+                Ok(mut file) => {
+                    file.read_to_string(&mut text)?;
+                }
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+        }
+        // a run killed mid-write can leave a truncated last line; it is cut off, so the
+        // next game starts on a line of its own, and that game simply gets played again
+        let complete = text.rfind('\n').map_or(0, |end| end + 1);
+        let mut lines = text[..complete].lines();
+
+        let mut done = Vec::new();
+        let fresh = complete == 0;
+        if !fresh {
+            let invalid = |message| io::Error::new(io::ErrorKind::InvalidData, message);
+            let first = lines.next().unwrap_or_default();
+            match Header::parse(first) {
+                Some(found) if found == *header => {}
+                Some(found) => {
+                    return Err(invalid(format!(
+                        "{} was written by {found}, not {header}; leave out --resume to start \
+                         it over",
+                        path.display()
+                    )))
+                }
+                None => {
+                    return Err(invalid(format!(
+                        "{} does not start with a checkpoint header",
+                        path.display()
+                    )))
+                }
+            }
+            done.extend(lines.filter_map(|line| serde_json::from_str(line).ok()));
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(resume)
+            .truncate(!resume)
+            .open(path)?;
+        if resume {
+            file.set_len(complete as u64)?;
+        }
+        let mut out = BufWriter::new(file);
+        if fresh {
+            serde_json::to_writer(
+                &mut out,
+                &Line {
+                    checkpoint: header.clone(),
+                },
+            )?;
+            writeln!(out)?;
+            out.flush()?;
+        }
+        let checkpoint = Self {
+            state: Mutex::new(State {
+                out,
+                last_flush: Instant::now(),
+                error: None,
+            }),
+        };
+        Ok((checkpoint, done))
+    }
+
+    /// Answers that already have a result in `done`.
+    pub fn finished(done: &[Record]) -> HashSet<String> {
+        done.iter().map(|r| r.result.answer.to_string()).collect()
+    }
+
+    /// Flush everything still buffered and report any error hit while saving.
+    pub fn finish(self) -> io::Result<()> {
+        let mut state = self
+            .state
+            .into_inner()
+            .expect("checkpoint writer never panics");
+        if let Some(e) = state.error {
+            return Err(e);
+        }
+        state.out.flush()
+    }
+}
+
+impl Sink for Checkpoint {
+    fn save(&self, record: &Record) {
+        let mut state = self.state.lock().expect("checkpoint writer never panics");
+        if state.error.is_some() {
+            return;
+        }
+        if let Err(e) = state.write(record) {
+            state.error = Some(e);
+        }
+    }
+}
+
+impl State {
+    fn write(&mut self, record: &Record) -> io::Result<()> {
+        serde_json::to_writer(&mut self.out, record)?;
+        writeln!(self.out)?;
+        if self.last_flush.elapsed() >= INTERVAL {
+            self.out.flush()?;
+            self.last_flush = Instant::now();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::OpenOptions;
+    use std::io::{self, Write};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use roget::algorithms::Naive;
+    use roget::{Rules, Wordle};
+
+    use super::{Checkpoint, Header};
+    use crate::cli::batch::{Record, Sink, SCHEMA};
+    use crate::Implementation;
+
+    fn header() -> Header {
+        Header {
+            version: "0.1.0".to_string(),
+            algorithm: Implementation::Naive,
+            dictionary: "0123456789abcdef".to_string(),
+            rules: Rules::Normal,
+            endgame: None,
+        }
+    }
+
+    fn record(answer: &str) -> Record {
+        Record {
+            schema: SCHEMA,
+            algorithm: Implementation::Naive,
+            result: Wordle::new().play_game(answer, Naive::new()),
+            time: Duration::from_millis(1),
+        }
+    }
+
+    fn temp(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("roget-{}-{name}.jsonl", std::process::id()))
+    }
+
+    fn answers(done: &[Record]) -> Vec<String> {
+        done.iter().map(|r| r.result.answer.to_string()).collect()
+    }
+
+    #[test]
+    fn resumes_after_a_cut_off_line() {
+        let path = temp("cut-off");
+        let (checkpoint, done) = Checkpoint::open(&path, false, &header()).unwrap();
+        assert!(done.is_empty());
+        checkpoint.save(&record("crane"));
+        checkpoint.finish().unwrap();
+        // killed halfway through writing the next game
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(br#"{"schema":1,"algori"#).unwrap();
+
+        let (checkpoint, done) = Checkpoint::open(&path, true, &header()).unwrap();
+        assert_eq!(answers(&done), ["crane"]);
+        checkpoint.save(&record("slate"));
+        checkpoint.finish().unwrap();
+        let (_, done) = Checkpoint::open(&path, true, &header()).unwrap();
+        assert_eq!(answers(&done), ["crane", "slate"]);
+        // the header does not get in the way of reading the games back
+        let read = crate::cli::records::read(&path).unwrap();
+        assert_eq!(answers(&read), ["crane", "slate"]);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn refuses_other_settings() {
+        let path = temp("settings");
+        let (checkpoint, _) = Checkpoint::open(&path, false, &header()).unwrap();
+        checkpoint.save(&record("crane"));
+        checkpoint.finish().unwrap();
+
+        let hard = Header {
+            rules: Rules::Hard,
+            ..header()
+        };
+        let error = Checkpoint::open(&path, true, &hard).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("normal rules, no endgame, not"));
+        // starting over is always allowed
+        let (_, done) = Checkpoint::open(&path, false, &hard).unwrap();
+        assert!(done.is_empty());
+
+        std::fs::write(&path, "{}\n").unwrap();
+        let error = Checkpoint::open(&path, true, &header()).err().unwrap();
+        assert!(error.to_string().contains("checkpoint header"));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,102 @@
+use std::time::{Duration, Instant};
+
+use clap::ValueEnum;
+use roget::{GameStats, Wordle};
+
+use super::batch;
+use crate::Implementation;
+
+/// Play the same answers with each of `implementations` and print their score
+/// distributions side by side.
+pub fn compare(wordle: &Wordle, implementations: &[Implementation], answers: &[&str]) {
+    let mut columns = Vec::new();
+    let mut runs = Vec::new();
+    for &implementation in implementations {
+        let start = Instant::now();
+        let factory = implementation.factory(wordle.dictionary());
+        let records = batch::run(wordle, implementation, &factory, answers, &[], None);
+        columns.push((implementation, batch::stats(&records), start.elapsed()));
+        runs.push(records);
+    }
+
+    let rows = columns
+        .iter()
+        .map(|(_, stats, _)| stats.histogram().len())
+        .max()
+        .unwrap_or(0);
+
+    print!("{:<10}", "");
+    for (implementation, _, _) in &columns {
+        let name = implementation
+            .to_possible_value()
+            .expect("no variants are skipped");
+        print!(" {:>10}", name.get_name());
+    }
+    println!();
+
+    print!("{:<10}", "average");
+    for (_, stats, _) in &columns {
+        match stats.mean() {
+            Some(average) => print!(" {average:>10.4}"),
+            None => print!(" {:>10}", "-"),
+        }
+    }
+    println!();
+
+    for row in 0..rows {
+        print!("{:<10}", row + 1);
+        for (_, stats, _) in &columns {
+            print!(" {:>10}", stats.histogram().get(row).copied().unwrap_or(0));
+        }
+        println!();
+    }
+
+    print!("{:<10}", "DNF");
+    for (_, stats, _) in &columns {
+        print!(" {:>10}", stats.dnf());
+    }
+    println!();
+
+    print!("{:<10}", "time");
+    for (_, _, time) in &columns {
+        print!(" {:>10}", format!("{time:.2?}"));
+    }
+    println!();
+
+    type Timing = fn(&GameStats) -> Option<Duration>;
+    let guess_times: [(&str, Timing); 3] = [
+        ("min/guess", GameStats::fastest_guess),
+        ("avg/guess", GameStats::mean_guess_time),
+        ("max/guess", GameStats::slowest_guess),
+    ];
+    for (label, time) in guess_times {
+        print!("{label:<10}");
+        for (_, stats, _) in &columns {
+            match time(stats) {
+                Some(time) => print!(" {:>10}", format!("{time:.2?}")),
+                None => print!(" {:>10}", "-"),
+            }
+        }
+        println!();
+    }
+
+    // paired against the first, so each answer's difficulty cancels out
+    let name = |implementation: Implementation| {
+        let name = implementation
+            .to_possible_value()
+            .expect("no variants are skipped");
+        name.get_name().to_string()
+    };
+    if let Some((first, rest)) = runs.split_first() {
+        println!();
+        for ((implementation, _, _), records) in columns[1..].iter().zip(rest) {
+            let paired = batch::paired(first, records);
+            println!(
+                "{} vs {}: {}",
+                name(columns[0].0),
+                name(*implementation),
+                batch::describe(&paired)
+            );
+        }
+    }
+}
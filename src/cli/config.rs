@@ -0,0 +1,64 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use roget::{Language, Rules, Speed};
+use serde::Deserialize;
+
+use super::batch::Format;
+use crate::Implementation;
+
+/// The file read from the working directory when `--config` is not given.
+pub const DEFAULT_PATH: &str = "roget.toml";
+
+/// Settings from a `roget.toml`, so an experiment can be rerun without retyping flags.
+///
+/// Every key is optional and mirrors the command-line flag of the same name, which wins
+/// when both are given.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    pub implementation: Option<Implementation>,
+    pub games: Option<usize>,
+    pub random: Option<usize>,
+    pub seed: Option<u64>,
+    pub format: Option<Format>,
+    pub answers: Option<PathBuf>,
+    pub restrict_answers: Option<bool>,
+    pub exclude: Option<Vec<PathBuf>>,
+    pub past_answers: Option<f64>,
+    pub max_obscurity: Option<f64>,
+    pub endgame: Option<usize>,
+    pub speed: Option<Speed>,
+    pub rules: Option<Rules>,
+    pub language: Option<Language>,
+    pub dictionary: Option<PathBuf>,
+    pub checkpoint: Option<PathBuf>,
+    pub out: Option<PathBuf>,
+    pub record: Option<PathBuf>,
+    #[cfg(feature = "sqlite")]
+    pub db: Option<PathBuf>,
+    /// How many threads batch runs may use; all cores if unset.
+    pub threads: Option<usize>,
+    /// How many megabytes cached tables like the pattern matrix may take; no cap if unset.
+    pub memory: Option<usize>,
+    pub max_average: Option<f64>,
+    pub max_worst: Option<usize>,
+}
+
+impl Config {
+    /// Read `path`, or `roget.toml` if it exists when no path is given.
+    pub fn load(path: Option<&Path>) -> anyhow::Result<Self> {
+        let (path, required) = match path {
+            Some(path) => (path, true),
+            None => (Path::new(DEFAULT_PATH), false),
+        };
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if !required && e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Self::default())
+            }
+            Err(e) => return Err(e).with_context(|| format!("reading {}", path.display())),
+        };
+        toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+}
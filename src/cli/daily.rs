@@ -0,0 +1,108 @@
+use anyhow::bail;
+use chrono::NaiveDate;
+use roget::{Board, Guesser, ShareGrid, Word, Wordle};
+
+/// The day puzzle #0 was published; puzzle `n` is `n` days later.
+const FIRST_PUZZLE: NaiveDate = match NaiveDate::from_ymd_opt(2021, 6, 19) {
+    Some(date) => date,
+    None => panic!("launch date is valid"),
+};
+
+/// The New York Times' puzzle for a day, as its endpoint describes it.
+#[cfg(feature = "network")]
+#[derive(Debug, serde::Deserialize)]
+struct Puzzle {
+    solution: String,
+    days_since_launch: usize,
+}
+
+/// Ask the New York Times for the puzzle published on `date`.
+#[cfg(feature = "network")]
+fn fetch(date: NaiveDate) -> anyhow::Result<(usize, Word)> {
+    use anyhow::Context;
+
+    let url = format!("https://www.nytimes.com/svc/wordle/v2/{date}.json");
+    let body = ureq::get(&url)
+        .call()
+        .with_context(|| format!("downloading {url}"))?
+        .into_string()?;
+    let puzzle: Puzzle = serde_json::from_str(&body).with_context(|| format!("parsing {url}"))?;
+    let answer = puzzle
+        .solution
+        .parse()
+        .with_context(|| format!("{url} has solution {:?}", puzzle.solution))?;
+    Ok((puzzle.days_since_launch, answer))
+}
+
+/// The number of the puzzle published on `date`.
+pub fn number(date: NaiveDate) -> anyhow::Result<usize> {
+    match usize::try_from((date - FIRST_PUZZLE).num_days()) {
+        Ok(number) => Ok(number),
+        Err(_) => bail!("there was no Wordle before {FIRST_PUZZLE}"),
+    }
+}
+
+/// The puzzle number and answer for `date`, by position in `answers`, which matches the
+/// official game as long as the list is in its original publication order.
+fn lookup(date: NaiveDate, answers: &[&str]) -> anyhow::Result<(usize, Word)> {
+    let number = number(date)?;
+    if answers.is_empty() {
+        bail!("no answers to pick the daily word from");
+    }
+    let answer = answers[number % answers.len()];
+    Ok((number, answer.parse()?))
+}
+
+/// Solve the puzzle published on `date`, then print a spoiler-free share grid.
+///
+/// With the `network` feature the answer comes from the New York Times, falling back to
+/// looking it up in `answers` if that fails; see [`lookup`].
+pub fn daily(
+    wordle: &Wordle,
+    date: NaiveDate,
+    answers: &[&str],
+    guesser: impl Guesser,
+) -> anyhow::Result<()> {
+    #[cfg(feature = "network")]
+    let puzzle = fetch(date).or_else(|e| {
+        eprintln!("could not fetch the puzzle ({e:#}); using the answer list instead");
+        lookup(date, answers)
+    });
+    #[cfg(not(feature = "network"))]
+    let puzzle = lookup(date, answers);
+    let (number, answer) = puzzle?;
+    if !wordle.candidates(&[]).any(|(word, _)| word == answer) {
+        bail!("puzzle {number}'s answer is not one of the possible answers");
+    }
+
+    let result = wordle.play_game(&answer.to_string(), guesser);
+    println!("{}", Board::new(&result.guesses));
+    println!();
+
+    println!("{}", ShareGrid::new(&result.guesses).puzzle(number));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{lookup, FIRST_PUZZLE};
+
+    #[test]
+    fn lookup_by_position() {
+        let date = NaiveDate::from_ymd_opt(2021, 6, 21).unwrap();
+        let (number, answer) = lookup(date, &["cigar", "rebut", "sissy"]).unwrap();
+        assert_eq!(number, 2);
+        assert_eq!(answer, "sissy");
+        // the list wraps around once it runs out
+        let (_, answer) = lookup(date, &["cigar", "rebut"]).unwrap();
+        assert_eq!(answer, "cigar");
+    }
+
+    #[test]
+    fn lookup_without_answers() {
+        let error = lookup(FIRST_PUZZLE, &[]).unwrap_err();
+        assert_eq!(error.to_string(), "no answers to pick the daily word from");
+    }
+}
@@ -0,0 +1,278 @@
+use std::path::Path;
+use std::sync::Mutex;
+
+use clap::ValueEnum;
+use roget::{Correctness, Elo};
+use rusqlite::{params, Connection};
+
+use super::batch::{Record, Sink};
+
+/// Tables are only ever added to, so a database can collect runs from every version.
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS runs (
+        id INTEGER PRIMARY KEY,
+        started TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS games (
+        id INTEGER PRIMARY KEY,
+        run INTEGER NOT NULL REFERENCES runs (id),
+        algorithm TEXT NOT NULL,
+        answer TEXT NOT NULL,
+        -- NULL when the game was never solved
+        score INTEGER,
+        -- seconds
+        time REAL NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS games_by_algorithm ON games (algorithm);
+    CREATE INDEX IF NOT EXISTS games_by_answer ON games (answer);
+    CREATE TABLE IF NOT EXISTS guesses (
+        game INTEGER NOT NULL REFERENCES games (id),
+        -- 1 for the opener
+        turn INTEGER NOT NULL,
+        word TEXT NOT NULL,
+        -- as typed for `roget assist`: g (green), y (yellow), x (gray)
+        mask TEXT NOT NULL,
+        PRIMARY KEY (game, turn)
+    ) WITHOUT ROWID;
+    CREATE INDEX IF NOT EXISTS guesses_by_word ON guesses (word);
+    -- each entrant's rating after every `roget tournament` run it played in
+    CREATE TABLE IF NOT EXISTS ratings (
+        run INTEGER NOT NULL REFERENCES runs (id),
+        -- an algorithm or plugin name
+        name TEXT NOT NULL,
+        rating REAL NOT NULL,
+        -- matches played over every run so far
+        matches INTEGER NOT NULL,
+        PRIMARY KEY (run, name)
+    );
+";
+
+/// A SQLite database that gets every finished game, and each of its guesses, as a row.
+///
+/// Each batch run is a row in `runs`, so results from many experiments can live side by side
+/// and be compared with plain SQL or the helpers below.
+pub struct Db {
+    state: Mutex<State>,
+}
+
+struct State {
+    conn: Connection,
+    run: i64,
+    /// The first write error; later games are skipped and it is reported by `finish`.
+    error: Option<rusqlite::Error>,
+}
+
+/// Open the database at `path`, creating it and its tables if needed.
+pub fn open(path: &Path) -> rusqlite::Result<Connection> {
+    let conn = Connection::open(path)?;
+    conn.execute_batch(SCHEMA)?;
+    Ok(conn)
+}
+
+impl Db {
+    /// Start a new run in the database at `path`.
+    pub fn create(path: &Path) -> rusqlite::Result<Self> {
+        let conn = open(path)?;
+        let run = start_run(&conn)?;
+        Ok(Self {
+            state: Mutex::new(State {
+                conn,
+                run,
+                error: None,
+            }),
+        })
+    }
+
+    /// Report any error hit while saving games.
+    pub fn finish(self) -> rusqlite::Result<()> {
+        let state = self
+            .state
+            .into_inner()
+            .expect("database writer never panics");
+        match state.error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Add a row to `runs` for a run starting now, and return its id.
+fn start_run(conn: &Connection) -> rusqlite::Result<i64> {
+    conn.execute(
+        "INSERT INTO runs (started) VALUES (?1)",
+        [chrono::Local::now().to_rfc3339()],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+impl Sink for Db {
+    fn save(&self, record: &Record) {
+        let mut state = self.state.lock().expect("database writer never panics");
+        if state.error.is_some() {
+            return;
+        }
+        if let Err(e) = state.write(record) {
+            state.error = Some(e);
+        }
+    }
+}
+
+impl State {
+    fn write(&mut self, record: &Record) -> rusqlite::Result<()> {
+        let algorithm = record
+            .algorithm
+            .to_possible_value()
+            .expect("no variants are skipped");
+
+        // a game and its guesses land together or not at all
+        let tx = self.conn.transaction()?;
+        tx.execute(
+            "INSERT INTO games (run, algorithm, answer, score, time) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                self.run,
+                algorithm.get_name(),
+                record.result.answer.to_string(),
+                record.result.score,
+                record.time.as_secs_f64(),
+            ],
+        )?;
+        let game = tx.last_insert_rowid();
+        {
+            let mut insert = tx.prepare_cached(
+                "INSERT INTO guesses (game, turn, word, mask) VALUES (?1, ?2, ?3, ?4)",
+            )?;
+            for (turn, guess) in (1..).zip(&record.result.guesses) {
+                insert.execute(params![
+                    game,
+                    turn,
+                    guess.word.to_string(),
+                    mask(guess.mask)
+                ])?;
+            }
+        }
+        tx.commit()
+    }
+}
+
+fn mask(mask: [Correctness; 5]) -> String {
+    mask.iter().map(|c| format!("{c:#}")).collect()
+}
+
+/// How one algorithm, or how one answer, has done over every game stored.
+#[derive(Debug)]
+pub struct Summary {
+    pub name: String,
+    pub games: usize,
+    /// Games never solved.
+    pub dnf: usize,
+    /// The mean score over solved games.
+    pub average: Option<f64>,
+}
+
+const SUMMARY: &str = "COUNT(*), COUNT(*) - COUNT(score), AVG(score)";
+
+fn summary(row: &rusqlite::Row<'_>) -> rusqlite::Result<Summary> {
+    Ok(Summary {
+        name: row.get(0)?,
+        games: row.get(1)?,
+        dnf: row.get(2)?,
+        average: row.get(3)?,
+    })
+}
+
+/// Every algorithm in the database, best average first.
+pub fn averages(conn: &Connection) -> rusqlite::Result<Vec<Summary>> {
+    conn.prepare(&format!(
+        "SELECT algorithm, {SUMMARY} FROM games GROUP BY algorithm
+         ORDER BY AVG(score) IS NULL, AVG(score), algorithm"
+    ))?
+    .query_map([], summary)?
+    .collect()
+}
+
+/// The `limit` answers that took the most guesses, counting any unsolved game as worse than
+/// every solved one.
+pub fn hardest(conn: &Connection, limit: usize) -> rusqlite::Result<Vec<Summary>> {
+    conn.prepare(&format!(
+        "SELECT answer, {SUMMARY} FROM games GROUP BY answer
+         ORDER BY COUNT(*) - COUNT(score) DESC, AVG(score) DESC, answer LIMIT ?1"
+    ))?
+    .query_map([limit], summary)?
+    .collect()
+}
+
+/// Print `summaries` as a table headed by `what` they summarize.
+pub fn print(what: &str, summaries: &[Summary]) {
+    println!("{what:<12} {:>7} {:>5} {:>8}", "games", "DNF", "average");
+    for s in summaries {
+        let average = s.average.map_or("-".to_string(), |a| format!("{a:.4}"));
+        println!("{:<12} {:>7} {:>5} {average:>8}", s.name, s.games, s.dnf);
+    }
+}
+
+/// The latest rating of each of `names`, or a fresh one for any never rated.
+pub fn ratings(conn: &Connection, names: &[String]) -> rusqlite::Result<Vec<Elo>> {
+    let mut latest = conn
+        .prepare("SELECT rating, matches FROM ratings WHERE name = ?1 ORDER BY run DESC LIMIT 1")?;
+    names
+        .iter()
+        .map(|name| {
+            let mut rows = latest.query_map([name], elo)?;
+            rows.next().unwrap_or(Ok(Elo::new()))
+        })
+        .collect()
+}
+
+/// Store `ratings` as a new run's.
+pub fn rate(conn: &mut Connection, ratings: &[(String, Elo)]) -> rusqlite::Result<()> {
+    let tx = conn.transaction()?;
+    let run = start_run(&tx)?;
+    for (name, elo) in ratings {
+        tx.execute(
+            "INSERT INTO ratings (run, name, rating, matches) VALUES (?1, ?2, ?3, ?4)",
+            params![run, name, elo.rating, elo.matches],
+        )?;
+    }
+    tx.commit()
+}
+
+fn elo(row: &rusqlite::Row<'_>) -> rusqlite::Result<Elo> {
+    Ok(Elo {
+        rating: row.get(0)?,
+        matches: row.get(1)?,
+    })
+}
+
+/// Everything ever rated with its latest rating, highest first.
+pub fn leaderboard(conn: &Connection) -> rusqlite::Result<Vec<(String, Elo)>> {
+    conn.prepare(
+        "SELECT rating, matches, name FROM ratings AS r
+         WHERE run = (SELECT MAX(run) FROM ratings WHERE name = r.name)
+         ORDER BY rating DESC, name",
+    )?
+    .query_map([], |row| Ok((row.get(2)?, elo(row)?)))?
+    .collect()
+}
+
+/// When `name` was rated and what it was rated, oldest first.
+pub fn history(conn: &Connection, name: &str) -> rusqlite::Result<Vec<(String, Elo)>> {
+    conn.prepare(
+        "SELECT rating, matches, started FROM ratings JOIN runs ON runs.id = ratings.run
+         WHERE name = ?1 ORDER BY run",
+    )?
+    .query_map([name], |row| Ok((row.get(2)?, elo(row)?)))?
+    .collect()
+}
+
+/// Print ratings as a table headed by `what` each row is: an algorithm, or when it was
+/// rated.
+pub fn print_ratings(what: &str, ratings: &[(String, Elo)]) {
+    let width = ratings
+        .iter()
+        .map(|(name, _)| name.len())
+        .fold(12, usize::max);
+    println!("{what:<width$} {:>7} {:>7}", "rating", "matches");
+    for (name, elo) in ratings {
+        println!("{name:<width$} {:>7.1} {:>7}", elo.rating, elo.matches);
+    }
+}
@@ -0,0 +1,81 @@
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use roget::dictionary::Combine;
+use roget::{Dictionary, Language};
+
+/// Where `dict update` saves the word list for `language`, which is then used in place of
+/// the built-in one.
+pub fn installed(language: Language) -> Option<PathBuf> {
+    Some(
+        dirs::data_dir()?
+            .join("roget")
+            .join(format!("{language}.txt")),
+    )
+}
+
+/// Merge the word lists in `files` into one, written to `out` or stdout.
+pub fn merge(files: &[PathBuf], combine: Combine, out: Option<&Path>) -> anyhow::Result<()> {
+    let dictionaries = files
+        .iter()
+        .map(|path| Dictionary::load(path).with_context(|| format!("loading {}", path.display())))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let total: usize = dictionaries.iter().map(Dictionary::len).sum();
+    let merged = Dictionary::merge(&dictionaries, combine);
+
+    match out {
+        Some(path) => std::fs::write(path, merged.to_string())
+            .with_context(|| format!("writing {}", path.display()))?,
+        None => io::stdout()
+            .lock()
+            .write_all(merged.to_string().as_bytes())?,
+    }
+    eprintln!(
+        "merged {} files into {} words ({} duplicates, {} of the wrong length skipped)",
+        files.len(),
+        merged.len(),
+        total - merged.len(),
+        merged.skipped()
+    );
+    Ok(())
+}
+
+/// Download a word list from `url`, check that it parses, and install it for `language`.
+#[cfg(feature = "network")]
+pub fn update(url: &str, language: Language) -> anyhow::Result<()> {
+    let path = installed(language).context("no data directory on this system")?;
+    let text = ureq::get(url)
+        .call()
+        .with_context(|| format!("downloading {url}"))?
+        .into_string()?;
+
+    let dictionary = Dictionary::parse(&text).with_context(|| format!("parsing {url}"))?;
+    anyhow::ensure!(
+        !dictionary.is_empty(),
+        "{url} has no {}-letter words",
+        roget::Word::LEN
+    );
+    if let Some((word, _)) = dictionary
+        .words()
+        .iter()
+        .find(|&&(w, _)| !language.allows(w))
+    {
+        anyhow::bail!("\"{word}\" uses letters outside the {language} alphabet");
+    }
+
+    let dir = path.parent().expect("installed paths are in a directory");
+    std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+    // write next to the old list and swap it in, so a failed write never leaves half a file
+    let partial = path.with_extension("txt.partial");
+    std::fs::write(&partial, dictionary.to_string())
+        .with_context(|| format!("writing {}", partial.display()))?;
+    std::fs::rename(&partial, &path).with_context(|| format!("writing {}", path.display()))?;
+    eprintln!(
+        "installed {} {language} words to {} ({} of the wrong length skipped)",
+        dictionary.len(),
+        path.display(),
+        dictionary.skipped()
+    );
+    Ok(())
+}
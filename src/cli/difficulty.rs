@@ -0,0 +1,105 @@
+use std::io;
+
+use clap::ValueEnum;
+use roget::{Word, Wordle};
+
+use super::batch;
+use crate::Implementation;
+
+struct Row {
+    answer: Word,
+    /// Each algorithm's score, in the order they were given.
+    scores: Vec<Option<usize>>,
+}
+
+impl Row {
+    fn unsolved(&self) -> usize {
+        self.scores.iter().filter(|s| s.is_none()).count()
+    }
+
+    /// The mean over the algorithms that solved it.
+    fn average(&self) -> Option<f64> {
+        let solved: Vec<usize> = self.scores.iter().flatten().copied().collect();
+        (!solved.is_empty()).then(|| solved.iter().sum::<usize>() as f64 / solved.len() as f64)
+    }
+}
+
+/// Rank `answers` from hardest to easiest by how many guesses `implementations` take on
+/// them, averaged over the algorithms, and print the `limit` hardest as a table or CSV.
+///
+/// An answer some algorithm never solved ranks above every one they all solved.
+pub fn difficulty(
+    wordle: &Wordle,
+    implementations: &[Implementation],
+    answers: &[&str],
+    limit: Option<usize>,
+    csv: bool,
+) -> anyhow::Result<()> {
+    let runs: Vec<_> = implementations
+        .iter()
+        .map(|&implementation| {
+            let factory = implementation.factory(wordle.dictionary());
+            batch::run(wordle, implementation, &factory, answers, &[], None)
+        })
+        .collect();
+    // batch runs keep the order of `answers`, so the games line up
+    let mut rows: Vec<Row> = (0..answers.len())
+        .map(|i| Row {
+            answer: runs[0][i].result.answer,
+            scores: runs.iter().map(|run| run[i].result.score).collect(),
+        })
+        .collect();
+    rows.sort_by(|a, b| {
+        b.unsolved()
+            .cmp(&a.unsolved())
+            .then_with(|| {
+                b.average()
+                    .unwrap_or(0.0)
+                    .total_cmp(&a.average().unwrap_or(0.0))
+            })
+            .then(a.answer.cmp(&b.answer))
+    });
+    rows.truncate(limit.unwrap_or(usize::MAX));
+
+    let names: Vec<String> = implementations
+        .iter()
+        .map(|implementation| {
+            let name = implementation
+                .to_possible_value()
+                .expect("no variants are skipped");
+            name.get_name().to_string()
+        })
+        .collect();
+    let score = |score: Option<usize>| score.map_or("DNF".to_string(), |s| s.to_string());
+    let average = |row: &Row| row.average().map_or("-".to_string(), |a| format!("{a:.2}"));
+
+    if csv {
+        let mut out = csv::Writer::from_writer(io::stdout().lock());
+        let mut header = vec!["rank".to_string(), "answer".to_string()];
+        header.extend(names);
+        header.push("average".to_string());
+        out.write_record(&header)?;
+        for (i, row) in rows.iter().enumerate() {
+            let mut record = vec![(i + 1).to_string(), row.answer.to_string()];
+            record.extend(row.scores.iter().map(|&s| score(s)));
+            record.push(average(row));
+            out.write_record(&record)?;
+        }
+        out.flush()?;
+        return Ok(());
+    }
+
+    print!("{:>5} {:<8}", "rank", "answer");
+    for name in &names {
+        print!(" {name:>10}");
+    }
+    println!(" {:>8}", "average");
+    for (i, row) in rows.iter().enumerate() {
+        print!("{:>5} {:<8}", i + 1, row.answer);
+        for &s in &row.scores {
+            print!(" {:>10}", score(s));
+        }
+        println!(" {:>8}", average(row));
+    }
+    Ok(())
+}
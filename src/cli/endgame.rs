@@ -0,0 +1,46 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use clap::ValueEnum;
+use roget::{Endgame, Tablebase, Wordle};
+
+use crate::{Factory, Implementation};
+
+/// Wrap the guessers `factory` starts so they play endgames of at most `max` answers from a
+/// [`Tablebase`], filled by playing `implementation` against every answer. The table is
+/// cached, as filling it takes a while.
+pub fn endgame(
+    wordle: &Wordle,
+    implementation: Implementation,
+    max: usize,
+    factory: Factory,
+) -> anyhow::Result<Factory> {
+    let dictionary = wordle.dictionary();
+    let name = implementation
+        .to_possible_value()
+        .expect("no variants are skipped");
+    let max_param = max.to_string();
+    let params = [env!("CARGO_PKG_VERSION"), name.get_name(), &max_param];
+    let path = super::cache::path("endgame", dictionary, &params, "txt")?;
+    let table = super::cache::cached(
+        &path,
+        |path| Tablebase::load(path, dictionary),
+        || {
+            let start = Instant::now();
+            let mut table = Tablebase::new(dictionary, max);
+            table.fill(&factory);
+            eprintln!(
+                "solved {} endgames of up to {max} answers in {:.1?}",
+                table.len(),
+                start.elapsed()
+            );
+            Ok(table)
+        },
+        |table, path| table.save(path, dictionary),
+    )?;
+
+    let table = Arc::new(table);
+    Ok(Box::new(move || {
+        Box::new(Endgame::new(factory(), Arc::clone(&table)))
+    }))
+}
@@ -0,0 +1,128 @@
+//! A line-based protocol for driving the solver from other programs, in the spirit of UCI
+//! for chess engines.
+//!
+//! Each line on stdin is one command; replies go to stdout, one per line:
+//!
+//! | command                  | reply                                         |
+//! |--------------------------|-----------------------------------------------|
+//! | `roget`                  | `id name roget <version>`, then `rogetok`     |
+//! | `isready`                | `readyok`                                     |
+//! | `algorithm <name>`       | nothing; later games use that algorithm       |
+//! | `newgame`                | nothing; forgets all feedback                 |
+//! | `feedback <word> <mask>` | nothing; records that `<word>` got `<mask>`   |
+//! | `go`                     | `bestguess <word>`                            |
+//! | `candidates [<n>]`       | `candidates <count> <word>...`, up to n words |
+//! | `quit`                   | ends the session                              |
+//!
+//! Masks are in any notation `assist` reads, such as the `g`/`y`/`x` letters. Anything that
+//! cannot be carried out gets `error <reason>` instead, and the session goes on.
+
+use std::io::{self, BufRead, Write};
+
+use clap::ValueEnum;
+use roget::{parse_mask, Guess, Word, Wordle};
+
+use crate::{Factory, Implementation};
+
+/// How many words `candidates` lists when not told.
+const CANDIDATES: usize = 10;
+
+struct Engine<'a> {
+    wordle: &'a Wordle,
+    factory: Factory,
+    history: Vec<Guess>,
+}
+
+impl Engine<'_> {
+    /// Carry out one command, or say why not.
+    fn command(&mut self, line: &str, out: &mut impl Write) -> io::Result<Result<(), String>> {
+        let mut words = line.split_whitespace();
+        let Some(command) = words.next() else {
+            return Ok(Ok(()));
+        };
+        let arguments: Vec<&str> = words.collect();
+        match (command, arguments.as_slice()) {
+            ("roget", []) => {
+                writeln!(out, "id name roget {}", env!("CARGO_PKG_VERSION"))?;
+                writeln!(out, "rogetok")?;
+            }
+            ("isready", []) => writeln!(out, "readyok")?,
+            ("algorithm", [name]) => match Implementation::from_str(name, true) {
+                Ok(implementation) => {
+                    self.factory = implementation.factory(self.wordle.dictionary())
+                }
+                Err(_) => return Ok(Err(format!("unknown algorithm {name}"))),
+            },
+            ("newgame", []) => self.history.clear(),
+            ("feedback", [word, mask]) => {
+                let word: Word = match word.parse() {
+                    Ok(word) => word,
+                    Err(e) => return Ok(Err(format!("{word}: {e}"))),
+                };
+                if let Err(e) = self.wordle.check(word) {
+                    return Ok(Err(e.to_string()));
+                }
+                match parse_mask(mask) {
+                    Ok(mask) => self.history.push(Guess { word, mask }),
+                    Err(e) => return Ok(Err(format!("{mask}: {e}"))),
+                }
+            }
+            ("go", []) => {
+                if self.wordle.candidates(&self.history).next().is_none() {
+                    return Ok(Err("no answer matches the feedback".to_string()));
+                }
+                // guessers keep state between turns, so take a fresh one through the game
+                let mut guesser = (self.factory)();
+                let mut guess = guesser.guess(&[]);
+                for turn in 1..=self.history.len() {
+                    guess = guesser.guess(&self.history[..turn]);
+                }
+                writeln!(out, "bestguess {guess}")?;
+            }
+            ("candidates", [] | [_]) => {
+                let limit = match arguments.first() {
+                    Some(n) => match n.parse() {
+                        Ok(n) => n,
+                        Err(_) => return Ok(Err(format!("{n} is not a number"))),
+                    },
+                    None => CANDIDATES,
+                };
+                let mut candidates: Vec<_> = self.wordle.candidates(&self.history).collect();
+                candidates.sort_by(|(a, x), (b, y)| y.cmp(x).then(a.cmp(b)));
+                write!(out, "candidates {}", candidates.len())?;
+                for (word, _) in candidates.iter().take(limit) {
+                    write!(out, " {word}")?;
+                }
+                writeln!(out)?;
+            }
+            _ => return Ok(Err(format!("unknown command: {line}"))),
+        }
+        Ok(Ok(()))
+    }
+}
+
+/// Answer commands from `input` on `output` until `quit` or the end of input.
+pub fn engine(
+    wordle: &Wordle,
+    implementation: Implementation,
+    input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<()> {
+    let mut engine = Engine {
+        wordle,
+        factory: implementation.factory(wordle.dictionary()),
+        history: Vec::new(),
+    };
+    for line in input.lines() {
+        let line = line?;
+        if line.trim() == "quit" {
+            break;
+        }
+        if let Err(reason) = engine.command(&line, &mut output)? {
+            writeln!(output, "error {reason}")?;
+        }
+        // whoever is on the other end is waiting for this reply before sending more
+        output.flush()?;
+    }
+    Ok(())
+}
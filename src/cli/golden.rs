@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::ValueEnum;
+use roget::Wordle;
+
+use super::batch;
+use crate::Implementation;
+
+/// Where each algorithm's snapshot lives in `dir`.
+fn path(dir: &Path, implementation: Implementation) -> PathBuf {
+    let name = implementation
+        .to_possible_value()
+        .expect("no variants are skipped");
+    dir.join(format!("{}.txt", name.get_name()))
+}
+
+/// Play `answers` with every algorithm and compare each game's score with the snapshot in
+/// `dir`, failing if any changed; with `update`, write new snapshots instead.
+///
+/// The snapshots are for the default dictionary and answers, so refactors can be checked
+/// not to change what any algorithm plays.
+pub fn golden(wordle: &Wordle, answers: &[&str], dir: &Path, update: bool) -> anyhow::Result<()> {
+    let mut changed = 0;
+    for &implementation in Implementation::value_variants() {
+        let factory = implementation.factory(wordle.dictionary());
+        let records = batch::run(wordle, implementation, &factory, answers, &[], None);
+        let path = path(dir, implementation);
+
+        if update {
+            let mut out = String::from("# answer, then guesses taken or - if unsolved\n");
+            for record in &records {
+                let score = record
+                    .result
+                    .score
+                    .map_or("-".to_string(), |s| s.to_string());
+                writeln!(out, "{} {score}", record.result.answer)?;
+            }
+            std::fs::create_dir_all(dir)?;
+            std::fs::write(&path, out).with_context(|| format!("writing {}", path.display()))?;
+            eprintln!("wrote {} scores to {}", records.len(), path.display());
+            continue;
+        }
+
+        let text = std::fs::read_to_string(&path)
+            .with_context(|| format!("reading {}", path.display()))?;
+        let expected = parse(&text).with_context(|| format!("parsing {}", path.display()))?;
+        for record in &records {
+            let answer = record.result.answer.to_string();
+            let got = record.result.score;
+            match expected.get(answer.as_str()) {
+                Some(&score) if score == got => {}
+                Some(&score) => {
+                    changed += 1;
+                    println!(
+                        "{}: {answer} went from {} to {}",
+                        path.display(),
+                        show(score),
+                        show(got)
+                    );
+                }
+                None => {
+                    changed += 1;
+                    println!("{}: {answer} is missing", path.display());
+                }
+            }
+        }
+    }
+
+    if changed > 0 {
+        anyhow::bail!(
+            "{changed} scores differ from the snapshots; rerun with --update if that is intended"
+        );
+    }
+    if !update {
+        eprintln!("every score matches the snapshots in {}", dir.display());
+    }
+    Ok(())
+}
+
+fn show(score: Option<usize>) -> String {
+    score.map_or("unsolved".to_string(), |s| s.to_string())
+}
+
+/// `answer score` per line, with `-` for an unsolved game and `#` starting a comment.
+fn parse(text: &str) -> anyhow::Result<BTreeMap<&str, Option<usize>>> {
+    let mut scores = BTreeMap::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (answer, score) = line
+            .split_once(' ')
+            .with_context(|| format!("line {}: expected `answer score`", i + 1))?;
+        let score = match score.trim() {
+            "-" => None,
+            score => Some(score.parse().with_context(|| format!("line {}", i + 1))?),
+        };
+        scores.insert(answer, score);
+    }
+    Ok(scores)
+}
@@ -0,0 +1,47 @@
+use anyhow::Context;
+use roget::{Runtime, Speed, Word, Wordle};
+
+use super::render::tiles;
+
+/// Grade a game played by hand against `answer`, guess by guess: how close each came to the
+/// best guess there was, and how lucky the colors it got were. `speed` decides how many
+/// guesses the search for the best one tries.
+pub fn grade(
+    wordle: &Wordle,
+    answer: Word,
+    guesses: &[Word],
+    speed: Speed,
+    runtime: &Runtime,
+) -> anyhow::Result<()> {
+    anyhow::ensure!(!guesses.is_empty(), "there are no guesses to grade");
+    for &word in std::iter::once(&answer).chain(guesses) {
+        wordle.check(word)?;
+    }
+    let matrix = super::matrix::cached(wordle.dictionary(), runtime)?;
+    let grade = roget::grade(&matrix, answer, guesses, speed)
+        .with_context(|| format!("{answer} is not one of the possible answers"))?;
+
+    println!(
+        "{:>4}  {:<15} {:>7} {:>7} {:>5} {:>5}  best",
+        "turn", "guess", "before", "after", "skill", "luck"
+    );
+    for (i, graded) in grade.guesses.iter().enumerate() {
+        println!(
+            "{:>4}  {} {:>7} {:>7} {:>5} {:>5}  {} ({:.2} bits, against {:.2})",
+            i + 1,
+            tiles(graded.guess.word, graded.guess.mask),
+            graded.before,
+            graded.after,
+            graded.skill,
+            graded.luck,
+            graded.best,
+            graded.best_bits,
+            graded.bits.expected,
+        );
+    }
+    if grade.guesses.len() < guesses.len() {
+        println!("guesses after the answer was found are not graded");
+    }
+    println!("skill {}/99, luck {}/99", grade.skill, grade.luck);
+    Ok(())
+}
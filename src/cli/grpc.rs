@@ -0,0 +1,188 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Instant;
+
+use rayon::prelude::*;
+use roget::{Guess, Guesser, Word, Wordle};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+
+use super::service;
+
+mod proto {
+    tonic::include_proto!("roget");
+}
+
+use proto::solver_server::{Solver, SolverServer};
+
+struct Server<F> {
+    wordle: Wordle,
+    guesser: F,
+    /// What `Simulate` plays when the request names no answers.
+    answers: Vec<Word>,
+}
+
+/// Implements the service generated from `proto/roget.proto`.
+struct Service<F> {
+    server: Arc<Server<F>>,
+}
+
+fn word(s: &str) -> Result<Word, String> {
+    s.parse().map_err(|e| format!("{s}: {e}"))
+}
+
+fn turn(guess: &Guess) -> proto::Turn {
+    proto::Turn {
+        word: guess.word.to_string(),
+        mask: service::mask_letters(guess.mask),
+    }
+}
+
+#[tonic::async_trait]
+impl<F, G> Solver for Service<F>
+where
+    F: Fn() -> G + Send + Sync + 'static,
+    G: Guesser + 'static,
+{
+    async fn suggest(
+        &self,
+        request: Request<proto::SuggestRequest>,
+    ) -> Result<Response<proto::SuggestResponse>, Status> {
+        let request = request.into_inner();
+        let suggestion = request
+            .history
+            .iter()
+            .map(|t| service::turn(word(&t.word)?, &t.mask))
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(|history| {
+                service::suggest(&self.server.wordle, (self.server.guesser)(), &history)
+            })
+            .map_err(Status::invalid_argument)?;
+
+        Ok(Response::new(proto::SuggestResponse {
+            suggestion: suggestion.suggestion.to_string(),
+            remaining: suggestion.candidates.len() as u64,
+            candidates: suggestion
+                .candidates
+                .into_iter()
+                .take(request.limit.unwrap_or(10) as usize)
+                .map(|(word, count)| proto::Candidate {
+                    word: word.to_string(),
+                    count: count as u64,
+                })
+                .collect(),
+        }))
+    }
+
+    async fn score(
+        &self,
+        request: Request<proto::ScoreRequest>,
+    ) -> Result<Response<proto::ScoreResponse>, Status> {
+        let request = request.into_inner();
+        let scored = (|| {
+            let answer = word(&request.answer)?;
+            let guesses = request
+                .guesses
+                .iter()
+                .map(|g| word(g))
+                .collect::<Result<Vec<_>, _>>()?;
+            service::score(
+                &self.server.wordle,
+                (self.server.guesser)(),
+                answer,
+                &guesses,
+            )
+        })()
+        .map_err(Status::invalid_argument)?;
+
+        Ok(Response::new(proto::ScoreResponse {
+            guesses: scored
+                .guesses
+                .iter()
+                .map(|(guess, remaining)| proto::ScoredGuess {
+                    word: guess.word.to_string(),
+                    mask: service::mask_letters(guess.mask),
+                    remaining: *remaining as u64,
+                })
+                .collect(),
+            score: scored.score.map(|s| s as u32),
+            solver_score: scored.solver_score.map(|s| s as u32),
+        }))
+    }
+
+    type SimulateStream = ReceiverStream<Result<proto::Game, Status>>;
+
+    async fn simulate(
+        &self,
+        request: Request<proto::SimulateRequest>,
+    ) -> Result<Response<Self::SimulateStream>, Status> {
+        let server = self.server.clone();
+        let answers = match request.into_inner().answers {
+            requested if requested.is_empty() => server.answers.clone(),
+            requested => requested
+                .iter()
+                .map(|a| word(a))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(Status::invalid_argument)?,
+        };
+        if let Some(answer) = answers
+            .iter()
+            .find(|&&a| !server.wordle.candidates(&[]).any(|(w, _)| w == a))
+        {
+            return Err(Status::invalid_argument(format!(
+                "{answer} is not a possible answer"
+            )));
+        }
+
+        let (games, stream) = mpsc::channel(64);
+        // play on the rayon pool, off the async runtime, sending games in the order they end
+        tokio::task::spawn_blocking(move || {
+            // stops early once the client hangs up and the channel closes
+            let _ = answers.par_iter().try_for_each(|answer| {
+                let start = Instant::now();
+                let result = server
+                    .wordle
+                    .play_game(&answer.to_string(), (server.guesser)());
+                let game = proto::Game {
+                    answer: result.answer.to_string(),
+                    guesses: result.guesses.iter().map(turn).collect(),
+                    score: result.score.map(|s| s as u32),
+                    time: start.elapsed().as_secs_f64(),
+                };
+                games.blocking_send(Ok(game)).map_err(drop)
+            });
+        });
+        Ok(Response::new(ReceiverStream::new(stream)))
+    }
+}
+
+/// Serve the solver over gRPC on `port` until the process is stopped.
+pub fn serve<F, G>(wordle: &Wordle, guesser: F, answers: &[&str], port: u16) -> anyhow::Result<()>
+where
+    F: Fn() -> G + Send + Sync + 'static,
+    G: Guesser + 'static,
+{
+    let answers = answers
+        .iter()
+        .map(|a| a.parse())
+        .collect::<Result<Vec<Word>, _>>()?;
+    let service = Service {
+        server: Arc::new(Server {
+            wordle: wordle.clone(),
+            guesser,
+            answers,
+        }),
+    };
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async {
+        let address = SocketAddr::from(([127, 0, 0, 1], port));
+        eprintln!("listening for gRPC on {address}");
+        tonic::transport::Server::builder()
+            .add_service(SolverServer::new(service))
+            .serve(address)
+            .await?;
+        Ok(())
+    })
+}
@@ -0,0 +1,51 @@
+use anyhow::Context;
+use roget::{parse_mask, Guess, Word, Wordle};
+
+use super::render::board;
+
+/// Print how often each letter appears in each position among the answers still possible
+/// after `guesses`, given as alternating words and colors like `crane xyxxg slate xxgxx`.
+pub fn heatmap(wordle: &Wordle, guesses: &[String]) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        guesses.len().is_multiple_of(2),
+        "expected each guess as a word followed by its colors, e.g. crane xyxxg"
+    );
+    let history = guesses
+        .chunks(2)
+        .map(|pair| {
+            let word: Word = pair[0].parse().with_context(|| pair[0].clone())?;
+            let mask = parse_mask(&pair[1]).with_context(|| pair[1].clone())?;
+            Ok(Guess { word, mask })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let heatmap = wordle.heatmap(&history);
+    if heatmap.words() == 0 {
+        anyhow::bail!("no answers match those colors");
+    }
+    if !history.is_empty() {
+        println!("{}", board(&history));
+    }
+    println!(
+        "percent of the {} possible answers with each letter in each position",
+        heatmap.words()
+    );
+    print!("{:<6}", "");
+    for position in 1..=Word::LEN {
+        print!(" {position:>4}");
+    }
+    println!(" {:>5}", "any");
+
+    let percent = |count: usize| match count {
+        0 => format!("{:>4}", "·"),
+        n => format!("{:>4.0}", 100.0 * n as f64 / heatmap.words() as f64),
+    };
+    for letter in heatmap.letters() {
+        print!("{letter:<6}");
+        for position in 0..Word::LEN {
+            print!(" {}", percent(heatmap.count(position, letter)));
+        }
+        println!(" {:>5}", percent(heatmap.anywhere(letter)));
+    }
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anyhow::Context;
+use regex::Regex;
+use roget::{Guesser, Word, Wordle};
+
+/// Print the answers that fit colors seen in a game played elsewhere, and what to play next.
+///
+/// `green` has a letter for each known position and `.` elsewhere, like `c..n.`. Each of
+/// `yellow` is a letter followed by the 1-based positions it came back yellow at, like `r2`
+/// or just `r`, and `gray` is the letters that came back gray. A `regex` narrows the answers
+/// down further.
+#[allow(clippy::too_many_arguments)]
+pub fn hint<G: Guesser>(
+    wordle: &Wordle,
+    green: Option<&str>,
+    yellow: &[String],
+    gray: Option<&str>,
+    regex: Option<&str>,
+    limit: usize,
+    make_guesser: impl FnOnce(&roget::Dictionary) -> G,
+) -> anyhow::Result<()> {
+    let mut query = wordle.dictionary().query();
+    if let Some(green) = green {
+        let letters: Vec<char> = green.chars().collect();
+        anyhow::ensure!(
+            letters.len() == Word::LEN,
+            "--green needs a letter or . for each of the {} positions, like c..n.",
+            Word::LEN
+        );
+        for (position, &letter) in letters.iter().enumerate() {
+            if letter != '.' {
+                query = query.green(position, letter);
+            }
+        }
+    }
+    for constraint in yellow {
+        let mut chars = constraint.chars();
+        let letter = chars.next().context("--yellow needs a letter")?;
+        let positions: Vec<char> = chars.collect();
+        if positions.is_empty() {
+            query = query.yellow(letter);
+        }
+        for position in positions {
+            let position = position
+                .to_digit(10)
+                .map(|p| p as usize)
+                .filter(|p| (1..=Word::LEN).contains(p))
+                .with_context(|| format!("{constraint}: positions go from 1 to {}", Word::LEN))?;
+            query = query.yellow_at(position - 1, letter);
+        }
+    }
+    for letter in gray.unwrap_or_default().chars() {
+        query = query.gray(letter);
+    }
+    if let Some(regex) = regex {
+        query = query.regex(Regex::new(regex).with_context(|| format!("--regex {regex}"))?);
+    }
+
+    let hint = query.hint(make_guesser);
+    let Some(suggestion) = hint.suggestion else {
+        anyhow::bail!("no answers match those colors");
+    };
+    println!("try {suggestion} ({} candidates)", hint.candidates.len());
+    let shown: Vec<String> = hint
+        .candidates
+        .iter()
+        .take(limit)
+        .map(|(word, _)| word.to_string())
+        .collect();
+    println!("{}", shown.join(" "));
+    if hint.candidates.len() > limit {
+        println!("and {} more", hint.candidates.len() - limit);
+    }
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use roget::{Correctness, GameStats, Word};
+
+use super::batch::{self, Record};
+
+/// How many of the hardest answers the report lists.
+const HARDEST: usize = 25;
+
+const STYLE: &str = "
+body { font-family: system-ui, sans-serif; margin: 2em auto; max-width: 60em; color: #222; }
+table { border-collapse: collapse; margin: 1em 0; }
+th, td { padding: 0.2em 0.8em; text-align: right; border-bottom: 1px solid #ddd; }
+th:first-child, td:first-child { text-align: left; }
+.bar { background: #6aaa64; height: 1em; }
+.dnf .bar { background: #c9b458; }
+.grid { font-family: monospace; margin: 0.3em 0 0.6em 1.5em; }
+.tile { display: inline-block; width: 1.4em; text-align: center; color: white;
+        text-transform: uppercase; margin: 1px; }
+.g { background: #6aaa64; } .y { background: #c9b458; } .x { background: #787c7e; }
+details { margin: 0.1em 0; }
+";
+
+/// A self-contained HTML page with the results of every algorithm, keyed by name: a
+/// comparison table, each score histogram, the hardest answers, and every game guess by
+/// guess.
+///
+/// Nothing in a record needs escaping: words are letters and algorithm names come from
+/// `Implementation`.
+pub struct Html<'a>(pub &'a BTreeMap<String, Vec<Record>>);
+
+impl fmt::Display for Html<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stats: Vec<(&String, GameStats)> = self
+            .0
+            .iter()
+            .map(|(algorithm, records)| (algorithm, batch::stats(records)))
+            .collect();
+
+        writeln!(f, "<!DOCTYPE html>\n<html lang=\"en\">\n<head>")?;
+        writeln!(f, "<meta charset=\"utf-8\">\n<title>roget report</title>")?;
+        writeln!(f, "<style>{STYLE}</style>\n</head>\n<body>")?;
+        writeln!(f, "<h1>roget report</h1>")?;
+
+        comparison(f, &stats)?;
+        for (algorithm, stats) in &stats {
+            histogram(f, algorithm, stats)?;
+        }
+        hardest(f, self.0)?;
+        for (algorithm, records) in self.0 {
+            games(f, algorithm, records)?;
+        }
+        writeln!(f, "</body>\n</html>")
+    }
+}
+
+fn comparison(f: &mut fmt::Formatter<'_>, stats: &[(&String, GameStats)]) -> fmt::Result {
+    let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+    writeln!(f, "<h2>Algorithms</h2>\n<table>")?;
+    writeln!(
+        f,
+        "<tr><th>algorithm</th><th>games</th><th>solved</th><th>DNF</th><th>average</th>\
+         <th>median</th><th>90th percentile</th><th>worst</th><th>time per guess</th></tr>"
+    )?;
+    for (algorithm, stats) in stats {
+        writeln!(
+            f,
+            "<tr><td>{algorithm}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td>\
+             <td>{}</td><td>{}</td><td>{}</td></tr>",
+            stats.games(),
+            stats.solved(),
+            stats.dnf(),
+            or_dash(stats.mean().map(|mean| format!("{mean:.4}"))),
+            or_dash(stats.median().map(|median| median.to_string())),
+            or_dash(stats.percentile(0.9).map(|p90| p90.to_string())),
+            or_dash(stats.worst().map(|worst| worst.to_string())),
+            or_dash(stats.mean_guess_time().map(|time| format!("{time:.2?}"))),
+        )?;
+    }
+    writeln!(f, "</table>")
+}
+
+fn histogram(f: &mut fmt::Formatter<'_>, algorithm: &str, stats: &GameStats) -> fmt::Result {
+    let widest = stats.histogram().iter().copied().max().unwrap_or(0);
+    let widest = widest.max(stats.dnf()).max(1);
+    let row = |f: &mut fmt::Formatter<'_>, class: &str, label: &str, n: usize| {
+        let width = 100.0 * n as f64 / widest as f64;
+        writeln!(
+            f,
+            "<tr class=\"{class}\"><td>{label}</td><td>{n}</td><td style=\"width: 30em\">\
+             <div class=\"bar\" style=\"width: {width:.1}%\"></div></td></tr>",
+        )
+    };
+
+    writeln!(f, "<h2>Scores: {algorithm}</h2>\n<table>")?;
+    for (i, &n) in stats.histogram().iter().enumerate() {
+        row(f, "", &(i + 1).to_string(), n)?;
+    }
+    row(f, "dnf", "DNF", stats.dnf())?;
+    writeln!(f, "</table>")
+}
+
+/// The answers that took the most guesses on average over every algorithm, with any
+/// answer an algorithm never solved first.
+fn hardest(
+    f: &mut fmt::Formatter<'_>,
+    by_algorithm: &BTreeMap<String, Vec<Record>>,
+) -> fmt::Result {
+    let mut by_answer: BTreeMap<Word, Vec<Option<usize>>> = BTreeMap::new();
+    for record in by_algorithm.values().flatten() {
+        by_answer
+            .entry(record.result.answer)
+            .or_default()
+            .push(record.result.score);
+    }
+    let average = |scores: &[Option<usize>]| {
+        let solved: Vec<usize> = scores.iter().flatten().copied().collect();
+        solved.iter().sum::<usize>() as f64 / solved.len().max(1) as f64
+    };
+    let unsolved = |scores: &[Option<usize>]| scores.iter().filter(|s| s.is_none()).count();
+    let mut answers: Vec<(Word, Vec<Option<usize>>)> = by_answer.into_iter().collect();
+    answers.sort_by(|(a, x), (b, y)| {
+        unsolved(y)
+            .cmp(&unsolved(x))
+            .then(average(y).total_cmp(&average(x)))
+            .then(a.cmp(b))
+    });
+
+    writeln!(f, "<h2>Hardest answers</h2>\n<table>")?;
+    writeln!(
+        f,
+        "<tr><th>answer</th><th>games</th><th>DNF</th><th>average</th></tr>"
+    )?;
+    for (answer, scores) in answers.iter().take(HARDEST) {
+        writeln!(
+            f,
+            "<tr><td>{answer}</td><td>{}</td><td>{}</td><td>{:.2}</td></tr>",
+            scores.len(),
+            unsolved(scores),
+            average(scores),
+        )?;
+    }
+    writeln!(f, "</table>")
+}
+
+/// Every game, folded away until clicked, hardest first.
+fn games(f: &mut fmt::Formatter<'_>, algorithm: &str, records: &[Record]) -> fmt::Result {
+    let mut records: Vec<&Record> = records.iter().collect();
+    records.sort_by_key(|r| {
+        (
+            std::cmp::Reverse(r.result.score.unwrap_or(usize::MAX)),
+            r.result.answer,
+        )
+    });
+
+    writeln!(f, "<h2>Games: {algorithm}</h2>")?;
+    for Record { result, .. } in records {
+        let score = result.score.map_or("DNF".to_string(), |s| s.to_string());
+        writeln!(
+            f,
+            "<details><summary>{} ({score})</summary><div class=\"grid\">",
+            result.answer
+        )?;
+        for guess in &result.guesses {
+            for (letter, c) in guess.word.letters().zip(guess.mask) {
+                let class = match c {
+                    Correctness::Correct => "g",
+                    Correctness::Misplaced => "y",
+                    Correctness::Wrong => "x",
+                };
+                write!(f, "<span class=\"tile {class}\">{letter}</span>")?;
+            }
+            writeln!(f, "<br>")?;
+        }
+        writeln!(f, "</div></details>")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use std::io;
+
+use roget::{Guesser, Word, Wordle};
+
+use super::solve::trace;
+
+/// Play `answers` back to back as in Hurdle, each board opening with the last one's answer,
+/// and show every board.
+pub fn hurdle<G>(wordle: &Wordle, answers: &[Word], mk: impl Fn() -> G) -> io::Result<()>
+where
+    G: Guesser,
+{
+    let results = wordle.play_hurdle(answers, mk);
+    let mut out = io::stdout().lock();
+    for (i, result) in results.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("board {}/{}:", i + 1, answers.len());
+        trace(wordle, result, &mut out)?;
+    }
+
+    let guesses: usize = results.iter().map(|r| r.guesses.len()).sum();
+    let cleared = results.iter().filter(|r| r.score.is_some()).count();
+    println!();
+    println!(
+        "cleared {cleared} of {} boards in {guesses} guesses",
+        answers.len()
+    );
+    Ok(())
+}
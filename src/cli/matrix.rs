@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+use std::time::Instant;
+
+use anyhow::Context;
+use roget::{Dictionary, Matrix, Runtime};
+
+/// Where the pattern matrix for `dictionary` is cached.
+pub fn path(dictionary: &Dictionary) -> anyhow::Result<PathBuf> {
+    super::cache::words_path("matrix", dictionary, &[], "bin")
+}
+
+/// Load the pattern matrix for `dictionary` from the cache, computing and saving it first
+/// if it is missing or stale, unless it takes more memory than `runtime` allows.
+pub fn cached(dictionary: &Dictionary, runtime: &Runtime) -> anyhow::Result<Matrix> {
+    runtime
+        .check_memory(Matrix::size(dictionary))
+        .context("the pattern matrix does not fit in --memory")?;
+    super::cache::cached(
+        &path(dictionary)?,
+        |path| Matrix::load(path, dictionary),
+        || {
+            let start = Instant::now();
+            let matrix = Matrix::compute(dictionary);
+            eprintln!(
+                "computed {}x{} pattern matrix in {:.1?}",
+                matrix.guesses().len(),
+                matrix.answers().len(),
+                start.elapsed()
+            );
+            Ok(matrix)
+        },
+        |matrix, path| matrix.save(path),
+    )
+}
+
+/// Make sure the pattern matrix for `dictionary` is cached, and say where.
+pub fn precompute(dictionary: &Dictionary, runtime: &Runtime) -> anyhow::Result<()> {
+    let matrix = cached(dictionary, runtime)?;
+    println!(
+        "{}x{} pattern matrix cached at {}",
+        matrix.guesses().len(),
+        matrix.answers().len(),
+        path(dictionary)?.display()
+    );
+    Ok(())
+}
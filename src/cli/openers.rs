@@ -0,0 +1,163 @@
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::Context;
+use roget::algorithms::Normalization;
+use roget::{Opener, Runtime, Speed, Word, Wordle};
+
+/// Score every allowed word as an opener and print the `limit` best, as a table or CSV.
+///
+/// With `out`, every opener is written there as CSV instead, for analysis elsewhere. At a
+/// faster `speed` only the answers and a share of the other words are scored.
+pub fn openers(
+    wordle: &Wordle,
+    limit: usize,
+    csv: bool,
+    out: Option<&Path>,
+    speed: Speed,
+    runtime: &Runtime,
+) -> anyhow::Result<()> {
+    let openers = score(wordle, speed, runtime)?;
+
+    if let Some(path) = out {
+        let file =
+            std::fs::File::create(path).with_context(|| format!("writing {}", path.display()))?;
+        write_csv(io::BufWriter::new(file), &openers)?;
+        eprintln!("wrote {} openers to {}", openers.len(), path.display());
+        return Ok(());
+    }
+    if csv {
+        return write_csv(io::stdout().lock(), &openers[..limit.min(openers.len())]);
+    }
+
+    println!("{:>5} {:<8} {:>8} {:>8}", "rank", "opener", "bits", "score");
+    for (i, opener) in openers.iter().take(limit).enumerate() {
+        println!(
+            "{:>5} {:<8} {:>8.4} {:>8.4}",
+            i + 1,
+            opener.word,
+            opener.bits,
+            opener.expected_score
+        );
+    }
+    Ok(())
+}
+
+/// Score `word` as an opener and print how it does, and how it ranks among every allowed
+/// word, which takes scoring them all whatever the speed.
+pub fn opener(wordle: &Wordle, word: Word, runtime: &Runtime) -> anyhow::Result<()> {
+    wordle.check(word)?;
+    let openers = score(wordle, Speed::Best, runtime)?;
+    let (rank, opener) = openers
+        .iter()
+        .enumerate()
+        .find(|(_, opener)| opener.word == word)
+        .expect("every allowed word is scored");
+
+    println!("{word}");
+    println!("  information     {:.4} bits", opener.bits);
+    println!(
+        "  expected score  {:.4} guesses, always playing the likeliest answer next",
+        opener.expected_score
+    );
+    println!(
+        "  worst case      {} of {} answers left",
+        opener.largest_bucket,
+        wordle.dictionary().answers().len()
+    );
+    println!("  rank            {} of {}", rank + 1, openers.len());
+    Ok(())
+}
+
+/// Write `openers` as CSV, one row each, ranked in the order given.
+fn write_csv(out: impl Write, openers: &[Opener]) -> anyhow::Result<()> {
+    let mut out = csv::Writer::from_writer(out);
+    out.write_record([
+        "rank",
+        "opener",
+        "bits",
+        "expected_score",
+        "buckets",
+        "largest_bucket",
+    ])?;
+    for (i, opener) in openers.iter().enumerate() {
+        out.write_record([
+            (i + 1).to_string(),
+            opener.word.to_string(),
+            format!("{:.4}", opener.bits),
+            format!("{:.4}", opener.expected_score),
+            opener.buckets.to_string(),
+            opener.largest_bucket.to_string(),
+        ])?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// The allowed words `speed` scores as openers, best first; see [`roget::openers`]. They are
+/// cached, as scoring them all takes a while.
+fn score(wordle: &Wordle, speed: Speed, runtime: &Runtime) -> anyhow::Result<Vec<Opener>> {
+    let dictionary = wordle.dictionary();
+    let params = [env!("CARGO_PKG_VERSION"), speed.name()];
+    let path = super::cache::path("openers", dictionary, &params, "txt")?;
+    super::cache::cached(
+        &path,
+        load,
+        || {
+            let matrix = super::matrix::cached(dictionary, runtime)?;
+            let priors = Normalization::default().weights(dictionary.answers());
+
+            let start = Instant::now();
+            let openers = roget::openers(&matrix, &priors, speed);
+            eprintln!(
+                "scored {} openers against {} answers in {:.1?}",
+                openers.len(),
+                matrix.answers().len(),
+                start.elapsed()
+            );
+            Ok(openers)
+        },
+        |openers, path| save(openers, path),
+    )
+}
+
+/// Write `openers` one per line as `word bits expected_score buckets largest_bucket`.
+fn save(openers: &[Opener], path: &Path) -> io::Result<()> {
+    let mut out = io::BufWriter::new(std::fs::File::create(path)?);
+    for opener in openers {
+        writeln!(
+            out,
+            "{} {} {} {} {}",
+            opener.word, opener.bits, opener.expected_score, opener.buckets, opener.largest_bucket
+        )?;
+    }
+    out.flush()
+}
+
+/// Read back what [`save`] wrote.
+fn load(path: &Path) -> io::Result<Vec<Opener>> {
+    let text = std::fs::read_to_string(path)?;
+    let invalid = |line| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("line {line} is not an opener"),
+        )
+    };
+    let mut openers = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let parse = || -> Option<Opener> {
+            let mut fields = line.split_whitespace();
+            let opener = Opener {
+                word: fields.next()?.parse().ok()?,
+                bits: fields.next()?.parse().ok()?,
+                expected_score: fields.next()?.parse().ok()?,
+                buckets: fields.next()?.parse().ok()?,
+                largest_bucket: fields.next()?.parse().ok()?,
+            };
+            fields.next().is_none().then_some(opener)
+        };
+        openers.push(parse().ok_or_else(|| invalid(i + 1))?);
+    }
+    Ok(openers)
+}
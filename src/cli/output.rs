@@ -0,0 +1,126 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+use roget::Call;
+use serde::Serialize;
+
+use super::batch::{Record, Sink};
+
+/// A CSV results file that gets a row, flushed immediately, for every finished game.
+pub struct CsvOut {
+    state: Mutex<State>,
+}
+
+struct State {
+    out: csv::Writer<File>,
+    /// The first write error; later rows are skipped and it is reported by `finish`.
+    error: Option<io::Error>,
+}
+
+impl CsvOut {
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let mut out = csv::Writer::from_path(path)?;
+        out.write_record(Record::CSV_HEADER)?;
+        out.flush()?;
+        Ok(Self {
+            state: Mutex::new(State { out, error: None }),
+        })
+    }
+
+    /// Report any error hit while writing rows.
+    pub fn finish(self) -> io::Result<()> {
+        let state = self.state.into_inner().expect("csv writer never panics");
+        match state.error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Sink for CsvOut {
+    fn save(&self, record: &Record) {
+        let mut state = self.state.lock().expect("csv writer never panics");
+        if state.error.is_some() {
+            return;
+        }
+        let written = state
+            .out
+            .write_record(record.csv_row())
+            .map_err(io::Error::from)
+            .and_then(|()| state.out.flush());
+        if let Err(e) = written {
+            state.error = Some(e);
+        }
+    }
+}
+
+/// A JSON lines file with every word asked of the guessers in a batch run, written through
+/// a [`roget::Recorder`] for each game.
+pub struct CallLog {
+    state: Mutex<Calls>,
+}
+
+struct Calls {
+    out: BufWriter<File>,
+    /// The first write error; later calls are skipped and it is reported by `finish`.
+    error: Option<io::Error>,
+}
+
+/// One line of a [`CallLog`].
+#[derive(Serialize)]
+struct Line<'a> {
+    answer: &'a str,
+    #[serde(flatten)]
+    call: &'a Call,
+}
+
+impl CallLog {
+    pub fn create(path: &Path) -> io::Result<Self> {
+        Ok(Self {
+            state: Mutex::new(Calls {
+                out: BufWriter::new(File::create(path)?),
+                error: None,
+            }),
+        })
+    }
+
+    /// A sink for the calls of the game against `answer`.
+    pub fn game<'a>(&'a self, answer: &'a str) -> GameCalls<'a> {
+        GameCalls { log: self, answer }
+    }
+
+    /// Flush the file and report any error hit while writing calls.
+    pub fn finish(self) -> io::Result<()> {
+        let mut state = self.state.into_inner().expect("call log never panics");
+        match state.error {
+            Some(e) => Err(e),
+            None => state.out.flush(),
+        }
+    }
+}
+
+pub struct GameCalls<'a> {
+    log: &'a CallLog,
+    answer: &'a str,
+}
+
+impl roget::Sink for GameCalls<'_> {
+    fn record(&mut self, call: Call) {
+        let mut state = self.log.state.lock().expect("call log never panics");
+        if state.error.is_some() {
+            return;
+        }
+        let line = Line {
+            answer: self.answer,
+            call: &call,
+        };
+        let written = serde_json::to_writer(&mut state.out, &line)
+            .map_err(io::Error::from)
+            .and_then(|()| writeln!(state.out));
+        if let Err(e) = written {
+            state.error = Some(e);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::io::{self, BufRead, Lines, StdinLock, Write};
+
+use roget::{Absurdle, AntiWordle, Correctness, Guess, Guesser, MultiWordle, Oracle, Word, Wordle};
+
+use super::render::tiles;
+
+pub const MAX_GUESSES: usize = 6;
+
+/// Host a game in the terminal: the human guesses and we show the colors.
+pub fn play(wordle: &Wordle, answer: Word) {
+    let human = Human {
+        wordle,
+        lines: io::stdin().lock().lines(),
+        limit: Some(MAX_GUESSES),
+    };
+
+    match wordle.play_against(Shown(answer), human, MAX_GUESSES) {
+        Some(n) => println!("you got it in {n}/{MAX_GUESSES}!"),
+        None => println!("out of guesses; the word was {answer}"),
+    }
+}
+
+/// Host several boards at once, as in Quordle: the human's guesses go to every board still
+/// open, shown side by side.
+pub fn play_boards(wordle: &Wordle, answers: Vec<Word>) {
+    let mut game = MultiWordle::new(answers);
+    let limit = game.guesses_left();
+    let mut human = Human {
+        wordle,
+        lines: io::stdin().lock().lines(),
+        limit: Some(limit),
+    };
+
+    while !game.is_over() {
+        let word = human.read(game.guesses() + 1, &[]);
+        let row: Vec<_> = game
+            .guess(word)
+            .into_iter()
+            .map(|mask| match mask {
+                Some(mask) => tiles(word, mask),
+                // keep the other boards in their columns
+                None => " ".repeat(3 * Word::LEN),
+            })
+            .collect();
+        println!("{}", row.join("  "));
+    }
+
+    if game.is_won() {
+        println!(
+            "you solved all {} boards in {}/{limit}!",
+            game.boards(),
+            game.guesses()
+        );
+    } else {
+        let missed: Vec<_> = game
+            .answers()
+            .iter()
+            .zip(game.solved())
+            .filter(|(_, solved)| solved.is_none())
+            .map(|(answer, _)| answer.to_string())
+            .collect();
+        println!("out of guesses; still unsolved: {}", missed.join(" "));
+    }
+}
+
+/// Play against a host that dodges every guess, as in Absurdle, for as long as it takes.
+pub fn play_absurdle(wordle: &Wordle, answers: &[Word]) {
+    let human = Human {
+        wordle,
+        lines: io::stdin().lock().lines(),
+        limit: None,
+    };
+    let mut host = Absurdle::new(answers.iter().copied());
+    if let Some(n) = wordle.play_against(Shown(&mut host), human, usize::MAX) {
+        println!("you pinned it down in {n}!");
+    }
+}
+
+/// Play Antiwordle: every guess must still be possible, and the game lasts until the human
+/// lands on the answer.
+pub fn play_anti(wordle: &Wordle, answer: Word) {
+    let mut game = AntiWordle::new(answer);
+    let mut human = Human {
+        wordle,
+        lines: io::stdin().lock().lines(),
+        limit: None,
+    };
+
+    while !game.is_over() {
+        let word = human.read(game.history().len() + 1, &[]);
+        match game.guess(word) {
+            Ok(mask) => println!("{}", tiles(word, mask)),
+            Err(e) => println!("{e}"),
+        }
+    }
+    println!("you dodged {answer} for {} guesses", game.dodged());
+}
+
+/// Play `guesser` against a host that dodges every guess, showing how it holds up.
+pub fn absurdle(wordle: &Wordle, answers: &[Word], guesser: impl Guesser) {
+    // a guesser that never narrows things down would go on forever
+    const LIMIT: usize = 32;
+
+    let mut host = Absurdle::new(answers.iter().copied());
+    match wordle.play_against(Shown(&mut host), guesser, LIMIT) {
+        Some(n) => println!("pinned down in {n}"),
+        None => println!(
+            "gave up after {LIMIT} guesses with {} answers left",
+            host.remaining().len()
+        ),
+    }
+}
+
+/// An oracle that prints each graded guess as a row of colored tiles.
+pub struct Shown<O>(pub O);
+
+impl<O: Oracle> Oracle for Shown<O> {
+    fn grade(&mut self, guess: Word) -> [Correctness; 5] {
+        let mask = self.0.grade(guess);
+        // a mask the oracle made up is not worth showing
+        if !self.0.failed() {
+            println!("{}", tiles(guess, mask));
+        }
+        mask
+    }
+
+    fn failed(&self) -> bool {
+        self.0.failed()
+    }
+}
+
+/// A guesser that asks the person at the terminal.
+struct Human<'a> {
+    wordle: &'a Wordle,
+    lines: Lines<StdinLock<'static>>,
+    /// The guess limit to show in the prompt, if there is one.
+    limit: Option<usize>,
+}
+
+impl Guesser for Human<'_> {
+    fn guess(&mut self, history: &[Guess]) -> Word {
+        self.read(history.len() + 1, history)
+    }
+}
+
+impl Human<'_> {
+    /// Prompt until the player enters a word from the word list that the game's rules
+    /// allow after `history`.
+    fn read(&mut self, turn: usize, history: &[Guess]) -> Word {
+        loop {
+            match self.limit {
+                Some(limit) => print!("guess {turn}/{limit}> "),
+                None => print!("guess {turn}> "),
+            }
+            io::stdout().flush().expect("stdout is writable");
+            let line = match self.lines.next() {
+                Some(Ok(line)) => line,
+                // the player walked away
+                Some(Err(_)) | None => std::process::exit(0),
+            };
+
+            let word: Word = match line.trim().parse() {
+                Ok(word) => word,
+                Err(e) => {
+                    println!("{e}");
+                    continue;
+                }
+            };
+            if let Err(e) = self.wordle.check_guess(history, word) {
+                println!("{e}");
+                continue;
+            }
+            return word;
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Guessers run by other programs, which speak the protocol `roget engine` answers; see
+//! [`super::engine`].
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::{Arc, Mutex};
+
+use anyhow::Context;
+use roget::{Guess, Guesser, Word};
+
+/// A program to play games with, as `NAME=COMMAND`.
+///
+/// Games run in parallel, so this starts as many copies of the program as are playing at
+/// once, and hands each to the next game when its own is over.
+pub struct Plugin {
+    name: String,
+    command: Vec<String>,
+    idle: Mutex<Vec<Connection>>,
+}
+
+impl Plugin {
+    /// Parse `NAME=COMMAND`, where `COMMAND` is the program followed by its arguments, and
+    /// check the program answers the handshake.
+    pub fn new(spec: &str) -> anyhow::Result<Self> {
+        let (name, command) = spec
+            .split_once('=')
+            .with_context(|| format!("plugin {spec} should be NAME=COMMAND"))?;
+        let command: Vec<String> = command.split_whitespace().map(String::from).collect();
+        anyhow::ensure!(!command.is_empty(), "plugin {name} has no command");
+        let plugin = Self {
+            name: name.to_string(),
+            command,
+            idle: Mutex::new(Vec::new()),
+        };
+        let connection = plugin
+            .connect()
+            .with_context(|| format!("could not start plugin {name}"))?;
+        plugin
+            .idle
+            .lock()
+            .expect("no game panics holding the lock")
+            .push(connection);
+        Ok(plugin)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// A guesser for one game, on a copy of the program no other game is using.
+    pub fn guesser(self: &Arc<Self>) -> PluginGuesser {
+        let idle = self
+            .idle
+            .lock()
+            .expect("no game panics holding the lock")
+            .pop();
+        let connection = match idle {
+            Some(connection) => connection,
+            None => self
+                .connect()
+                .unwrap_or_else(|e| panic!("could not start plugin {}: {e}", self.name)),
+        };
+        PluginGuesser {
+            plugin: Arc::clone(self),
+            connection: Some(connection),
+        }
+    }
+
+    fn connect(&self) -> io::Result<Connection> {
+        let mut child = Command::new(&self.command[0])
+            .args(&self.command[1..])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let mut connection = Connection {
+            input: child.stdin.take().expect("stdin is piped"),
+            output: BufReader::new(child.stdout.take().expect("stdout is piped")),
+            child,
+        };
+        connection.send("roget")?;
+        // the id line and anything else the program wants to say come first
+        while connection.receive()? != "rogetok" {}
+        Ok(connection)
+    }
+}
+
+/// One running copy of a plugin's program.
+struct Connection {
+    child: Child,
+    input: ChildStdin,
+    output: BufReader<ChildStdout>,
+}
+
+impl Connection {
+    fn send(&mut self, command: &str) -> io::Result<()> {
+        writeln!(self.input, "{command}")?;
+        self.input.flush()
+    }
+
+    fn receive(&mut self) -> io::Result<String> {
+        let mut line = String::new();
+        if self.output.read_line(&mut line)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(line.trim().to_string())
+    }
+
+    /// Tell the program what `history` ended with and ask for its next guess.
+    fn guess(&mut self, history: &[Guess]) -> io::Result<Result<Word, String>> {
+        match history.last() {
+            None => self.send("newgame")?,
+            Some(Guess { word, mask }) => {
+                let mask: String = mask.iter().map(|c| format!("{c:#}")).collect();
+                self.send(&format!("feedback {word} {mask}"))?;
+            }
+        }
+        self.send("go")?;
+        // `feedback` only answers when it fails, so an error may come before the guess
+        loop {
+            let reply = self.receive()?;
+            if let Some(reason) = reply.strip_prefix("error ") {
+                return Ok(Err(reason.to_string()));
+            }
+            if let Some(word) = reply.strip_prefix("bestguess ") {
+                return Ok(word.parse().map_err(|e| format!("{word}: {e}")));
+            }
+        }
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        // the program may already be gone, and then there is nothing left to stop
+        let _ = self.send("quit");
+        let _ = self.child.wait();
+    }
+}
+
+/// Plays one game through a [`Plugin`], handing its program back when dropped.
+///
+/// Panics if the program stops or answers with an error, as a guesser with a bug would.
+pub struct PluginGuesser {
+    plugin: Arc<Plugin>,
+    connection: Option<Connection>,
+}
+
+impl Guesser for PluginGuesser {
+    fn guess(&mut self, history: &[Guess]) -> Word {
+        let connection = self.connection.as_mut().expect("only taken on drop");
+        match connection.guess(history) {
+            Ok(Ok(word)) => word,
+            Ok(Err(reason)) => panic!("plugin {} failed: {reason}", self.plugin.name),
+            Err(e) => panic!("plugin {} stopped: {e}", self.plugin.name),
+        }
+    }
+}
+
+impl Drop for PluginGuesser {
+    fn drop(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            if let Ok(mut idle) = self.plugin.idle.lock() {
+                idle.push(connection);
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::cmp::Ordering;
+
+use clap::ValueEnum;
+use roget::Wordle;
+
+use super::batch;
+use crate::Implementation;
+
+/// Play the same answers with two algorithms and show every word where one beat the other,
+/// then the overall record from `first`'s side.
+pub fn race(wordle: &Wordle, first: Implementation, second: Implementation, answers: &[&str]) {
+    let play = |implementation: Implementation| {
+        let factory = implementation.factory(wordle.dictionary());
+        batch::run(wordle, implementation, &factory, answers, &[], None)
+    };
+    let (a, b) = (play(first), play(second));
+    let name = |implementation: Implementation| {
+        implementation
+            .to_possible_value()
+            .expect("no variants are skipped")
+            .get_name()
+            .to_string()
+    };
+    let (first, second) = (name(first), name(second));
+    let score = |score: Option<usize>| score.map_or("DNF".to_string(), |s| s.to_string());
+
+    println!("{:<8} {first:>10} {second:>10}  winner", "answer");
+    // batch runs keep the order of `answers`, so the games line up
+    for (a, b) in a.iter().zip(&b) {
+        let (x, y) = (a.result.score, b.result.score);
+        let winner = match compare(x, y) {
+            Ordering::Less => &first,
+            Ordering::Greater => &second,
+            Ordering::Equal => continue,
+        };
+        println!(
+            "{:<8} {:>10} {:>10}  {winner}",
+            a.result.answer,
+            score(x),
+            score(y)
+        );
+    }
+
+    println!();
+    let paired = batch::paired(&a, &b);
+    println!("{first} vs {second}: {}", batch::describe(&paired));
+}
+
+/// Fewer guesses is better, and any solved game beats an unsolved one.
+fn compare(a: Option<usize>, b: Option<usize>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
@@ -0,0 +1,50 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+
+use anyhow::Context;
+use serde::Deserialize;
+
+use super::batch::{Record, SCHEMA};
+use super::checkpoint::Header;
+
+/// Write `records` one JSON object per line.
+pub fn write(records: &[Record], mut out: impl Write) -> io::Result<()> {
+    for record in records {
+        serde_json::to_writer(&mut out, record)?;
+        writeln!(out)?;
+    }
+    out.flush()
+}
+
+/// Read every record in a JSON-lines file, such as one from `--format jsonl` or
+/// `--checkpoint`.
+///
+/// Unlike resuming from a checkpoint, any line that is not a record is an error, as is a
+/// record from a newer version of the format.
+pub fn read(path: &Path) -> anyhow::Result<Vec<Record>> {
+    let file = File::open(path).with_context(|| format!("opening {}", path.display()))?;
+    let mut records = Vec::new();
+    for (i, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        // a checkpoint starts with what its games were played with
+        if line.trim().is_empty() || (i == 0 && Header::parse(&line).is_some()) {
+            continue;
+        }
+        let at = || format!("{}:{}", path.display(), i + 1);
+        // check the version first, since a newer record may not parse as this one at all
+        let Versioned { schema } = serde_json::from_str(&line).with_context(at)?;
+        anyhow::ensure!(
+            schema <= SCHEMA,
+            "{}: record schema {schema} is newer than this roget understands ({SCHEMA})",
+            at(),
+        );
+        records.push(serde_json::from_str(&line).with_context(at)?);
+    }
+    Ok(records)
+}
+
+#[derive(Deserialize)]
+struct Versioned {
+    schema: u32,
+}
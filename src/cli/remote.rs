@@ -0,0 +1,96 @@
+use anyhow::Context;
+use roget::{Guesser, Remote, Wordle};
+
+use super::play::Shown;
+
+/// Play the game hosted at `url` with `guesser`, showing each guess as it is graded.
+pub fn remote(
+    wordle: &Wordle,
+    url: &str,
+    params: &[String],
+    field: &str,
+    max_guesses: usize,
+    guesser: impl Guesser,
+) -> anyhow::Result<()> {
+    let mut host = Remote::new(url).field(field);
+    for param in params {
+        let (key, value) = param
+            .split_once('=')
+            .with_context(|| format!("expected KEY=VALUE, got {param:?}"))?;
+        host = host.param(key, value);
+    }
+
+    let score = wordle.play_against(Shown(&mut host), guesser, max_guesses);
+    if let Some(e) = host.error() {
+        anyhow::bail!("{url}: {e}");
+    }
+    match score {
+        Some(n) => println!("solved in {n}/{max_guesses}"),
+        None => println!("not solved within {max_guesses} guesses"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    use roget::algorithms::Naive;
+    use roget::{Oracle, Remote, Word, Wordle};
+
+    use super::super::play::Shown;
+
+    /// A host that answers one guess without any tiles, and returns its URL.
+    fn broken_host() -> (String, std::thread::JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/guess", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim().to_ascii_lowercase();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some(n) = line.strip_prefix("content-length:") {
+                    length = n.trim().parse().unwrap();
+                }
+            }
+            reader.read_exact(&mut vec![0; length]).unwrap();
+            let reply = "{}";
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                 Content-Length: {}\r\nConnection: close\r\n\r\n{reply}",
+                reply.len()
+            )
+            .unwrap();
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn a_broken_game_is_an_error() {
+        let (url, host) = broken_host();
+        let error = super::remote(&Wordle::new(), &url, &[], "colors", 6, Naive::new())
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with(&url), "{error}");
+        assert!(error.contains("colors"), "{error}");
+        host.join().unwrap();
+    }
+
+    #[test]
+    fn shown_passes_the_failure_on() {
+        let (url, host) = broken_host();
+        let mut shown = Shown(Remote::new(url));
+        assert!(!shown.failed());
+        shown.grade(Word::new("crane"));
+        assert!(shown.failed());
+        host.join().unwrap();
+    }
+}
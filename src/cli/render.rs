@@ -0,0 +1,70 @@
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use roget::{Correctness, Guess, Word};
+
+/// Whether tiles are drawn with ANSI colors; see [`init`].
+static COLOR: AtomicBool = AtomicBool::new(false);
+
+/// Draw tiles in color from now on if stdout is a terminal that shows them, unless
+/// `no_color` is set or the `NO_COLOR` environment variable is.
+pub fn init(no_color: bool) {
+    COLOR.store(!no_color && supported(), Ordering::Relaxed);
+}
+
+/// Whether tiles are drawn in color.
+pub fn color() -> bool {
+    COLOR.load(Ordering::Relaxed)
+}
+
+fn supported() -> bool {
+    // https://no-color.org asks for any non-empty value to turn colors off
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    let dumb = std::env::var_os("TERM").is_some_and(|term| term == "dumb");
+    io::stdout().is_terminal() && !no_color && !dumb
+}
+
+/// `word` as a row of tiles colored by `mask`, three columns to a letter.
+///
+/// Without colors a green letter is shown in brackets, `[C]`, a yellow one in parentheses,
+/// `(R)`, and a gray one bare, so rows still line up.
+pub fn tiles(word: Word, mask: [Correctness; 5]) -> String {
+    let color = color();
+    word.letters()
+        .zip(mask)
+        .map(|(letter, c)| {
+            let letter = upper(letter);
+            match (color, c) {
+                (true, c) => {
+                    let background = match c {
+                        Correctness::Correct => 42,
+                        Correctness::Misplaced => 43,
+                        Correctness::Wrong => 100,
+                    };
+                    format!("\x1b[30;{background}m {letter} \x1b[0m")
+                }
+                (false, Correctness::Correct) => format!("[{letter}]"),
+                (false, Correctness::Misplaced) => format!("({letter})"),
+                (false, Correctness::Wrong) => format!(" {letter} "),
+            }
+        })
+        .collect()
+}
+
+/// Every guess of `history` as a row of [`tiles`], one per line.
+pub fn board(history: &[Guess]) -> String {
+    history
+        .iter()
+        .map(|guess| tiles(guess.word, guess.mask) + "\n")
+        .collect()
+}
+
+/// `letter` in uppercase, as tiles show it.
+pub fn upper(letter: char) -> char {
+    // ß has no single uppercase letter, so it stays as it is
+    let mut upper = letter.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) => upper,
+        _ => letter,
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::ValueEnum;
+
+use super::batch::{self, print_failures, Record};
+use super::{html, records};
+
+/// Print the score distribution and failures of every algorithm found in `files`, or write
+/// them to `out` as an HTML page along with every game.
+pub fn report(files: &[PathBuf], out: Option<&Path>) -> anyhow::Result<()> {
+    let mut by_algorithm: BTreeMap<String, Vec<Record>> = BTreeMap::new();
+    for path in files {
+        for record in records::read(path)? {
+            let name = record
+                .algorithm
+                .to_possible_value()
+                .expect("no variants are skipped");
+            by_algorithm
+                .entry(name.get_name().to_string())
+                .or_default()
+                .push(record);
+        }
+    }
+
+    if let Some(out) = out {
+        std::fs::write(out, html::Html(&by_algorithm).to_string())
+            .with_context(|| format!("writing {}", out.display()))?;
+        eprintln!("wrote {}", out.display());
+        return Ok(());
+    }
+
+    for (i, (algorithm, records)) in by_algorithm.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("== {algorithm} ==");
+        batch::print_stats(&batch::stats(records));
+        print_failures(records);
+    }
+    Ok(())
+}
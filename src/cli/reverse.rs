@@ -0,0 +1,83 @@
+use std::io::{self, Read};
+
+use anyhow::Context;
+use roget::algorithms::Normalization;
+use roget::{infer_answers, Runtime, SharedGame, Wordle};
+
+/// Print the answers the share grids could have been posted for, most likely first, up to
+/// `limit` of them. Reads the grids from stdin when `grids` is empty.
+///
+/// Every grid must be for the same puzzle. Hard mode grids are replayed against each answer,
+/// so answers no hard mode game could have reached are left out.
+pub fn reverse(
+    wordle: &Wordle,
+    grids: &[String],
+    limit: usize,
+    runtime: &Runtime,
+) -> anyhow::Result<()> {
+    let texts = match grids.is_empty() {
+        false => grids.to_vec(),
+        true => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .context("reading grids from stdin")?;
+            split(&text)
+        }
+    };
+    anyhow::ensure!(!texts.is_empty(), "no share grids given");
+    let games = texts
+        .iter()
+        .enumerate()
+        .map(|(i, text)| {
+            text.parse::<SharedGame>()
+                .with_context(|| format!("grid {}", i + 1))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let mut puzzles: Vec<usize> = games.iter().filter_map(|game| game.puzzle).collect();
+    puzzles.sort_unstable();
+    puzzles.dedup();
+    anyhow::ensure!(
+        puzzles.len() <= 1,
+        "the grids are for different puzzles: {puzzles:?}"
+    );
+
+    let dictionary = wordle.dictionary();
+    let matrix = super::matrix::cached(dictionary, runtime)?;
+    let priors = Normalization::default().weights(dictionary.answers());
+    let mut answers = infer_answers(&matrix, &priors, &games);
+    let hard: Vec<&SharedGame> = games.iter().filter(|game| game.hard_mode).collect();
+    if !hard.is_empty() {
+        answers.retain(|&(answer, _)| {
+            hard.iter()
+                .all(|game| game.replay(answer, dictionary).is_ok())
+        });
+        // the chances were shared out before hard mode ruled some answers out
+        let total: f64 = answers.iter().map(|&(_, p)| p).sum();
+        for (_, p) in &mut answers {
+            *p /= total;
+        }
+    }
+
+    anyhow::ensure!(!answers.is_empty(), "no answer fits every grid");
+    println!("{} answers fit every grid", answers.len());
+    for (i, (answer, p)) in answers.iter().take(limit).enumerate() {
+        println!("{:>4}. {answer} {:>6.2}%", i + 1, p * 100.0);
+    }
+    Ok(())
+}
+
+/// Split pasted text into grids, each starting at a `Wordle` title line.
+fn split(text: &str) -> Vec<String> {
+    let mut grids: Vec<String> = Vec::new();
+    for line in text.lines() {
+        match grids.last_mut() {
+            Some(grid) if !line.trim_start().starts_with("Wordle") => {
+                grid.push_str(line);
+                grid.push('\n');
+            }
+            _ => grids.push(format!("{line}\n")),
+        }
+    }
+    grids
+}
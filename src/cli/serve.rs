@@ -0,0 +1,165 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::post;
+use axum::{Json, Router};
+use roget::{Guesser, Word, Wordle};
+use serde::{Deserialize, Serialize};
+
+use super::service;
+
+type Error = (StatusCode, String);
+
+fn invalid(message: impl Into<String>) -> Error {
+    (StatusCode::UNPROCESSABLE_ENTITY, message.into())
+}
+
+struct Server<F> {
+    wordle: Wordle,
+    guesser: F,
+}
+
+#[derive(Debug, Deserialize)]
+struct Turn {
+    word: Word,
+    mask: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SuggestRequest {
+    #[serde(default)]
+    history: Vec<Turn>,
+    /// How many candidates to list.
+    #[serde(default = "default_limit")]
+    limit: usize,
+}
+
+fn default_limit() -> usize {
+    10
+}
+
+#[derive(Debug, Serialize)]
+struct Candidate {
+    word: Word,
+    count: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct SuggestResponse {
+    suggestion: Word,
+    /// How many answers are still possible.
+    remaining: usize,
+    /// The most common of them, up to the requested limit.
+    candidates: Vec<Candidate>,
+}
+
+/// `POST /suggest`: the algorithm's next guess after `history`, with the answers left.
+async fn suggest<F, G>(
+    State(server): State<Arc<Server<F>>>,
+    Json(request): Json<SuggestRequest>,
+) -> Result<Json<SuggestResponse>, Error>
+where
+    F: Fn() -> G,
+    G: Guesser,
+{
+    let history = request
+        .history
+        .iter()
+        .map(|turn| service::turn(turn.word, &turn.mask))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(invalid)?;
+    let suggestion =
+        service::suggest(&server.wordle, (server.guesser)(), &history).map_err(invalid)?;
+
+    Ok(Json(SuggestResponse {
+        suggestion: suggestion.suggestion,
+        remaining: suggestion.candidates.len(),
+        candidates: suggestion
+            .candidates
+            .into_iter()
+            .take(request.limit)
+            .map(|(word, count)| Candidate { word, count })
+            .collect(),
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+struct ScoreRequest {
+    answer: Word,
+    guesses: Vec<Word>,
+}
+
+#[derive(Debug, Serialize)]
+struct ScoredGuess {
+    word: Word,
+    mask: String,
+    /// How many answers were still possible after this guess.
+    remaining: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct ScoreResponse {
+    guesses: Vec<ScoredGuess>,
+    /// How many guesses it took, or `null` if the answer was never guessed.
+    score: Option<usize>,
+    /// How many the algorithm takes on the same answer.
+    solver_score: Option<usize>,
+}
+
+/// `POST /score`: grade a finished game guess by guess and compare it with the algorithm.
+async fn score<F, G>(
+    State(server): State<Arc<Server<F>>>,
+    Json(request): Json<ScoreRequest>,
+) -> Result<Json<ScoreResponse>, Error>
+where
+    F: Fn() -> G,
+    G: Guesser,
+{
+    let scored = service::score(
+        &server.wordle,
+        (server.guesser)(),
+        request.answer,
+        &request.guesses,
+    )
+    .map_err(invalid)?;
+    Ok(Json(ScoreResponse {
+        guesses: scored
+            .guesses
+            .into_iter()
+            .map(|(guess, remaining)| ScoredGuess {
+                word: guess.word,
+                mask: service::mask_letters(guess.mask),
+                remaining,
+            })
+            .collect(),
+        score: scored.score,
+        solver_score: scored.solver_score,
+    }))
+}
+
+/// Serve suggestions over HTTP on `port` until the process is stopped.
+pub fn serve<F, G>(wordle: &Wordle, guesser: F, port: u16) -> anyhow::Result<()>
+where
+    F: Fn() -> G + Send + Sync + 'static,
+    G: Guesser + 'static,
+{
+    let server = Arc::new(Server {
+        wordle: wordle.clone(),
+        guesser,
+    });
+    let app = Router::new()
+        .route("/suggest", post(suggest::<F, G>))
+        .route("/score", post(score::<F, G>))
+        .with_state(server);
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async {
+        let address = SocketAddr::from(([127, 0, 0, 1], port));
+        let listener = tokio::net::TcpListener::bind(address).await?;
+        eprintln!("listening on http://{address}");
+        axum::serve(listener, app).await?;
+        Ok(())
+    })
+}
@@ -0,0 +1,86 @@
+//! What `roget serve` and `roget grpc` answer, apart from how requests arrive.
+
+use roget::{parse_mask, Correctness, Guess, Guesser, Oracle, Word, Wordle};
+
+/// Masks travel as the `g`/`y`/`x` letters, though any notation `parse_mask` reads is taken.
+pub fn mask_letters(mask: [Correctness; 5]) -> String {
+    mask.iter().map(|c| format!("{c:#}")).collect()
+}
+
+/// A turn of a request's history, with its mask still as letters.
+pub fn turn(word: Word, mask: &str) -> Result<Guess, String> {
+    let mask = parse_mask(mask).map_err(|e| format!("{mask}: {e}"))?;
+    Ok(Guess { word, mask })
+}
+
+pub struct Suggestion {
+    pub suggestion: Word,
+    /// Every answer still possible, most common first.
+    pub candidates: Vec<(Word, usize)>,
+}
+
+/// The algorithm's next guess after `history`, with the answers left.
+pub fn suggest(
+    wordle: &Wordle,
+    mut guesser: impl Guesser,
+    history: &[Guess],
+) -> Result<Suggestion, String> {
+    let mut candidates: Vec<_> = wordle.candidates(history).collect();
+    if candidates.is_empty() {
+        return Err("no answer matches that history".to_string());
+    }
+    candidates.sort_by(|(a, x), (b, y)| y.cmp(x).then(a.cmp(b)));
+
+    // guessers keep state between turns, so take a fresh one through the whole game
+    let mut suggestion = guesser.guess(&[]);
+    for turn in 1..=history.len() {
+        suggestion = guesser.guess(&history[..turn]);
+    }
+    Ok(Suggestion {
+        suggestion,
+        candidates,
+    })
+}
+
+pub struct Scored {
+    /// Every guess up to the answer, with how many answers were still possible after it.
+    pub guesses: Vec<(Guess, usize)>,
+    /// How many guesses it took, or `None` if the answer was never guessed.
+    pub score: Option<usize>,
+    /// How many the algorithm takes on the same answer.
+    pub solver_score: Option<usize>,
+}
+
+/// Grade a finished game guess by guess and compare it with the algorithm.
+pub fn score(
+    wordle: &Wordle,
+    guesser: impl Guesser,
+    mut answer: Word,
+    guesses: &[Word],
+) -> Result<Scored, String> {
+    if !wordle.candidates(&[]).any(|(word, _)| word == answer) {
+        return Err(format!("{answer} is not a possible answer"));
+    }
+    for &word in guesses {
+        wordle.check(word).map_err(|e| e.to_string())?;
+    }
+
+    let mut history = Vec::new();
+    let mut scored = Vec::new();
+    let mut score = None;
+    for (i, &word) in guesses.iter().enumerate() {
+        let mask = answer.grade(word);
+        history.push(Guess { word, mask });
+        scored.push((history[i].clone(), wordle.candidates(&history).count()));
+        if mask == [Correctness::Correct; 5] {
+            score = Some(i + 1);
+            break;
+        }
+    }
+
+    Ok(Scored {
+        guesses: scored,
+        score,
+        solver_score: wordle.play(&answer.to_string(), guesser),
+    })
+}
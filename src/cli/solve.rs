@@ -0,0 +1,67 @@
+use std::io::{self, Write};
+
+use roget::{GameResult, Guesser, Luck, Progress, ShareGrid, Word, Wordle};
+
+use super::render::tiles;
+use super::traced::Traced;
+
+/// Play one game against `answer` and show how the candidate set shrank after each guess.
+///
+/// With `share`, finish with the emoji grid Wordle lets you post.
+pub fn solve(wordle: &Wordle, answer: Word, guesser: impl Guesser, share: bool) -> io::Result<()> {
+    let result = wordle.play_game(&answer.to_string(), Traced(guesser));
+    trace(wordle, &result, &mut io::stdout().lock())?;
+
+    if share {
+        println!();
+        println!("{}", ShareGrid::new(&result.guesses));
+    }
+    Ok(())
+}
+
+/// Write each guess of a finished game with its mask, how many candidates it left, and the
+/// bits of information it gained against those it was expected to, then how much of the
+/// game was skill and how much luck.
+pub fn trace(wordle: &Wordle, result: &GameResult, out: &mut impl Write) -> io::Result<()> {
+    let answer = result.answer;
+    // records saved before games kept their progress have to work it out again
+    let progress = match result.progress.len() == result.guesses.len() {
+        true => result.progress.clone(),
+        false => (0..result.guesses.len())
+            .map(|i| Progress {
+                before: wordle.candidates(&result.guesses[..i]).count(),
+                after: wordle.candidates(&result.guesses[..=i]).count(),
+            })
+            .collect(),
+    };
+    let luck = wordle.luck(result);
+    for (i, ((guess, step), luck)) in result.guesses.iter().zip(&progress).zip(&luck).enumerate() {
+        let word = guess.word;
+        writeln!(
+            out,
+            "{:>2}. {} {word}  {} -> {} candidates ({:.2} bits, {:.2} expected)",
+            i + 1,
+            tiles(word, guess.mask),
+            step.before,
+            step.after,
+            step.bits(),
+            luck.expected
+        )?;
+    }
+
+    let total: Luck = luck.into_iter().sum();
+    writeln!(
+        out,
+        "skill {:.2} bits, luck {:+.2} bits",
+        total.expected,
+        total.luck()
+    )?;
+    match result.score {
+        Some(score) => writeln!(out, "solved {answer} in {score}"),
+        None => writeln!(
+            out,
+            "gave up on {answer} after {} guesses",
+            result.guesses.len()
+        ),
+    }
+}
@@ -0,0 +1,91 @@
+use anyhow::Context;
+use clap::ValueEnum;
+use roget::{GameStats, Streaks, Wordle};
+
+use super::batch;
+use crate::Implementation;
+
+/// The guess distribution a player's own statistics show, as `--distribution` takes it: the
+/// games solved in 1 to 6 guesses, then the games lost, e.g. `1,10,40,50,20,5,3`.
+pub fn distribution(counts: &str) -> anyhow::Result<GameStats> {
+    let counts = counts
+        .split(',')
+        .map(|n| n.trim().parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("{counts:?} is not a list of game counts"))?;
+    let (&lost, solved) = counts
+        .split_last()
+        .context("the distribution has no games")?;
+    let mut stats = GameStats::new();
+    for (score, &n) in (1..).zip(solved) {
+        for _ in 0..n {
+            stats.record_score(Some(score));
+        }
+    }
+    for _ in 0..lost {
+        stats.record_score(None);
+    }
+    anyhow::ensure!(stats.games() > 0, "the distribution has no games");
+    Ok(stats)
+}
+
+/// Play the daily puzzle for `days` days, each game going like one of `stats`, and report
+/// how likely a game is to be lost within `max_guesses`, how long streaks last, and how
+/// much the scores and streaks vary.
+pub fn streak(
+    stats: &GameStats,
+    name: &str,
+    max_guesses: usize,
+    days: usize,
+    seed: u64,
+) -> anyhow::Result<()> {
+    anyhow::ensure!(stats.games() > 0, "there are no games to draw from");
+    let streaks = Streaks::simulate(stats, max_guesses, days, seed);
+    let failure = stats.failure_rate(max_guesses).unwrap_or(0.0);
+
+    println!("{name} over {} games:", stats.games());
+    if let (Some(mean), Some(variance)) = (stats.mean(), stats.variance()) {
+        println!(
+            "  score            {mean:.3} ± {:.3} (variance {variance:.3})",
+            variance.sqrt()
+        );
+    }
+    println!(
+        "  lost             {:.3}% of games, within {max_guesses} guesses",
+        failure * 100.0
+    );
+    match stats.expected_streak(max_guesses) {
+        Some(expected) => println!("  expected streak  {expected:.1} games"),
+        None => println!("  expected streak  unending"),
+    }
+
+    println!("simulated over {days} days (seed {seed}):");
+    println!("  losses           {}", streaks.losses());
+    if let (Some(mean), Some(variance)) = (streaks.mean(), streaks.variance()) {
+        println!(
+            "  streak           {mean:.1} ± {:.1} games",
+            variance.sqrt()
+        );
+    }
+    println!("  longest streak   {} games", streaks.longest());
+    println!("  current streak   {} games", streaks.current);
+    Ok(())
+}
+
+/// The games `implementation` plays on `answers`, as the distribution to simulate.
+pub fn played(
+    wordle: &Wordle,
+    implementation: Implementation,
+    answers: &[&str],
+) -> (GameStats, String) {
+    let factory = implementation.factory(wordle.dictionary());
+    let records = batch::run(wordle, implementation, &factory, answers, &[], None);
+    let mut stats = GameStats::new();
+    for record in &records {
+        stats.record(&record.result);
+    }
+    let name = implementation
+        .to_possible_value()
+        .expect("no variants are skipped");
+    (stats, name.get_name().to_string())
+}
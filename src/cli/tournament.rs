@@ -0,0 +1,175 @@
+use std::cmp::Ordering;
+#[cfg(feature = "sqlite")]
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use clap::ValueEnum;
+use roget::{GameStats, Wordle};
+
+use super::batch::{self, Record};
+use super::plugin::Plugin;
+use crate::Implementation;
+
+/// One algorithm or plugin's games.
+struct Entrant {
+    name: String,
+    records: Vec<Record>,
+    stats: GameStats,
+    time: Duration,
+}
+
+/// Play the same answers with each of `implementations` and `plugins`, then rank them and
+/// print how each did against every other, answer by answer.
+///
+/// Entrants are ranked by unsolved games, then average score, with ties broken by the worst
+/// game and then by speed.
+///
+/// With a database, every pairing is also a match for the entrants' Elo ratings, which
+/// start from where the last run in the database left them.
+pub fn tournament(
+    wordle: &Wordle,
+    implementations: &[Implementation],
+    plugins: Vec<Plugin>,
+    answers: &[&str],
+    #[cfg(feature = "sqlite")] db: Option<&Path>,
+) -> anyhow::Result<()> {
+    let mut entrants = Vec::new();
+    let mut enter = |name: String, play: &dyn Fn() -> Vec<Record>| {
+        eprintln!("playing {name}");
+        let start = Instant::now();
+        let records = play();
+        entrants.push(Entrant {
+            name,
+            stats: batch::stats(&records),
+            records,
+            time: start.elapsed(),
+        });
+    };
+    for &implementation in implementations {
+        let name = implementation
+            .to_possible_value()
+            .expect("no variants are skipped")
+            .get_name()
+            .to_string();
+        let factory = implementation.factory(wordle.dictionary());
+        enter(name, &|| {
+            batch::run(wordle, implementation, &factory, answers, &[], None)
+        });
+    }
+    for plugin in plugins {
+        let plugin = Arc::new(plugin);
+        // the records are only scored here, so which algorithm they claim does not matter
+        enter(plugin.name().to_string(), &|| {
+            batch::run(
+                wordle,
+                Implementation::default(),
+                || plugin.guesser(),
+                answers,
+                &[],
+                None,
+            )
+        });
+    }
+
+    entrants.sort_by(rank);
+
+    let width = entrants
+        .iter()
+        .map(|entrant| entrant.name.len())
+        .max()
+        .unwrap_or(0)
+        .max(7);
+    print!(
+        "{:>4} {:<width$} {:>8} {:>5} {:>5} {:>10}",
+        "rank", "entrant", "average", "worst", "DNF", "time"
+    );
+    for entrant in &entrants {
+        print!(" {:>width$}", entrant.name);
+    }
+    println!();
+
+    for (i, entrant) in entrants.iter().enumerate() {
+        let average = entrant
+            .stats
+            .mean()
+            .map_or("-".to_string(), |mean| format!("{mean:.4}"));
+        let worst = entrant
+            .stats
+            .worst()
+            .map_or("-".to_string(), |worst| worst.to_string());
+        print!(
+            "{:>4} {:<width$} {average:>8} {worst:>5} {:>5} {:>10}",
+            i + 1,
+            entrant.name,
+            entrant.stats.dnf(),
+            format!("{:.2?}", entrant.time)
+        );
+        // wins, losses, and ties against each opponent, answer by answer
+        for opponent in &entrants {
+            let cell = match std::ptr::eq(entrant, opponent) {
+                true => "-".to_string(),
+                false => {
+                    let paired = batch::paired(&entrant.records, &opponent.records);
+                    format!("{}-{}-{}", paired.wins, paired.losses, paired.ties)
+                }
+            };
+            print!(" {cell:>width$}");
+        }
+        println!();
+    }
+
+    #[cfg(feature = "sqlite")]
+    if let Some(path) = db {
+        rate(&entrants, path)?;
+    }
+    Ok(())
+}
+
+/// Play every pairing of `entrants` as one Elo match, update their ratings in the
+/// database at `path`, and print the new ones.
+#[cfg(feature = "sqlite")]
+fn rate(entrants: &[Entrant], path: &Path) -> anyhow::Result<()> {
+    let mut conn = super::db::open(path)?;
+    let names: Vec<String> = entrants.iter().map(|e| e.name.clone()).collect();
+    let before = super::db::ratings(&conn, &names)?;
+    let mut after = before.clone();
+    for (i, a) in entrants.iter().enumerate() {
+        let change: f64 = entrants
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .map(|(j, b)| {
+                let score = batch::paired(&a.records, &b.records).score();
+                before[i].change(&before[j], score)
+            })
+            .sum();
+        after[i].apply(change, entrants.len() - 1);
+    }
+
+    println!();
+    println!("{:<15} {:>7} {:>7}", "entrant", "rating", "change");
+    for ((name, before), after) in names.iter().zip(&before).zip(&after) {
+        println!(
+            "{name:<15} {:>7.1} {:>+7.1}",
+            after.rating,
+            after.rating - before.rating
+        );
+    }
+    let ratings: Vec<_> = names.into_iter().zip(after).collect();
+    super::db::rate(&mut conn, &ratings)?;
+    Ok(())
+}
+
+/// Fewer unsolved games first, then a lower average, then a better worst game, then less
+/// time.
+fn rank(a: &Entrant, b: &Entrant) -> Ordering {
+    let average = |entrant: &Entrant| entrant.stats.mean().unwrap_or(f64::INFINITY);
+    let worst = |entrant: &Entrant| entrant.stats.worst().unwrap_or(usize::MAX);
+    a.stats
+        .dnf()
+        .cmp(&b.stats.dnf())
+        .then(average(a).total_cmp(&average(b)))
+        .then(worst(a).cmp(&worst(b)))
+        .then(a.time.cmp(&b.time))
+}
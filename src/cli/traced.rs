@@ -0,0 +1,25 @@
+use std::time::Instant;
+
+use roget::{Cancel, Guess, Guesser, Word};
+use tracing::{debug, debug_span};
+
+/// Wraps a guesser so every guess runs in its own `guess` span and logs what it chose.
+pub struct Traced<G>(pub G);
+
+impl<G: Guesser> Guesser for Traced<G> {
+    fn guess(&mut self, history: &[Guess]) -> Word {
+        self.guess_until(history, &Cancel::new())
+    }
+
+    fn guess_until(&mut self, history: &[Guess], cancel: &Cancel) -> Word {
+        let _span = debug_span!("guess", turn = history.len() + 1).entered();
+        let start = Instant::now();
+        let guess = self.0.guess_until(history, cancel);
+        debug!(%guess, elapsed = ?start.elapsed(), "guessed");
+        guess
+    }
+
+    fn ranked(&mut self, history: &[Guess]) -> Vec<Word> {
+        self.0.ranked(history)
+    }
+}
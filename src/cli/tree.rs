@@ -0,0 +1,133 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Context;
+use roget::algorithms::{Normalization, PrecomputedTree};
+use roget::{DecisionTree, GameResult, Guesser, Wordle};
+
+/// Play every answer and write the strategy the guesser followed as a Graphviz graph.
+///
+/// The games are cached under `name`, the algorithm's, so the same strategy is not played
+/// out again.
+pub fn export<G>(
+    wordle: &Wordle,
+    name: &str,
+    mk: impl Fn() -> G + Sync,
+    answers: &[&str],
+    out: Option<&Path>,
+) -> anyhow::Result<()>
+where
+    G: Guesser,
+{
+    // the hasher may change between Rust versions, which only costs a cache miss
+    let mut hasher = DefaultHasher::new();
+    answers.hash(&mut hasher);
+    let key = format!("{:016x}", hasher.finish());
+    let path = super::cache::path(
+        "tree",
+        wordle.dictionary(),
+        &[name, env!("CARGO_PKG_VERSION"), &key],
+        "jsonl",
+    )?;
+    let games = super::cache::cached(
+        &path,
+        load,
+        || Ok(wordle.play_all(answers, mk)),
+        |games, path| save(games, path),
+    )?;
+    let tree = DecisionTree::from_games(&games)
+        .context("only algorithms that always guess the same way have a decision tree")?;
+    eprintln!(
+        "{} guesses, up to {} deep, over {} answers",
+        tree.size(),
+        tree.depth(),
+        games.len()
+    );
+    write_dot(&tree, out)
+}
+
+/// Write `games` one JSON object per line.
+fn save(games: &[GameResult], path: &Path) -> io::Result<()> {
+    let mut out = io::BufWriter::new(std::fs::File::create(path)?);
+    for game in games {
+        serde_json::to_writer(&mut out, game)?;
+        writeln!(out)?;
+    }
+    out.flush()
+}
+
+/// Read back what [`save`] wrote.
+fn load(path: &Path) -> io::Result<Vec<GameResult>> {
+    let file = io::BufReader::new(std::fs::File::open(path)?);
+    file.lines()
+        .map(|line| Ok(serde_json::from_str(&line?)?))
+        .collect()
+}
+
+/// Load a published strategy, check how it does against every answer, and write it as a
+/// Graphviz graph.
+pub fn import(
+    wordle: &Wordle,
+    path: &Path,
+    answers: &[&str],
+    out: Option<&Path>,
+) -> anyhow::Result<()> {
+    let text =
+        std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let tree: Arc<DecisionTree> = Arc::new(
+        text.parse()
+            .with_context(|| format!("parsing {}", path.display()))?,
+    );
+    if let Some(word) = guesses(&tree).find(|&word| !wordle.contains(word)) {
+        anyhow::bail!("the tree guesses {word}, which is not in the dictionary");
+    }
+
+    let priors = Normalization::default().weights(wordle.dictionary().answers());
+    let games = wordle.play_all(answers, || PrecomputedTree::new(tree.clone(), &priors));
+    // a game stayed in the tree if the tree made its last guess, and that solved it
+    let (covered, missed): (Vec<_>, Vec<_>) = games.iter().partition(|game| {
+        let (last, before) = game.guesses.split_last().expect("every game has a guess");
+        game.score.is_some() && tree.follow(before).is_some_and(|n| n.guess == last.word)
+    });
+
+    let scores: Vec<_> = covered.iter().filter_map(|g| g.score).collect();
+    let average = scores.iter().sum::<usize>() as f64 / scores.len().max(1) as f64;
+    let worst = scores.iter().max().copied().unwrap_or(0);
+    eprintln!(
+        "the tree solves {} of {} answers, averaging {average:.4} guesses, at worst {worst}",
+        covered.len(),
+        games.len()
+    );
+    if !missed.is_empty() {
+        const SHOWN: usize = 20;
+        let words: Vec<_> = missed
+            .iter()
+            .take(SHOWN)
+            .map(|g| g.answer.to_string())
+            .collect();
+        let more = match missed.len().checked_sub(SHOWN) {
+            Some(n) if n > 0 => format!(" and {n} more"),
+            _ => String::new(),
+        };
+        eprintln!("not covered: {}{more}", words.join(" "));
+    }
+    write_dot(&tree, out)
+}
+
+/// Every guess anywhere in `tree`.
+fn guesses(tree: &DecisionTree) -> Box<dyn Iterator<Item = roget::Word> + '_> {
+    Box::new(std::iter::once(tree.guess).chain(tree.children.values().flat_map(guesses)))
+}
+
+fn write_dot(tree: &DecisionTree, out: Option<&Path>) -> anyhow::Result<()> {
+    let dot = tree.dot().to_string();
+    match out {
+        Some(path) => {
+            std::fs::write(path, dot).with_context(|| format!("writing {}", path.display()))?
+        }
+        None => io::stdout().lock().write_all(dot.as_bytes())?,
+    }
+    Ok(())
+}
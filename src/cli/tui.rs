@@ -0,0 +1,290 @@
+use std::io;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use roget::{Correctness, Guess, Guesser, Keyboard, Oracle, Word, Wordle};
+
+use super::assist::read_feedback;
+use super::play::MAX_GUESSES;
+use super::render::upper;
+
+/// How many of the guesser's top words the suggestion panel lists.
+const SUGGESTIONS: usize = 20;
+
+const KEYBOARD: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// A full-screen game: the board, the keyboard colored by what is known, the answers still
+/// possible, and what `guesser` would play next.
+///
+/// With an `answer` this hosts a game like `roget play`; without one it assists with a game
+/// played elsewhere like `roget assist`, reading back the colors it showed.
+pub fn tui(wordle: &Wordle, answer: Option<Word>, guesser: impl Guesser) -> io::Result<()> {
+    let mut terminal = ratatui::try_init()?;
+    let result = App::new(wordle, answer, guesser).run(&mut terminal);
+    ratatui::try_restore()?;
+    result
+}
+
+/// Which list the arrow keys scroll.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    Candidates,
+    Suggestions,
+}
+
+struct App<'a, G> {
+    wordle: &'a Wordle,
+    /// The answer when hosting a game, or `None` when assisting with one.
+    answer: Option<Word>,
+    guesser: G,
+    history: Vec<Guess>,
+    candidates: Vec<Word>,
+    suggestions: Vec<Word>,
+    input: String,
+    /// A line under the input: errors, and how the game ended.
+    message: String,
+    over: bool,
+    focus: Focus,
+    candidate_list: ListState,
+    suggestion_list: ListState,
+}
+
+impl<'a, G: Guesser> App<'a, G> {
+    fn new(wordle: &'a Wordle, answer: Option<Word>, guesser: G) -> Self {
+        let mut app = Self {
+            wordle,
+            answer,
+            guesser,
+            history: Vec::new(),
+            candidates: Vec::new(),
+            suggestions: Vec::new(),
+            input: String::new(),
+            message: String::new(),
+            over: false,
+            focus: Focus::Candidates,
+            candidate_list: ListState::default(),
+            suggestion_list: ListState::default(),
+        };
+        app.refresh();
+        app
+    }
+
+    fn run(mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Esc => return Ok(()),
+                KeyCode::Tab => {
+                    self.focus = match self.focus {
+                        Focus::Candidates => Focus::Suggestions,
+                        Focus::Suggestions => Focus::Candidates,
+                    }
+                }
+                KeyCode::Up => self.list().scroll_up_by(1),
+                KeyCode::Down => self.list().scroll_down_by(1),
+                KeyCode::PageUp => self.list().scroll_up_by(10),
+                KeyCode::PageDown => self.list().scroll_down_by(10),
+                _ if self.over => {}
+                KeyCode::Enter => self.submit(),
+                KeyCode::Backspace => {
+                    self.input.pop();
+                }
+                KeyCode::Char(c) => self.input.push(c),
+                _ => {}
+            }
+        }
+    }
+
+    fn list(&mut self) -> &mut ListState {
+        match self.focus {
+            Focus::Candidates => &mut self.candidate_list,
+            Focus::Suggestions => &mut self.suggestion_list,
+        }
+    }
+
+    /// Play the word typed in, or when assisting, read back the colors it got.
+    fn submit(&mut self) {
+        let line = std::mem::take(&mut self.input);
+        let guess = match self.read(&line) {
+            Ok(guess) => guess,
+            Err(e) => {
+                self.message = e;
+                return;
+            }
+        };
+        self.message.clear();
+        let solved = guess.mask == [Correctness::Correct; 5];
+        self.history.push(guess);
+        let turns = self.history.len();
+        if solved {
+            self.over = true;
+            self.message = format!("solved in {turns}! press Esc to quit");
+        } else if let Some(answer) = self.answer.filter(|_| turns == MAX_GUESSES) {
+            self.over = true;
+            self.message = format!("out of guesses; the word was {answer}. press Esc to quit");
+        }
+        self.refresh();
+    }
+
+    fn read(&self, line: &str) -> Result<Guess, String> {
+        match self.answer {
+            Some(mut answer) => {
+                let word: Word = line.trim().parse().map_err(|e| format!("{e}"))?;
+                self.wordle
+                    .check_guess(&self.history, word)
+                    .map_err(|e| e.to_string())?;
+                Ok(Guess {
+                    word,
+                    mask: answer.grade(word),
+                })
+            }
+            None => {
+                let suggestion = self
+                    .suggestions
+                    .first()
+                    .ok_or("no dictionary words match that feedback")?;
+                read_feedback(line, *suggestion)
+            }
+        }
+    }
+
+    /// Work out the candidates and suggestions after the latest guess.
+    fn refresh(&mut self) {
+        self.candidates = self
+            .wordle
+            .candidates(&self.history)
+            .map(|(word, _)| word)
+            .collect();
+        self.suggestions.clear();
+        // a guesser needs at least one answer left to choose from
+        if !self.over && !self.candidates.is_empty() {
+            self.suggestions = self.guesser.ranked(&self.history);
+            self.suggestions.truncate(SUGGESTIONS);
+        }
+        if self.candidates.is_empty() && !self.over {
+            self.message = "no dictionary words match that feedback".to_string();
+        }
+        self.candidate_list.select(None);
+        self.suggestion_list.select(None);
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, side] =
+            Layout::horizontal([Constraint::Length(48), Constraint::Min(20)]).areas(frame.area());
+        let [board, keyboard, input, help] = Layout::vertical([
+            Constraint::Min(MAX_GUESSES as u16 + 2),
+            Constraint::Length(5),
+            Constraint::Length(4),
+            Constraint::Length(1),
+        ])
+        .areas(main);
+        let [candidates, suggestions] =
+            Layout::vertical([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(side);
+
+        self.draw_board(frame, board);
+        self.draw_keyboard(frame, keyboard);
+        self.draw_input(frame, input);
+        let keys = "Enter plays, Tab and arrows scroll, Esc quits";
+        frame.render_widget(
+            Paragraph::new(keys).style(Style::new().add_modifier(Modifier::DIM)),
+            help,
+        );
+
+        let title = format!("Candidates ({})", self.candidates.len());
+        let focused = self.focus == Focus::Candidates;
+        let words = self.candidates.iter().map(|word| word.to_string());
+        let list = panel(words, &title, focused);
+        frame.render_stateful_widget(list, candidates, &mut self.candidate_list);
+
+        let focused = self.focus == Focus::Suggestions;
+        let words = self.suggestions.iter().map(|word| word.to_string());
+        let list = panel(words, "Suggestions", focused);
+        frame.render_stateful_widget(list, suggestions, &mut self.suggestion_list);
+    }
+
+    fn draw_board(&self, frame: &mut Frame, area: Rect) {
+        let rows: Vec<Line> =
+            self.history
+                .iter()
+                .map(|guess| {
+                    let tiles = guess.word.letters().zip(guess.mask).map(|(letter, c)| {
+                        Span::styled(format!(" {} ", upper(letter)), tile(Some(c)))
+                    });
+                    Line::from(tiles.collect::<Vec<_>>())
+                })
+                .collect();
+        let title = match self.answer {
+            Some(_) => format!("Guess {}/{MAX_GUESSES}", self.history.len()),
+            None => format!("Guess {}", self.history.len()),
+        };
+        frame.render_widget(
+            Paragraph::new(rows).block(Block::bordered().title(title)),
+            area,
+        );
+    }
+
+    fn draw_keyboard(&self, frame: &mut Frame, area: Rect) {
+        let keyboard = Keyboard::from_history(&self.history);
+        let rows: Vec<Line> = KEYBOARD
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                // stagger the rows like a real keyboard
+                let mut keys = vec![Span::raw(" ".repeat(i))];
+                for letter in row.chars() {
+                    let style = tile(keyboard.get(letter));
+                    keys.push(Span::styled(format!(" {} ", upper(letter)), style));
+                }
+                Line::from(keys)
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(rows).block(Block::bordered()), area);
+    }
+
+    fn draw_input(&self, frame: &mut Frame, area: Rect) {
+        let prompt = match (self.answer, self.suggestions.first()) {
+            (Some(_), _) => "Your guess".to_string(),
+            (None, Some(suggestion)) => format!("Colors for {suggestion}, or word and colors"),
+            (None, None) => "Word and colors".to_string(),
+        };
+        let lines = vec![
+            Line::from(format!("> {}", self.input)),
+            Line::styled(self.message.as_str(), Style::new().fg(Color::Yellow)),
+        ];
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(prompt)),
+            area,
+        );
+    }
+}
+
+/// The colors of a tile or key with status `c`, or an unplayed one.
+fn tile(c: Option<Correctness>) -> Style {
+    let style = Style::new().fg(Color::Black).add_modifier(Modifier::BOLD);
+    match c {
+        Some(Correctness::Correct) => style.bg(Color::Green),
+        Some(Correctness::Misplaced) => style.bg(Color::Yellow),
+        Some(Correctness::Wrong) => style.fg(Color::White).bg(Color::DarkGray),
+        None => Style::new(),
+    }
+}
+
+fn panel<'a>(words: impl Iterator<Item = String>, title: &'a str, focused: bool) -> List<'a> {
+    let border = match focused {
+        true => Style::new().fg(Color::Cyan),
+        false => Style::new(),
+    };
+    List::new(words)
+        .block(Block::bordered().title(title).border_style(border))
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+}
@@ -0,0 +1,52 @@
+use std::io;
+
+use clap::ValueEnum;
+use roget::Wordle;
+
+use super::batch;
+use super::solve::trace;
+use crate::Implementation;
+
+/// Play every one of `answers` and fail unless each is solved within `max_guesses`, showing
+/// the full game for any that is not.
+pub fn verify(
+    wordle: &Wordle,
+    implementation: Implementation,
+    answers: &[&str],
+    max_guesses: usize,
+) -> anyhow::Result<()> {
+    let factory = implementation.factory(wordle.dictionary());
+    let records = batch::run(wordle, implementation, &factory, answers, &[], None);
+    let name = implementation
+        .to_possible_value()
+        .expect("no variants are skipped");
+
+    let mut out = io::stdout().lock();
+    let mut violations = 0;
+    for record in &records {
+        if record.result.score.is_some_and(|n| n <= max_guesses) {
+            continue;
+        }
+        if violations > 0 {
+            println!();
+        }
+        violations += 1;
+        println!("{}:", record.result.answer);
+        trace(wordle, &record.result, &mut out)?;
+    }
+
+    if violations > 0 {
+        println!();
+        anyhow::bail!(
+            "{} failed {violations} of {} answers within {max_guesses} guesses",
+            name.get_name(),
+            records.len()
+        );
+    }
+    println!(
+        "{} solves all {} answers within {max_guesses} guesses",
+        name.get_name(),
+        records.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,14 @@
+//! Collection types used by the game and algorithms.
+//!
+//! With `std` these are the hashed collections; without it they fall back to the
+//! ordered ones from `alloc`, which only need `Ord`.
+
+#[cfg(feature = "std")]
+pub(crate) type Map<K, V> = std::collections::HashMap<K, V>;
+#[cfg(feature = "std")]
+pub(crate) type Set<T> = std::collections::HashSet<T>;
+
+#[cfg(not(feature = "std"))]
+pub(crate) type Map<K, V> = alloc::collections::BTreeMap<K, V>;
+#[cfg(not(feature = "std"))]
+pub(crate) type Set<T> = alloc::collections::BTreeSet<T>;
@@ -0,0 +1,121 @@
+use alloc::vec::Vec;
+
+use crate::{Dictionary, Guess, Guesser, Rules, Word};
+
+/// Wraps a guesser so it only plays the guesses a game's [`Rules`] allow, for games in hard
+/// or ultra hard mode.
+///
+/// The wrapped guesser plays the best word in its [ranking](Guesser::ranked) the rules
+/// allow. If it ranked none, it plays the first allowed word of the dictionary, and only
+/// if there is no such word its own first choice.
+///
+/// ```
+/// use roget::{parse_mask, Compliant, Dictionary, Guess, Guesser, Rules, Word};
+/// # struct Stubborn;
+/// # impl Guesser for Stubborn {
+/// #     fn guess(&mut self, _: &[Guess]) -> Word { Word::new("crust") }
+/// #     fn ranked(&mut self, _: &[Guess]) -> Vec<Word> {
+/// #         vec![Word::new("crust"), Word::new("story")]
+/// #     }
+/// # }
+///
+/// let dictionary = Dictionary::parse("crane\ncrust\nstory\n").unwrap();
+/// let history = [Guess {
+///     word: Word::new("crane"),
+///     mask: parse_mask("xyxxx").unwrap(),
+/// }];
+/// let mut guesser = Compliant::new(Stubborn, &dictionary, Rules::UltraHard);
+/// assert_eq!(guesser.guess(&history), "story");
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Compliant<G> {
+    guesser: G,
+    rules: Rules,
+    /// Every word of the dictionary, to fall back on.
+    words: Vec<Word>,
+}
+
+impl<G> Compliant<G> {
+    /// Only play the words of `dictionary` that `rules` allow.
+    pub fn new(guesser: G, dictionary: &Dictionary, rules: Rules) -> Self {
+        let words = dictionary.words().iter().map(|&(word, _)| word).collect();
+        Self {
+            guesser,
+            rules,
+            words,
+        }
+    }
+}
+
+impl<G: Guesser> Guesser for Compliant<G> {
+    fn guess(&mut self, history: &[Guess]) -> Word {
+        self.ranked(history)[0]
+    }
+
+    /// The allowed words of the wrapped guesser's ranking, in its order, or a single word
+    /// if there are none.
+    fn ranked(&mut self, history: &[Guess]) -> Vec<Word> {
+        let ranked = self.guesser.ranked(history);
+        let allowed: Vec<Word> = ranked
+            .iter()
+            .copied()
+            .filter(|&word| self.rules.allows(history, word))
+            .collect();
+        if !allowed.is_empty() {
+            return allowed;
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!("no ranked word is allowed; looking through the dictionary");
+        match self
+            .words
+            .iter()
+            .find(|&&word| self.rules.allows(history, word))
+        {
+            Some(&word) => alloc::vec![word],
+            None => ranked.into_iter().take(1).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::Compliant;
+    use crate::{parse_mask, Dictionary, Guess, Guesser, Rules, Word};
+
+    struct Ranked(Vec<Word>);
+
+    impl Guesser for Ranked {
+        fn guess(&mut self, _: &[Guess]) -> Word {
+            self.0[0]
+        }
+
+        fn ranked(&mut self, _: &[Guess]) -> Vec<Word> {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn plays_by_the_rules() {
+        let dictionary = Dictionary::parse("crane\ncrate\nfuzzy\n").unwrap();
+        let history = [Guess {
+            word: Word::new("crate"),
+            mask: parse_mask("gggxg").unwrap(),
+        }];
+        let words = vec![Word::new("fuzzy"), Word::new("crate")];
+
+        // crate keeps every green, but plays the gray t again
+        let mut guesser = Compliant::new(Ranked(words.clone()), &dictionary, Rules::Hard);
+        assert_eq!(guesser.ranked(&history), ["crate"]);
+
+        // neither is allowed, so it finds crane in the dictionary
+        let mut guesser = Compliant::new(Ranked(words.clone()), &dictionary, Rules::UltraHard);
+        assert_eq!(guesser.guess(&history), "crane");
+
+        let mut guesser = Compliant::new(Ranked(words.clone()), &dictionary, Rules::Normal);
+        assert_eq!(guesser.ranked(&history), words);
+    }
+}
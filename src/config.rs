@@ -0,0 +1,253 @@
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+use core::time::Duration;
+
+use crate::{Cancel, Correctness, Guess, Rules, Word};
+
+/// How a game is played, for [`Wordle::play_with_config`](crate::Wordle::play_with_config).
+///
+/// The defaults are the ones [`Wordle::play_game`](crate::Wordle::play_game) plays by: up to
+/// 32 guesses, so long games still count in the score distribution, no hard mode, the
+/// dictionary's answers, a panic on any guess the game does not allow, and no time limits.
+/// Words are always [`Word::LEN`] letters long.
+///
+/// ```
+/// use roget::{GameConfig, Invalid, Word, Wordle};
+/// # use roget::{Guess, Guesser};
+/// # struct Answer(Word);
+/// # impl Guesser for Answer {
+/// #     fn guess(&mut self, _: &[Guess]) -> Word { self.0 }
+/// # }
+///
+/// let config = GameConfig::new().max_guesses(6).hard_mode(true).on_invalid(Invalid::Forfeit);
+/// let wordle = Wordle::new();
+/// let result = wordle.play_with_config(Word::new("crane"), Answer(Word::new("crane")), &config);
+/// assert_eq!(result.score, Some(1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use = "a config does nothing until a game is played with it"]
+pub struct GameConfig {
+    pub(crate) max_guesses: usize,
+    pub(crate) rules: Rules,
+    pub(crate) seed: Vec<Word>,
+    pub(crate) answers: Option<Vec<Word>>,
+    pub(crate) on_invalid: Invalid,
+    pub(crate) keep_candidates: bool,
+    pub(crate) cancel: Cancel,
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    pub(crate) time_limit: Option<Duration>,
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    pub(crate) guess_time_limit: Option<Duration>,
+}
+
+/// What happens when a guesser plays a word the game does not allow: one outside the
+/// dictionary, or in hard mode one that ignores a hint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Invalid {
+    /// Panic, since the guesser has a bug.
+    #[default]
+    Panic,
+    /// End the game unsolved, without counting the guess.
+    Forfeit,
+}
+
+/// Why a game rejected a guess.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidGuess {
+    /// The word is not in the dictionary. `closest` holds the nearest words that are, if
+    /// any are a letter or two off; see [`Dictionary::closest`](crate::Dictionary::closest).
+    NotInDictionary { word: Word, closest: Vec<Word> },
+    /// Hard mode is on and the word ignores a hint.
+    IgnoresHint(Word),
+    /// Ultra hard mode is on and the word could not be the answer, given the masks so far.
+    Contradicts(Word),
+}
+
+impl InvalidGuess {
+    pub fn word(&self) -> Word {
+        match self {
+            Self::NotInDictionary { word, .. }
+            | Self::IgnoresHint(word)
+            | Self::Contradicts(word) => *word,
+        }
+    }
+}
+
+impl fmt::Display for InvalidGuess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotInDictionary { word, closest } => {
+                write!(f, "{word} is not in the dictionary")?;
+                if let Some((last, rest)) = closest.split_last() {
+                    f.write_str("; did you mean ")?;
+                    for word in rest {
+                        write!(f, "{word}, ")?;
+                    }
+                    if !rest.is_empty() {
+                        f.write_str("or ")?;
+                    }
+                    write!(f, "{last}?")?;
+                }
+                Ok(())
+            }
+            Self::IgnoresHint(word) => {
+                write!(f, "{word} ignores a hint, which hard mode does not allow")
+            }
+            Self::Contradicts(word) => write!(
+                f,
+                "{word} goes against what earlier guesses revealed, which ultra hard mode does \
+                 not allow"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for InvalidGuess {}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameConfig {
+    pub fn new() -> Self {
+        Self {
+            max_guesses: 32,
+            rules: Rules::Normal,
+            seed: Vec::new(),
+            answers: None,
+            on_invalid: Invalid::Panic,
+            keep_candidates: false,
+            cancel: Cancel::new(),
+            #[cfg(all(feature = "std", not(target_family = "wasm")))]
+            time_limit: None,
+            #[cfg(all(feature = "std", not(target_family = "wasm")))]
+            guess_time_limit: None,
+        }
+    }
+
+    /// Give up after `n` guesses; Wordle itself allows 6.
+    pub fn max_guesses(mut self, n: usize) -> Self {
+        self.max_guesses = n;
+        self
+    }
+
+    /// Require every guess to use the hints so far, as in Wordle's hard mode: green letters
+    /// stay where they are and yellow letters are played again.
+    pub fn hard_mode(mut self, hard: bool) -> Self {
+        self.rules = match hard {
+            true => Rules::Hard,
+            false => Rules::Normal,
+        };
+        self
+    }
+
+    /// Only allow the guesses `rules` does; [`hard_mode`](GameConfig::hard_mode) picks
+    /// between normal and hard.
+    pub fn rules(mut self, rules: Rules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Open with `words` whatever the guesser would have played, as
+    /// [`Wordle::play_seeded`](crate::Wordle::play_seeded) does.
+    pub fn seed(mut self, words: &[Word]) -> Self {
+        self.seed = words.to_vec();
+        self
+    }
+
+    /// Track which of `answers` are still possible after each guess, instead of the
+    /// dictionary's answers.
+    pub fn answers(mut self, answers: Vec<Word>) -> Self {
+        self.answers = Some(answers);
+        self
+    }
+
+    pub fn on_invalid(mut self, policy: Invalid) -> Self {
+        self.on_invalid = policy;
+        self
+    }
+
+    /// Keep the answers still possible after each guess in
+    /// [`GameResult::candidates`](crate::GameResult::candidates).
+    pub fn keep_candidates(mut self, keep: bool) -> Self {
+        self.keep_candidates = keep;
+        self
+    }
+
+    /// Hand `token` to the guesser on every guess, and end the game unsolved before the
+    /// next guess once it is cancelled.
+    pub fn cancel(mut self, token: Cancel) -> Self {
+        self.cancel = token;
+        self
+    }
+
+    /// End the game unsolved once it has run for `limit`, telling the guesser to wrap up
+    /// when it runs out; see [`Cancel`].
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    pub fn time_limit(mut self, limit: Duration) -> Self {
+        self.time_limit = Some(limit);
+        self
+    }
+
+    /// Tell the guesser to wrap up each guess after `limit`; see [`Cancel`]. A guess that
+    /// runs over still counts.
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    pub fn guess_time_limit(mut self, limit: Duration) -> Self {
+        self.guess_time_limit = Some(limit);
+        self
+    }
+}
+
+/// Whether `word` uses every hint `history` revealed, as hard mode requires.
+pub(crate) fn uses_hints(history: &[Guess], word: Word) -> bool {
+    let letters = word.as_bytes();
+    history.iter().all(|guess| {
+        let revealed = guess.word.as_bytes();
+        let greens_kept = revealed
+            .iter()
+            .zip(letters)
+            .zip(&guess.mask)
+            .all(|((a, b), &c)| c != Correctness::Correct || a == b);
+        // each green or yellow tile asks for one more copy of its letter
+        let copies_kept = revealed.iter().all(|&letter| {
+            let hinted = revealed
+                .iter()
+                .zip(&guess.mask)
+                .filter(|&(&l, &c)| l == letter && c != Correctness::Wrong)
+                .count();
+            letters.iter().filter(|&&l| l == letter).count() >= hinted
+        });
+        greens_kept && copies_kept
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::uses_hints;
+    use crate::{parse_mask, Guess, Word};
+
+    #[test]
+    fn hard_mode_hints() {
+        let history = [Guess {
+            word: Word::new("crane"),
+            mask: parse_mask("gxyxx").unwrap(),
+        }];
+        assert!(uses_hints(&history, Word::new("cabal")));
+        // the green c moved
+        assert!(!uses_hints(&history, Word::new("acorn")));
+        // the yellow a is missing
+        assert!(!uses_hints(&history, Word::new("close")));
+        assert!(uses_hints(&[], Word::new("fuzzy")));
+
+        // two yellow e's need two e's
+        let history = [Guess {
+            word: Word::new("geese"),
+            mask: parse_mask("xyyxx").unwrap(),
+        }];
+        assert!(uses_hints(&history, Word::new("emery")));
+        assert!(!uses_hints(&history, Word::new("elbow")));
+    }
+}
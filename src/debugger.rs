@@ -0,0 +1,212 @@
+use alloc::vec::Vec;
+
+use crate::{Correctness, GameResult, Guess, Guesser, Luck, Progress, Word, Wordle};
+
+/// Steps through a finished game guess by guess, to see why it went the way it did: which
+/// answers were still possible at each point, what else the guesser was considering, and
+/// how those words would have done against the answer.
+///
+/// The possible answers are the dictionary's, so a game played against a narrower answer
+/// list sees more of them here than it did.
+///
+/// ```
+/// use roget::{Debugger, Word, Wordle};
+/// # use roget::{Guess, Guesser};
+/// # struct Script;
+/// # impl Guesser for Script {
+/// #     fn guess(&mut self, history: &[Guess]) -> Word {
+/// #         Word::new(["crane", "right"][history.len()])
+/// #     }
+/// # }
+///
+/// let wordle = Wordle::new();
+/// let game = wordle.play_game("right", Script);
+/// let mut debugger = Debugger::new(&wordle, game);
+/// assert_eq!(debugger.played().unwrap().word, "crane");
+/// assert!(debugger.forward());
+/// let wasted = debugger.what_if(Word::new("crane"));
+/// assert_eq!(wasted.left, debugger.candidates().len());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Debugger<'a> {
+    wordle: &'a Wordle,
+    game: GameResult,
+    /// How many guesses have been stepped over.
+    step: usize,
+    /// The answers still possible after those guesses.
+    remaining: Vec<Word>,
+}
+
+/// How playing one word at some point of a game would have gone, against the real answer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Alternative {
+    pub word: Word,
+    /// The mask it would have got.
+    pub mask: [Correctness; 5],
+    /// How many answers would still have been possible after it.
+    pub left: usize,
+    pub luck: Luck,
+}
+
+impl Alternative {
+    pub fn solves(&self) -> bool {
+        self.mask == [Correctness::Correct; 5]
+    }
+}
+
+impl<'a> Debugger<'a> {
+    /// Start before the first guess of `game`, which was played on `wordle`.
+    pub fn new(wordle: &'a Wordle, game: GameResult) -> Self {
+        Self {
+            wordle,
+            game,
+            step: 0,
+            remaining: wordle.answers(),
+        }
+    }
+
+    pub fn game(&self) -> &GameResult {
+        &self.game
+    }
+
+    /// How many guesses have been stepped over, from 0 at the start to the number of
+    /// guesses at the end.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// The guesses made so far.
+    pub fn history(&self) -> &[Guess] {
+        &self.game.guesses[..self.step]
+    }
+
+    /// The guess made next, or `None` at the end of the game.
+    pub fn played(&self) -> Option<&Guess> {
+        self.game.guesses.get(self.step)
+    }
+
+    /// The answers still possible before the next guess.
+    pub fn candidates(&self) -> &[Word] {
+        &self.remaining
+    }
+
+    /// Step over the next guess; false at the end of the game.
+    pub fn forward(&mut self) -> bool {
+        let Some(guess) = self.game.guesses.get(self.step) else {
+            return false;
+        };
+        Progress::narrow(&mut self.remaining, guess);
+        self.step += 1;
+        true
+    }
+
+    /// Step back before the last guess; false at the start of the game.
+    pub fn back(&mut self) -> bool {
+        if self.step == 0 {
+            return false;
+        }
+        self.seek(self.step - 1);
+        true
+    }
+
+    /// Go to just after `step` guesses, or to the end if the game is shorter.
+    pub fn seek(&mut self, step: usize) {
+        self.step = 0;
+        self.remaining = self.wordle.answers();
+        while self.step < step && self.forward() {}
+    }
+
+    /// How playing `word` next would have gone.
+    pub fn what_if(&self, word: Word) -> Alternative {
+        let answer = self.game.answer;
+        let mask = Correctness::compute(answer, word);
+        let guess = Guess { word, mask };
+        Alternative {
+            word,
+            mask,
+            left: self.remaining.iter().filter(|&&w| guess.matches(w)).count(),
+            luck: Luck::of(word, answer, &self.remaining),
+        }
+    }
+
+    /// The `limit` words `guesser` ranks highest for the next guess, best first, and how
+    /// each would have gone; see [`Guesser::ranked`].
+    ///
+    /// `guesser` should be fresh: it is shown every step of the history so far first, as
+    /// in a game.
+    pub fn alternatives<G: Guesser>(&self, mut guesser: G, limit: usize) -> Vec<Alternative> {
+        let history = self.history();
+        for i in 0..history.len() {
+            guesser.guess(&history[..i]);
+        }
+        guesser
+            .ranked(history)
+            .into_iter()
+            .take(limit)
+            .map(|word| self.what_if(word))
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "embedded-dictionary"))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::Debugger;
+    use crate::algorithms::Naive;
+    use crate::{Guess, Guesser, Word, Wordle};
+
+    /// Plays `crane`, `slate`, then `right`.
+    struct Script;
+
+    impl Guesser for Script {
+        fn guess(&mut self, history: &[Guess]) -> Word {
+            Word::new(["crane", "slate", "right"][history.len()])
+        }
+    }
+
+    #[test]
+    fn steps() {
+        let wordle = Wordle::new();
+        let game = wordle.play_game("right", Script);
+        let mut debugger = Debugger::new(&wordle, game);
+        assert_eq!(debugger.candidates().len(), wordle.candidates(&[]).count());
+        assert!(!debugger.back());
+
+        let mut sizes = Vec::new();
+        while debugger.forward() {
+            sizes.push(debugger.candidates().len());
+        }
+        let expected: Vec<usize> = debugger.game().progress.iter().map(|p| p.after).collect();
+        assert_eq!(sizes, expected);
+        assert_eq!(debugger.step(), 3);
+        assert!(debugger.played().is_none());
+
+        assert!(debugger.back());
+        assert_eq!(debugger.played().unwrap().word, "right");
+        assert_eq!(debugger.history().len(), 2);
+        assert_eq!(debugger.candidates().len(), expected[1]);
+        debugger.seek(10);
+        assert_eq!(debugger.step(), 3);
+    }
+
+    #[test]
+    fn alternatives() {
+        let wordle = Wordle::new();
+        let game = wordle.play_game("right", Script);
+        let mut debugger = Debugger::new(&wordle, game);
+        debugger.seek(2);
+
+        let alternatives = debugger.alternatives(Naive::new(), 5);
+        assert_eq!(alternatives.len(), 5);
+        // Naive only suggests candidates, and none of them can rule out the answer
+        for alternative in &alternatives {
+            assert!(debugger.candidates().contains(&alternative.word));
+            assert_eq!(alternative.solves(), alternative.word == "right");
+            assert!(alternative.left >= 1);
+        }
+        let right = debugger.what_if(Word::new("right"));
+        assert!(right.solves());
+        assert_eq!(right.left, 1);
+    }
+}
@@ -0,0 +1,553 @@
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::fmt;
+
+use crate::collections::{Map, Set};
+use crate::{ParseWordError, Query, Word};
+
+#[cfg(feature = "embedded-dictionary")]
+mod embedded;
+
+/// The official Wordle answers, whitespace-separated, in the order the puzzles ran.
+pub const ANSWERS: &str = include_str!("../answers.txt");
+
+/// The [`ANSWERS`] of the puzzles before puzzle number `puzzle`, counting from 0, which the
+/// official game will not use again.
+pub fn used_answers(puzzle: usize) -> impl Iterator<Item = Word> {
+    ANSWERS
+        .split_whitespace()
+        .take(puzzle)
+        .map(|answer| answer.parse().expect("the official answers are words"))
+}
+
+/// The words a game allows, each with how common it is.
+///
+/// By default any allowed word may also be the answer. [`Dictionary::with_answers`] narrows
+/// the possible answers to a smaller list while still allowing every word as a guess.
+#[derive(Debug, Clone)]
+pub struct Dictionary {
+    words: Vec<(Word, usize)>,
+    /// Each word's position in `words`.
+    index: Map<Word, usize>,
+    /// Every word's count, in ascending order, for percentiles.
+    ascending: Vec<usize>,
+    /// The words that may be the answer, if narrower than `words`.
+    answers: Option<Vec<(Word, usize)>>,
+    /// Words left out because they are not [`Word::LEN`] letters long.
+    skipped: usize,
+}
+
+impl Dictionary {
+    /// Parse one word per line, each optionally followed by a space and its frequency.
+    ///
+    /// Words without a frequency count as 1, so plain word lists work too. Blank lines are
+    /// skipped, as are repeats of a word already seen. Words of the wrong length are left
+    /// out rather than rejected, so general word lists can be used as they are; see
+    /// [`Dictionary::skipped`].
+    pub fn parse(text: &str) -> Result<Self, ParseDictionaryError> {
+        let mut words = Vec::new();
+        let mut seen = Set::new();
+        let mut skipped = 0;
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let error = |kind| ParseDictionaryError { line: i + 1, kind };
+
+            let (word, count) = match line.split_once(char::is_whitespace) {
+                Some((word, count)) => {
+                    let count = count.trim().parse().map_err(|_| error(ErrorKind::Count))?;
+                    (word, count)
+                }
+                None => (line, 1),
+            };
+            let word: Word = match word.parse() {
+                Ok(word) => word,
+                Err(ParseWordError::Length(_)) => {
+                    skipped += 1;
+                    continue;
+                }
+                Err(e) => return Err(error(ErrorKind::Word(e))),
+            };
+            if seen.insert(word) {
+                words.push((word, count));
+            }
+        }
+        Ok(Self::from_words(words, skipped))
+    }
+
+    /// `words` must not repeat a word.
+    fn from_words(words: Vec<(Word, usize)>, skipped: usize) -> Self {
+        let index: Map<_, _> = words
+            .iter()
+            .enumerate()
+            .map(|(i, &(w, _))| (w, i))
+            .collect();
+        let mut ascending: Vec<_> = words.iter().map(|&(_, count)| count).collect();
+        ascending.sort_unstable();
+        Self {
+            words,
+            index,
+            ascending,
+            answers: None,
+            skipped,
+        }
+    }
+
+    /// Only consider `answers` as possible answers; everything else stays a valid guess.
+    ///
+    /// Answers that are not in the dictionary are ignored, since they could never be
+    /// guessed.
+    pub fn with_answers(mut self, answers: impl IntoIterator<Item = Word>) -> Self {
+        let answers: Set<Word> = answers.into_iter().collect();
+        self.answers = Some(
+            self.words
+                .iter()
+                .copied()
+                .filter(|(word, _)| answers.contains(word))
+                .collect(),
+        );
+        self
+    }
+
+    /// Never consider `words` as possible answers, so guessers do not suggest them, while
+    /// they stay valid guesses: past answers that will not come up again, say, or words
+    /// that should not be shown.
+    ///
+    /// This narrows the answers further if [`Dictionary::with_answers`] already has.
+    pub fn excluding(mut self, words: impl IntoIterator<Item = Word>) -> Self {
+        let words: Set<Word> = words.into_iter().collect();
+        let answers = self
+            .answers()
+            .iter()
+            .copied()
+            .filter(|(word, _)| !words.contains(word))
+            .collect();
+        self.answers = Some(answers);
+        self
+    }
+
+    /// Make `words` less likely answers by scaling their counts by `factor`, between 0 and 1,
+    /// while they stay possible and their frequencies stay as they were; a `factor` of 0 is
+    /// [`Dictionary::excluding`] them instead.
+    ///
+    /// How much less likely they become depends on the
+    /// [`Normalization`](crate::algorithms::Normalization): in proportion with the default.
+    pub fn down_weighting(mut self, words: impl IntoIterator<Item = Word>, factor: f64) -> Self {
+        if factor <= 0.0 {
+            return self.excluding(words);
+        }
+        let words: Set<Word> = words.into_iter().collect();
+        let answers = self
+            .answers()
+            .iter()
+            .map(|&(word, count)| match words.contains(&word) {
+                // a count of 0 would leave no weight at all with the default normalization
+                true => (word, (libm::round(count as f64 * factor) as usize).max(1)),
+                false => (word, count),
+            })
+            .collect();
+        self.answers = Some(answers);
+        self
+    }
+
+    /// Combine several dictionaries into one with every word from any of them, sorted.
+    ///
+    /// A word in more than one dictionary gets its counts combined as `combine` says.
+    /// Restrictions on possible answers are not carried over.
+    pub fn merge<'a>(dictionaries: impl IntoIterator<Item = &'a Self>, combine: Combine) -> Self {
+        let mut counts: Map<Word, usize> = Map::new();
+        let mut skipped = 0;
+        for dictionary in dictionaries {
+            for &(word, count) in &dictionary.words {
+                counts
+                    .entry(word)
+                    .and_modify(|total| *total = combine.apply(*total, count))
+                    .or_insert(count);
+            }
+            skipped += dictionary.skipped;
+        }
+        let mut words: Vec<_> = counts.into_iter().collect();
+        words.sort_unstable();
+        Self::from_words(words, skipped)
+    }
+
+    /// Read and [parse](Dictionary::parse) a dictionary file.
+    #[cfg(feature = "std")]
+    pub fn load(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Self::parse(&text).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// Identifies the words, how often each occurs, and which may be the answer, so that
+    /// results weighted by how likely each answer is are not reused for a dictionary with the
+    /// same words but other counts. [`Matrix::fingerprint`](crate::Matrix::fingerprint)
+    /// covers the words alone.
+    pub fn fingerprint(&self) -> u64 {
+        let mut bytes = Vec::new();
+        for list in [&self.words[..], self.answers()] {
+            bytes.extend((list.len() as u64).to_le_bytes());
+            for &(word, count) in list {
+                bytes.extend(word.as_bytes());
+                bytes.extend((count as u64).to_le_bytes());
+            }
+        }
+        crate::matrix::fnv(bytes)
+    }
+
+    /// Every word with its frequency, in file order.
+    pub fn words(&self) -> &[(Word, usize)] {
+        &self.words
+    }
+
+    /// The words that may be the answer, with their frequencies, in file order.
+    pub fn answers(&self) -> &[(Word, usize)] {
+        self.answers.as_deref().unwrap_or(&self.words)
+    }
+
+    /// Start a search for possible answers by which letters they have where.
+    pub fn query(&self) -> Query<'_> {
+        Query::new(self)
+    }
+
+    /// How many words were left out for not being [`Word::LEN`] letters long.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    pub fn contains(&self, word: Word) -> bool {
+        self.index.contains_key(&word)
+    }
+
+    /// Up to `limit` dictionary words that differ from `word` in one or two letters, for
+    /// suggesting what a mistyped word was meant to be.
+    ///
+    /// Words one letter off come first, and the more common first among words as far off.
+    /// `word` itself is left out.
+    pub fn closest(&self, word: Word, limit: usize) -> Vec<Word> {
+        let mut close: Vec<_> = self
+            .words
+            .iter()
+            .filter_map(|&(other, count)| {
+                let distance = word
+                    .as_bytes()
+                    .iter()
+                    .zip(other.as_bytes())
+                    .filter(|(a, b)| a != b)
+                    .count();
+                (1..=2)
+                    .contains(&distance)
+                    .then_some((distance, Reverse(count), other))
+            })
+            .collect();
+        close.sort_unstable();
+        close.truncate(limit);
+        close.into_iter().map(|(_, _, word)| word).collect()
+    }
+
+    /// Where `word` is in [`Dictionary::words`], or `None` if it is not in the dictionary.
+    ///
+    /// Algorithms can keep these indices instead of words and only look the words up when
+    /// they have to hand one back.
+    pub fn index_of(&self, word: Word) -> Option<usize> {
+        self.index.get(&word).copied()
+    }
+
+    /// The word at `index` in [`Dictionary::words`].
+    ///
+    /// Panics if `index` is out of range.
+    pub fn word(&self, index: usize) -> Word {
+        self.words[index].0
+    }
+
+    /// How often `word` occurs, or `None` if it is not in the dictionary.
+    pub fn frequency(&self, word: Word) -> Option<usize> {
+        Some(self.words[self.index_of(word)?].1)
+    }
+
+    /// The percentage of dictionary words that are at most as common as `word`, from just
+    /// above 0 for the rarest word to 100 for the most common, or `None` if it is not in
+    /// the dictionary.
+    pub fn percentile(&self, word: Word) -> Option<f64> {
+        let count = self.frequency(word)?;
+        let at_most = self.ascending.partition_point(|&c| c <= count);
+        Some(100.0 * at_most as f64 / self.len() as f64)
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+/// Writes the `word count` lines [`Dictionary::parse`] reads, in order.
+impl fmt::Display for Dictionary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (word, count) in &self.words {
+            writeln!(f, "{word} {count}")?;
+        }
+        Ok(())
+    }
+}
+
+/// How [`Dictionary::merge`] combines the counts of a word found in several dictionaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Combine {
+    /// Add them up, as when the lists were counted over different texts.
+    #[default]
+    Sum,
+    /// Keep the largest, as when the lists overlap.
+    Max,
+}
+
+impl Combine {
+    fn apply(self, a: usize, b: usize) -> usize {
+        match self {
+            Self::Sum => a.saturating_add(b),
+            Self::Max => a.max(b),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDictionaryError {
+    /// The 1-based line the error is on.
+    pub line: usize,
+    pub kind: ErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    Word(ParseWordError),
+    /// The frequency after the word is not a number.
+    Count,
+}
+
+impl fmt::Display for ParseDictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            ErrorKind::Word(e) => write!(f, "{e}"),
+            ErrorKind::Count => f.write_str("frequency is not a number"),
+        }
+    }
+}
+
+impl core::error::Error for ParseDictionaryError {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use super::{Combine, Dictionary, ErrorKind, ParseDictionaryError};
+    use crate::{ParseWordError, Word};
+
+    #[test]
+    fn with_frequencies() {
+        let dictionary = Dictionary::parse("crane 10\nslate 20\n").unwrap();
+        assert_eq!(dictionary.words()[1].0, "slate");
+        assert_eq!(dictionary.words()[1].1, 20);
+    }
+
+    #[test]
+    fn plain_list() {
+        let dictionary = Dictionary::parse("crane\n\nslate\ncrane\n").unwrap();
+        assert_eq!(dictionary.len(), 2);
+        assert!(dictionary.words().iter().all(|&(_, count)| count == 1));
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        assert_eq!(
+            Dictionary::parse("crane 1\nslate x").unwrap_err(),
+            ParseDictionaryError {
+                line: 2,
+                kind: ErrorKind::Count
+            }
+        );
+        assert_eq!(
+            Dictionary::parse("crane\ncr€ne").unwrap_err(),
+            ParseDictionaryError {
+                line: 2,
+                kind: ErrorKind::Word(ParseWordError::Unsupported)
+            }
+        );
+    }
+
+    #[test]
+    fn frequency_and_percentile() {
+        let dictionary = Dictionary::parse("crane 10\nslate 40\ntrace 10\nreact 5\n").unwrap();
+        assert_eq!(dictionary.frequency(Word::new("slate")), Some(40));
+        assert_eq!(dictionary.frequency(Word::new("zzzzz")), None);
+        assert_eq!(dictionary.percentile(Word::new("slate")), Some(100.0));
+        assert_eq!(dictionary.percentile(Word::new("crane")), Some(75.0));
+        assert_eq!(dictionary.percentile(Word::new("react")), Some(25.0));
+        assert_eq!(dictionary.percentile(Word::new("zzzzz")), None);
+    }
+
+    #[test]
+    fn merge() {
+        let a = Dictionary::parse("slate 3\ncrane 5\n").unwrap();
+        let b = Dictionary::parse("trace 1\ncrane 2\ncranes 1\n").unwrap();
+
+        let summed = Dictionary::merge([&a, &b], Combine::Sum);
+        assert_eq!(summed.to_string(), "crane 7\nslate 3\ntrace 1\n");
+        assert_eq!(summed.skipped(), 1);
+
+        let maxed = Dictionary::merge([&a, &b], Combine::Max);
+        assert_eq!(maxed.frequency(Word::new("crane")), Some(5));
+    }
+
+    #[test]
+    fn indices() {
+        let dictionary = Dictionary::parse(
+            "crane
+slate
+trace
+",
+        )
+        .unwrap();
+        let slate = dictionary.index_of(Word::new("slate")).unwrap();
+        assert_eq!(slate, 1);
+        assert_eq!(dictionary.word(slate), "slate");
+        assert_eq!(dictionary.index_of(Word::new("react")), None);
+    }
+
+    #[test]
+    fn closest() {
+        let dictionary =
+            Dictionary::parse("crane 10\ncrate 30\ngrate 20\nslate 40\ntrace 50\n").unwrap();
+        // grate and trace are three letters off, and slate four
+        assert_eq!(
+            dictionary.closest(Word::new("crans"), 5),
+            ["crane", "crate"]
+        );
+        // one letter off beats two, however common
+        assert_eq!(
+            dictionary.closest(Word::new("grane"), 2),
+            ["grate", "crane"]
+        );
+        assert_eq!(
+            dictionary.closest(Word::new("crate"), 5),
+            ["grate", "crane", "trace", "slate"]
+        );
+        assert!(dictionary.closest(Word::new("zzzzz"), 5).is_empty());
+    }
+
+    #[test]
+    fn round_trips_through_text() {
+        let dictionary = Dictionary::parse("crane 10\nslate\n").unwrap();
+        let reparsed = Dictionary::parse(&dictionary.to_string()).unwrap();
+        assert_eq!(reparsed.words(), dictionary.words());
+    }
+
+    #[test]
+    fn other_lengths_are_skipped() {
+        let dictionary = Dictionary::parse("crane 3\ncranes 2\ncat\nslate\n").unwrap();
+        assert_eq!(dictionary.len(), 2);
+        assert_eq!(dictionary.skipped(), 2);
+        assert_eq!(dictionary.words()[1].0, "slate");
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-dictionary")]
+    fn embedded() {
+        let dictionary = Dictionary::embedded();
+        assert_eq!(dictionary.len(), 12947);
+        assert!(dictionary.words().is_sorted_by_key(|&(word, _)| word));
+
+        let text = include_str!("../dictionary.txt");
+        assert_eq!(dictionary.words(), Dictionary::parse(text).unwrap().words());
+        assert_eq!(dictionary.answers().len(), dictionary.len());
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-dictionary")]
+    fn official_answers() {
+        let dictionary = Dictionary::official();
+        assert_eq!(dictionary.len(), 12947);
+        assert_eq!(dictionary.answers().len(), 2309);
+        assert!(dictionary.contains(Word::new("aahed")));
+        assert!(!dictionary
+            .answers()
+            .iter()
+            .any(|&(word, _)| word == "aahed"));
+    }
+
+    #[test]
+    fn answers_outside_the_dictionary_are_dropped() {
+        let dictionary = Dictionary::parse("crane\nslate\ntrace\n")
+            .unwrap()
+            .with_answers([Word::new("slate"), Word::new("zzzzz")]);
+        assert_eq!(dictionary.answers(), [(Word::new("slate"), 1)]);
+        assert_eq!(dictionary.len(), 3);
+    }
+
+    #[test]
+    fn excluded_words_stay_guessable() {
+        let dictionary = Dictionary::parse("crane\nslate\ntrace\n")
+            .unwrap()
+            .excluding([Word::new("crane")]);
+        assert_eq!(
+            dictionary.answers(),
+            [(Word::new("slate"), 1), (Word::new("trace"), 1)]
+        );
+        assert!(dictionary.contains(Word::new("crane")));
+
+        let narrowed = dictionary
+            .with_answers([Word::new("crane"), Word::new("trace")])
+            .excluding([Word::new("crane")]);
+        assert_eq!(narrowed.answers(), [(Word::new("trace"), 1)]);
+    }
+
+    #[test]
+    fn down_weighting_scales_answer_counts() {
+        let dictionary = Dictionary::parse("crane 100\nslate 100\ntrace 1\n")
+            .unwrap()
+            .down_weighting([Word::new("crane"), Word::new("trace")], 0.25);
+        assert_eq!(
+            dictionary.answers(),
+            [
+                (Word::new("crane"), 25),
+                (Word::new("slate"), 100),
+                (Word::new("trace"), 1)
+            ]
+        );
+        assert_eq!(dictionary.frequency(Word::new("crane")), Some(100));
+
+        let excluded = dictionary.down_weighting([Word::new("slate")], 0.0);
+        assert_eq!(excluded.answers().len(), 2);
+    }
+
+    #[test]
+    fn fingerprint_covers_counts() {
+        let dictionary = Dictionary::parse("crane 100\nslate 100\n").unwrap();
+        let recounted = Dictionary::parse("crane 100\nslate 50\n").unwrap();
+        let down_weighted = dictionary.clone().down_weighting([Word::new("crane")], 0.5);
+        assert_eq!(
+            dictionary.fingerprint(),
+            Dictionary::parse("crane 100\nslate 100\n")
+                .unwrap()
+                .fingerprint()
+        );
+        assert_ne!(dictionary.fingerprint(), recounted.fingerprint());
+        assert_ne!(dictionary.fingerprint(), down_weighted.fingerprint());
+        // the words alone are the same
+        assert_eq!(
+            crate::Matrix::fingerprint(&dictionary),
+            crate::Matrix::fingerprint(&recounted)
+        );
+    }
+
+    #[test]
+    fn used_answers_come_in_order() {
+        let used: Vec<Word> = super::used_answers(3).collect();
+        assert_eq!(used, ["cigar", "rebut", "sissy"]);
+        assert_eq!(super::used_answers(0).count(), 0);
+    }
+}
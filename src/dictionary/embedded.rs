@@ -0,0 +1,89 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use super::{Dictionary, ANSWERS};
+use crate::sync::Lazy;
+use crate::Word;
+
+/// `dictionary.txt`, parsed and sorted at build time so startup does no text parsing; see
+/// `build.rs`. With the `compressed-dictionary` feature the words are delta-encoded instead
+/// of stored as fixed-size records.
+const EMBEDDED: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/dictionary.bin"));
+
+/// Bytes per record in [`EMBEDDED`]: the word's five bytes, then its count as a
+/// little-endian `u64`.
+#[cfg(not(feature = "compressed-dictionary"))]
+const RECORD: usize = 13;
+
+/// The built-in English dictionary, decoded once on first use and shared by everyone.
+static SHARED: Lazy<Arc<Dictionary>> = Lazy::new(|| {
+    #[cfg(not(feature = "compressed-dictionary"))]
+    let dictionary = Dictionary::from_records(EMBEDDED);
+    #[cfg(feature = "compressed-dictionary")]
+    let dictionary = Dictionary::from_compressed(EMBEDDED);
+    #[cfg(feature = "tracing")]
+    tracing::debug!(words = dictionary.len(), "decoded the embedded dictionary");
+    Arc::new(dictionary)
+});
+
+/// [`SHARED`] with only the official answers as possible answers.
+static OFFICIAL: Lazy<Arc<Dictionary>> = Lazy::new(|| {
+    let answers = ANSWERS.split_whitespace().map(Word::new);
+    Arc::new(SHARED.as_ref().clone().with_answers(answers))
+});
+
+impl Dictionary {
+    /// The dictionary compiled into the crate.
+    pub fn embedded() -> Arc<Self> {
+        Arc::clone(&SHARED)
+    }
+
+    /// The dictionary compiled into the crate, with only the official Wordle [`ANSWERS`] as
+    /// possible answers.
+    pub fn official() -> Arc<Self> {
+        Arc::clone(&OFFICIAL)
+    }
+
+    #[cfg(not(feature = "compressed-dictionary"))]
+    fn from_records(records: &[u8]) -> Self {
+        let words: Vec<_> = records
+            .chunks_exact(RECORD)
+            .map(|record| {
+                let (word, count) = record.split_at(5);
+                let word = Word::from_latin1(word.try_into().expect("records start with a word"));
+                let count = u64::from_le_bytes(count.try_into().expect("records end with a u64"));
+                (word, usize::try_from(count).unwrap_or(usize::MAX))
+            })
+            .collect();
+        Self::from_words(words, 0)
+    }
+
+    /// Undo the delta encoding `build.rs` applies: each word is the number of letters it
+    /// shares with the previous word, the remaining letters, and an LEB128 count.
+    #[cfg(feature = "compressed-dictionary")]
+    fn from_compressed(mut blob: &[u8]) -> Self {
+        let mut words = Vec::new();
+        let mut word = [0; 5];
+        while let Some((&shared, rest)) = blob.split_first() {
+            let (letters, rest) = rest.split_at(5 - usize::from(shared));
+            word[usize::from(shared)..].copy_from_slice(letters);
+
+            let mut count = 0u64;
+            let mut shift = 0;
+            let mut rest = rest.iter();
+            for &byte in rest.by_ref() {
+                count |= u64::from(byte & 0x7f) << shift;
+                shift += 7;
+                if byte & 0x80 == 0 {
+                    break;
+                }
+            }
+            blob = rest.as_slice();
+            words.push((
+                Word::from_latin1(word),
+                usize::try_from(count).unwrap_or(usize::MAX),
+            ));
+        }
+        Self::from_words(words, 0)
+    }
+}
@@ -0,0 +1,467 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::algorithms::Normalization;
+use crate::collections::Map;
+use crate::{Correctness, Dictionary, Guess, Guesser, Word};
+
+/// The best play from every endgame reached, worked out exactly: for each set of at most
+/// [`max`](Tablebase::max) answers still possible, the guess that solves it in the fewest
+/// guesses on average, with the answers as likely as their prior weights make them, as
+/// [`Naive`](crate::algorithms::Naive) ranks them.
+///
+/// Finding those guesses searches every allowed word, so the table is filled ahead of time
+/// with [`fill`](Tablebase::fill), which plays a guesser against every answer and solves
+/// each endgame it reaches, along with every endgame the best play leads on to. With `std`
+/// it can be [saved](Tablebase::save) and [loaded](Tablebase::load) so that is only done
+/// once. An [`Endgame`] then plays from the table.
+///
+/// ```
+/// use roget::{Dictionary, Tablebase, Word};
+///
+/// let dictionary = Dictionary::parse("crane\ntrace\nslate\n").unwrap();
+/// let mut table = Tablebase::new(&dictionary, 3);
+/// let mut answers = [Word::new("crane"), Word::new("trace"), Word::new("slate")];
+/// answers.sort();
+/// let best = table.solve(&answers).unwrap();
+/// // with the answers equally likely, crane tells the other two apart, so one more guess
+/// // always does it
+/// assert_eq!(best.guess, "crane");
+/// assert!((best.expected - 5.0 / 3.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tablebase {
+    /// Every allowed guess, in dictionary order.
+    guesses: Vec<Word>,
+    /// Every possible answer, sorted.
+    answers: Vec<Word>,
+    /// The prior weight of each answer.
+    weights: Map<Word, f64>,
+    max: usize,
+    /// Keyed by the sorted answers still possible.
+    table: Map<Vec<Word>, Move>,
+}
+
+/// The best guess for an endgame, and how many guesses it takes on average from there,
+/// counting itself.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Move {
+    pub guess: Word,
+    pub expected: f64,
+}
+
+/// How much better one play must be to count as better, so rounding does not decide ties.
+const EPSILON: f64 = 1e-9;
+
+impl Tablebase {
+    /// An empty table for endgames of at most `max` of the answers in `dictionary`.
+    pub fn new(dictionary: &Dictionary, max: usize) -> Self {
+        let priors = Normalization::default().weights(dictionary.answers());
+        let mut answers: Vec<Word> = priors.iter().map(|&(w, _)| w).collect();
+        answers.sort_unstable();
+        Self {
+            guesses: dictionary.words().iter().map(|&(w, _)| w).collect(),
+            answers,
+            // without counts every answer weighs nothing, so they are all equally likely
+            weights: priors
+                .into_iter()
+                .map(|(w, weight)| (w, weight.max(f64::MIN_POSITIVE)))
+                .collect(),
+            max,
+            table: Map::default(),
+        }
+    }
+
+    fn weight(&self, word: Word) -> f64 {
+        self.weights[&word]
+    }
+
+    /// The most answers an endgame in the table has left.
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// How many endgames have been solved.
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// The best play when `candidates`, sorted, are the answers still possible, if that
+    /// endgame has been solved.
+    pub fn get(&self, candidates: &[Word]) -> Option<Move> {
+        self.table.get(candidates).copied()
+    }
+
+    /// The best play when `candidates`, sorted, are the answers still possible, solving
+    /// that endgame and every one it leads to first if they are not in the table yet; `None`
+    /// if there are none, or more than [`max`](Tablebase::max).
+    pub fn solve(&mut self, candidates: &[Word]) -> Option<Move> {
+        if candidates.is_empty() || candidates.len() > self.max {
+            return None;
+        }
+        if let Some(best) = self.get(candidates) {
+            return Some(best);
+        }
+
+        let mut best = Move {
+            guess: candidates[0],
+            expected: f64::INFINITY,
+        };
+        if let [_] = candidates {
+            best.expected = 1.0;
+            self.table.insert(candidates.to_vec(), best);
+            return Some(best);
+        }
+        // the guesses that could be the answer first, likeliest first, since those are
+        // most often best
+        let mut members = candidates.to_vec();
+        members.sort_by(|&a, &b| self.weight(b).total_cmp(&self.weight(a)).then(a.cmp(&b)));
+        for guess in members {
+            self.consider(candidates, guess, &mut best);
+        }
+        // any other guess takes at least two, one to tell the answers apart and one to
+        // play it, so it can only do better when no candidate gets down to that
+        if best.expected > 2.0 + EPSILON {
+            for i in 0..self.guesses.len() {
+                let guess = self.guesses[i];
+                if candidates.binary_search(&guess).is_err() {
+                    self.consider(candidates, guess, &mut best);
+                }
+            }
+        }
+
+        self.table.insert(candidates.to_vec(), best);
+        Some(best)
+    }
+
+    /// Play `guess` against `candidates` and keep it in `best` if it does better.
+    fn consider(&mut self, candidates: &[Word], guess: Word, best: &mut Move) {
+        let buckets = split(guess, candidates);
+        if buckets.len() == 1 && buckets[0][0] != guess {
+            // tells nothing apart
+            return;
+        }
+        let total: f64 = candidates.iter().map(|&w| self.weight(w)).sum();
+        // each bucket still to solve, with its weight and that of its likeliest answer
+        let left: Vec<(&[Word], f64, f64)> = buckets
+            .iter()
+            .filter(|bucket| bucket.len() != 1 || bucket[0] != guess)
+            .map(|bucket| {
+                let weights = bucket.iter().map(|&w| self.weight(w));
+                (
+                    bucket.as_slice(),
+                    weights.clone().sum(),
+                    weights.fold(0.0, f64::max),
+                )
+            })
+            .collect();
+        // a bucket takes at least one more guess, and two for all but its likeliest answer,
+        // as when the next guess is that answer and tells the rest apart
+        let bound: f64 = left
+            .iter()
+            .map(|&(_, sum, most)| (2.0 * sum - most) / total)
+            .sum();
+        if 1.0 + bound >= best.expected - EPSILON {
+            return;
+        }
+
+        let mut expected = 1.0;
+        for (bucket, sum, _) in left {
+            let next = self
+                .solve(bucket)
+                .expect("a guess that tells answers apart leaves fewer of them");
+            expected += sum / total * next.expected;
+            if expected >= best.expected - EPSILON {
+                return;
+            }
+        }
+        *best = Move { guess, expected };
+    }
+
+    /// Play a fresh guesser from `make_guesser` against every answer until at most
+    /// [`max`](Tablebase::max) are left, and solve the endgame it reaches.
+    pub fn fill<G: Guesser>(&mut self, mut make_guesser: impl FnMut() -> G) {
+        let answers = self.answers.clone();
+        for &answer in &answers {
+            let mut guesser = make_guesser();
+            let mut history = Vec::new();
+            let mut remaining = answers.clone();
+            // as long as games may go, so a guesser that never finds the answer still stops
+            while remaining.len() > self.max && history.len() < 32 {
+                let word = guesser.guess(&history);
+                let guess = Guess {
+                    word,
+                    mask: Correctness::compute(answer, word),
+                };
+                remaining.retain(|&w| guess.matches(w));
+                history.push(guess);
+            }
+            self.solve(&remaining);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(endgames = self.table.len(), "filled the tablebase");
+    }
+}
+
+/// `candidates` split up by the mask `guess` gets against each, each bucket still sorted.
+fn split(guess: Word, candidates: &[Word]) -> Vec<Vec<Word>> {
+    let mut masked: Vec<(u8, Word)> = candidates
+        .iter()
+        .map(|&answer| {
+            (
+                Correctness::pack(Correctness::compute(answer, guess)),
+                answer,
+            )
+        })
+        .collect();
+    masked.sort_unstable();
+    masked
+        .chunk_by(|a, b| a.0 == b.0)
+        .map(|bucket| bucket.iter().map(|&(_, word)| word).collect())
+        .collect()
+}
+
+/// Wraps a guesser so it plays the endgame perfectly: once no more answers are possible than
+/// the [`Tablebase`] covers, it plays the table's guesses instead, and the wrapped guesser is
+/// not asked again.
+///
+/// An endgame the table has not solved, as when it was filled with a different guesser, is
+/// solved on the spot into this guesser's own copy of the table.
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use roget::algorithms::Naive;
+/// use roget::{Dictionary, Endgame, Tablebase, Wordle};
+///
+/// let dictionary = Arc::new(Dictionary::parse("crane\ntrace\nslate\nplate\n").unwrap());
+/// let mut table = Tablebase::new(&dictionary, 4);
+/// table.fill(|| Naive::with_dictionary(&dictionary));
+/// let table = Arc::new(table);
+///
+/// let wordle = Wordle::with_dictionary(Arc::clone(&dictionary));
+/// let guesser = Endgame::new(Naive::with_dictionary(&dictionary), table);
+/// assert!(wordle.play("plate", guesser).unwrap() <= 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Endgame<G> {
+    guesser: G,
+    table: Arc<Tablebase>,
+    /// The answers still possible after the guesses seen so far, sorted.
+    remaining: Vec<Word>,
+    seen: usize,
+}
+
+impl<G> Endgame<G> {
+    /// Play `guesser` until `table` takes over.
+    pub fn new(guesser: G, table: Arc<Tablebase>) -> Self {
+        Self {
+            guesser,
+            remaining: table.answers.clone(),
+            table,
+            seen: 0,
+        }
+    }
+
+    /// The table's play for the answers still possible after `history`, if it covers them.
+    fn endgame(&mut self, history: &[Guess]) -> Option<Word> {
+        for guess in history.get(self.seen..).unwrap_or_default() {
+            self.remaining.retain(|&w| guess.matches(w));
+        }
+        self.seen = history.len();
+        if self.remaining.is_empty() || self.remaining.len() > self.table.max {
+            return None;
+        }
+        let best = match self.table.get(&self.remaining) {
+            Some(best) => best,
+            None => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    left = self.remaining.len(),
+                    "solving an endgame not in the table"
+                );
+                Arc::make_mut(&mut self.table).solve(&self.remaining)?
+            }
+        };
+        Some(best.guess)
+    }
+}
+
+impl<G: Guesser> Guesser for Endgame<G> {
+    fn guess(&mut self, history: &[Guess]) -> Word {
+        match self.endgame(history) {
+            Some(guess) => guess,
+            None => self.guesser.guess(history),
+        }
+    }
+
+    /// Only the table's guess in the endgame, and the wrapped guesser's ranking before it.
+    fn ranked(&mut self, history: &[Guess]) -> Vec<Word> {
+        match self.endgame(history) {
+            Some(guess) => alloc::vec![guess],
+            None => self.guesser.ranked(history),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod file {
+    use std::io::{self, BufRead, Write};
+    use std::path::Path;
+
+    use super::{Move, Tablebase};
+    use crate::{Dictionary, Word};
+
+    const MAGIC: &str = "ROGETTB1";
+
+    impl Tablebase {
+        /// Write the table to `path` as text: a header naming the dictionary it was filled
+        /// for, then one endgame a line, as the guess, its expected guesses, and the answers.
+        pub fn save(&self, path: impl AsRef<Path>, dictionary: &Dictionary) -> io::Result<()> {
+            let mut out = io::BufWriter::new(std::fs::File::create(path)?);
+            let fingerprint = dictionary.fingerprint();
+            writeln!(out, "{MAGIC} {fingerprint:016x} {}", self.max)?;
+            for (candidates, best) in &self.table {
+                write!(out, "{} {}", best.guess, best.expected)?;
+                for word in candidates {
+                    write!(out, " {word}")?;
+                }
+                writeln!(out)?;
+            }
+            out.flush()
+        }
+
+        /// Read a table [saved](Tablebase::save) for `dictionary`.
+        ///
+        /// Fails with [`io::ErrorKind::InvalidData`] if the file is not a table or was saved
+        /// for a different dictionary, in which case it should be filled again.
+        pub fn load(path: impl AsRef<Path>, dictionary: &Dictionary) -> io::Result<Self> {
+            let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message);
+            let mut lines = io::BufReader::new(std::fs::File::open(path)?).lines();
+            let header = lines.next().transpose()?.unwrap_or_default();
+            let mut fields = header.split_whitespace();
+            if fields.next() != Some(MAGIC) {
+                return Err(invalid("not a roget tablebase"));
+            }
+            let fingerprint = format!("{:016x}", dictionary.fingerprint());
+            if fields.next() != Some(fingerprint.as_str()) {
+                return Err(invalid("tablebase is for a different dictionary"));
+            }
+            let max = fields
+                .next()
+                .and_then(|max| max.parse().ok())
+                .ok_or_else(|| invalid("tablebase has no size"))?;
+
+            let mut table = Self::new(dictionary, max);
+            for line in lines {
+                let line = line?;
+                let mut fields = line.split_whitespace();
+                let mut next = || fields.next().ok_or_else(|| invalid("truncated endgame"));
+                let guess: Word = next()?.parse().map_err(|_| invalid("bad guess"))?;
+                let expected = next()?
+                    .parse()
+                    .map_err(|_| invalid("bad expected guesses"))?;
+                let candidates = fields
+                    .map(|word| word.parse().map_err(|_| invalid("bad answer")))
+                    .collect::<io::Result<Vec<Word>>>()?;
+                table.table.insert(candidates, Move { guess, expected });
+            }
+            Ok(table)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+
+    use super::{Endgame, Tablebase};
+    use crate::algorithms::Naive;
+    use crate::{Dictionary, Word, Wordle};
+
+    fn sorted(words: &[&str]) -> Vec<Word> {
+        let mut words: Vec<Word> = words.iter().map(|&w| Word::new(w)).collect();
+        words.sort();
+        words
+    }
+
+    #[test]
+    fn tells_apart_with_a_word_that_cannot_win() {
+        // no candidate tells more than one of the others apart, but fumet tells them all
+        let dictionary = Dictionary::parse("cakes\nfakes\nmakes\ntakes\nfumet\n")
+            .unwrap()
+            .with_answers(sorted(&["cakes", "fakes", "makes", "takes"]));
+        let mut table = Tablebase::new(&dictionary, 4);
+        let best = table
+            .solve(&sorted(&["cakes", "fakes", "makes", "takes"]))
+            .unwrap();
+        assert_eq!(best.guess, "fumet");
+        assert!((best.expected - 2.0).abs() < 1e-9);
+        // with three left, playing one of them does as well, and could win
+        let best = table.solve(&sorted(&["fakes", "makes", "takes"])).unwrap();
+        assert_eq!(best.guess, "fakes");
+        assert!((best.expected - 2.0).abs() < 1e-9);
+        assert_eq!(
+            table.solve(&sorted(&["cakes", "fakes"])).unwrap().expected,
+            1.5
+        );
+        assert!(table
+            .solve(&sorted(&["cakes", "fakes", "makes", "takes", "fumet"]))
+            .is_none());
+    }
+
+    #[test]
+    fn plays_the_endgame() {
+        let answers = ["bakes", "cakes", "fakes", "makes", "takes"];
+        let dictionary = Arc::new(
+            Dictionary::parse(&[answers.as_slice(), &["fumet"]].concat().join("\n"))
+                .unwrap()
+                .with_answers(sorted(&answers)),
+        );
+        let mut table = Tablebase::new(&dictionary, 4);
+        table.fill(|| Naive::with_dictionary(&dictionary));
+        assert!(!table.is_empty());
+
+        let wordle = Wordle::with_dictionary(Arc::clone(&dictionary));
+        let table = Arc::new(table);
+        let naive = answers.map(|answer| {
+            wordle
+                .play(answer, Naive::with_dictionary(&dictionary))
+                .unwrap()
+        });
+        let endgame = answers.map(|answer| {
+            let guesser = Endgame::new(Naive::with_dictionary(&dictionary), Arc::clone(&table));
+            wordle.play(answer, guesser).unwrap()
+        });
+        // after bakes, naive tries the look-alikes one by one, where fumet tells them apart
+        assert_eq!(naive, [1, 2, 3, 4, 5]);
+        assert_eq!(endgame, [1, 3, 3, 3, 3]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn save_and_load() {
+        let dictionary = Dictionary::parse("crane\ntrace\nslate\nplate\n").unwrap();
+        let mut table = Tablebase::new(&dictionary, 4);
+        table.fill(|| Naive::with_dictionary(&dictionary));
+        let path = std::env::temp_dir().join(format!("roget-tablebase-{}", std::process::id()));
+        table.save(&path, &dictionary).unwrap();
+        let loaded = Tablebase::load(&path, &dictionary).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.len(), table.len());
+        assert_eq!(loaded.max(), 4);
+        let all = sorted(&["crane", "trace", "slate", "plate"]);
+        assert_eq!(loaded.get(&all), table.get(&all));
+
+        let other = Dictionary::parse("crane\ntrace\n").unwrap();
+        let path = std::env::temp_dir().join(format!("roget-tablebase2-{}", std::process::id()));
+        table.save(&path, &dictionary).unwrap();
+        assert!(Tablebase::load(&path, &other).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
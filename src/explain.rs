@@ -0,0 +1,282 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Correctness, Guess, Word};
+
+/// Why a guesser suggests a word, in terms a person learning the game can follow: what the
+/// word tests, how the answers still possible would split over the colors it could get,
+/// and why the words ranked after it lost out.
+///
+/// It is built from a guesser's [`ranked`](crate::Guesser::ranked) words, the first being
+/// the suggestion, and the answers still possible, each taken as equally likely. Shown with
+/// `{}` it reads as a few lines of plain text.
+///
+/// ```
+/// use roget::{Explanation, Word};
+///
+/// let remaining = [Word::new("crane"), Word::new("trace"), Word::new("slate")];
+/// let ranked = [Word::new("trace"), Word::new("lousy")];
+/// let explanation = Explanation::new(&[], &remaining, &ranked).unwrap();
+/// assert!(explanation.could_win);
+/// // trace tells all three apart, lousy cannot tell crane from trace
+/// assert_eq!(explanation.expected_left(), 1.0);
+/// assert_eq!(explanation.rejected[0].expected_left, 5.0 / 3.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    pub guess: Word,
+    /// How many answers were still possible before the guess.
+    pub candidates: usize,
+    /// Whether the guess could be the answer itself.
+    pub could_win: bool,
+    /// The letters of the guess that no earlier guess tried, in order.
+    pub new_letters: Vec<char>,
+    /// Each mask the guess could get, with how many answers it would leave, likeliest first.
+    pub outcomes: Vec<Outcome>,
+    /// The words ranked after the guess, and why each lost out.
+    pub rejected: Vec<Rejected>,
+}
+
+/// A mask a guess could get, and how many of the answers still possible would get it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    pub mask: [Correctness; 5],
+    pub left: usize,
+}
+
+/// A word ranked after the guess.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rejected {
+    pub word: Word,
+    /// How many answers it would leave on average.
+    pub expected_left: f64,
+    pub reason: Reason,
+}
+
+/// Why a word ranked after the guess lost out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    /// It would leave more answers on average.
+    WorseSplit,
+    /// It splits the answers as well, but cannot be the answer, while the guess can.
+    CannotWin,
+    /// It splits the answers as well or better, but the guesser ranks it lower, such as for
+    /// being a less likely answer.
+    RankedLower,
+}
+
+impl Explanation {
+    /// Explain the first of `ranked` after `history`, against the words ranked after it,
+    /// when `remaining` are the answers still possible; `None` if nothing is ranked.
+    pub fn new(history: &[Guess], remaining: &[Word], ranked: &[Word]) -> Option<Self> {
+        let (&guess, alternatives) = ranked.split_first()?;
+        let mut new_letters: Vec<char> = Vec::new();
+        for letter in guess.letters() {
+            let tried = history
+                .iter()
+                .any(|g| g.word.letters().any(|l| l == letter));
+            if !tried && !new_letters.contains(&letter) {
+                new_letters.push(letter);
+            }
+        }
+
+        let split = buckets(guess, remaining);
+        let mut outcomes: Vec<Outcome> = (0..=u8::MAX)
+            .zip(split)
+            .filter(|&(_, left)| left > 0)
+            .map(|(pattern, left)| Outcome {
+                mask: Correctness::unpack(pattern),
+                left,
+            })
+            .collect();
+        // the fewest left first among equally likely masks, so a win comes before the rest
+        outcomes.sort_by_key(|outcome| (core::cmp::Reverse(outcome.left), outcome.mask.map(rank)));
+
+        let could_win = remaining.contains(&guess);
+        let expected = expected_left(&split, remaining.len());
+        let rejected = alternatives
+            .iter()
+            .map(|&word| {
+                let expected_left = expected_left(&buckets(word, remaining), remaining.len());
+                let reason = if expected_left > expected + 1e-9 {
+                    Reason::WorseSplit
+                } else if could_win && !remaining.contains(&word) {
+                    Reason::CannotWin
+                } else {
+                    Reason::RankedLower
+                };
+                Rejected {
+                    word,
+                    expected_left,
+                    reason,
+                }
+            })
+            .collect();
+
+        Some(Self {
+            guess,
+            candidates: remaining.len(),
+            could_win,
+            new_letters,
+            outcomes,
+            rejected,
+        })
+    }
+
+    /// How many answers the guess would leave on average.
+    pub fn expected_left(&self) -> f64 {
+        let squares: usize = self.outcomes.iter().map(|o| o.left * o.left).sum();
+        squares as f64 / self.candidates.max(1) as f64
+    }
+}
+
+/// How many of `remaining` would get each packed mask from `guess`.
+fn buckets(guess: Word, remaining: &[Word]) -> [usize; 243] {
+    let mut buckets = [0; 243];
+    for &word in remaining {
+        buckets[usize::from(Correctness::pack(Correctness::compute(word, guess)))] += 1;
+    }
+    buckets
+}
+
+/// An answer in a bucket of `n` leaves `n`, so on average the sum of the squares over all.
+fn expected_left(buckets: &[usize], total: usize) -> f64 {
+    let squares: usize = buckets.iter().map(|&n| n * n).sum();
+    squares as f64 / total.max(1) as f64
+}
+
+/// Greens before yellows before grays, to order masks that are equally likely.
+fn rank(c: Correctness) -> u8 {
+    match c {
+        Correctness::Correct => 0,
+        Correctness::Misplaced => 1,
+        Correctness::Wrong => 2,
+    }
+}
+
+/// How many masks are shown before the rest are summed up.
+const SHOWN: usize = 5;
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.guess)?;
+        match self.new_letters.as_slice() {
+            [] => write!(f, "tries no new letters, only where known ones go")?,
+            letters => {
+                write!(f, "tests ")?;
+                for (i, letter) in letters.iter().enumerate() {
+                    match i {
+                        0 => {}
+                        i if i + 1 == letters.len() => write!(f, " and ")?,
+                        _ => write!(f, ", ")?,
+                    }
+                    write!(f, "{letter}")?;
+                }
+                write!(f, " for the first time")?;
+            }
+        }
+        match self.could_win {
+            true => writeln!(f, ", and could be the answer itself.")?,
+            false => writeln!(f, ", though it cannot be the answer.")?,
+        }
+
+        writeln!(
+            f,
+            "of the {} answers still possible, the likeliest colors would leave:",
+            self.candidates
+        )?;
+        for outcome in self.outcomes.iter().take(SHOWN) {
+            write!(f, "  ")?;
+            for c in outcome.mask {
+                write!(f, "{c}")?;
+            }
+            match outcome.mask == [Correctness::Correct; 5] {
+                true => writeln!(f, "  solved")?,
+                false => writeln!(f, "  {} left", outcome.left)?,
+            }
+        }
+        if let Some(rest) = self.outcomes.get(SHOWN..).filter(|rest| !rest.is_empty()) {
+            let left: usize = rest.iter().map(|o| o.left).sum();
+            writeln!(
+                f,
+                "  and {} other colors, {left} answers between them",
+                rest.len()
+            )?;
+        }
+        write!(f, "on average it leaves {:.1}", self.expected_left())?;
+
+        for rejected in &self.rejected {
+            writeln!(f)?;
+            let (word, left) = (rejected.word, rejected.expected_left);
+            match rejected.reason {
+                Reason::WorseSplit => write!(f, "not {word}: it would leave {left:.1}")?,
+                Reason::CannotWin => write!(
+                    f,
+                    "not {word}: it leaves {left:.1}, but cannot be the answer"
+                )?,
+                Reason::RankedLower => write!(
+                    f,
+                    "not {word}: it leaves {left:.1}, but is a less likely answer"
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::{Explanation, Reason};
+    use crate::{parse_mask, Correctness, Guess, Word};
+
+    fn words(words: &[&str]) -> alloc::vec::Vec<Word> {
+        words.iter().map(|&w| Word::new(w)).collect()
+    }
+
+    #[test]
+    fn splits_the_candidates() {
+        let remaining = words(&["crane", "trace", "slate", "plate", "grace"]);
+        let explanation = Explanation::new(&[], &remaining, &words(&["slate"])).unwrap();
+        let lefts: alloc::vec::Vec<usize> = explanation.outcomes.iter().map(|o| o.left).collect();
+        assert_eq!(lefts.iter().sum::<usize>(), remaining.len());
+        assert!(lefts.windows(2).all(|w| w[0] >= w[1]));
+        assert!(explanation
+            .outcomes
+            .iter()
+            .any(|o| o.mask == [Correctness::Correct; 5] && o.left == 1));
+        assert_eq!(explanation.new_letters, ['s', 'l', 'a', 't', 'e']);
+    }
+
+    #[test]
+    fn letters_already_tried() {
+        let history = [Guess {
+            word: Word::new("crane"),
+            mask: parse_mask("xxgxg").unwrap(),
+        }];
+        let remaining = words(&["slate", "plate", "state"]);
+        let explanation = Explanation::new(&history, &remaining, &words(&["plate"])).unwrap();
+        assert_eq!(explanation.new_letters, ['p', 'l', 't']);
+        assert!(explanation
+            .to_string()
+            .starts_with("plate tests p, l and t"));
+    }
+
+    #[test]
+    fn reasons() {
+        let remaining = words(&["crane", "trace", "slate"]);
+        let ranked = words(&["trace", "lousy", "crate", "crane"]);
+        let explanation = Explanation::new(&[], &remaining, &ranked).unwrap();
+        let reasons: alloc::vec::Vec<Reason> =
+            explanation.rejected.iter().map(|r| r.reason).collect();
+        // lousy cannot tell crane from trace; crate tells them all apart but cannot win
+        assert_eq!(
+            reasons,
+            [Reason::WorseSplit, Reason::CannotWin, Reason::RankedLower]
+        );
+        // two lines on the guess, one per mask, the average, and one per word passed over
+        assert_eq!(explanation.to_string().lines().count(), 2 + 3 + 1 + 3);
+        assert!(Explanation::new(&[], &remaining, &[]).is_none());
+    }
+}
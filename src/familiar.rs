@@ -0,0 +1,124 @@
+use alloc::vec::Vec;
+
+use crate::collections::Set;
+use crate::{Dictionary, Guess, Guesser, Word};
+
+/// Wraps a guesser so it only suggests words people know, since a word nobody has heard of
+/// is no use to someone playing by hand, however much it would tell them.
+///
+/// A word's obscurity is the percentage of the dictionary that is more common than it: 0
+/// for the most common word, up to nearly 100 for the rarest. The wrapped guesser plays the
+/// best word in its [ranking](Guesser::ranked) no more obscure than the limit, or its own
+/// first choice if every word it ranked is.
+///
+/// ```
+/// use roget::{Dictionary, Familiar, Guesser, Word};
+/// # use roget::Guess;
+/// # struct Obscure;
+/// # impl Guesser for Obscure {
+/// #     fn guess(&mut self, _: &[Guess]) -> Word { Word::new("aahed") }
+/// #     fn ranked(&mut self, _: &[Guess]) -> Vec<Word> {
+/// #         vec![Word::new("aahed"), Word::new("crane")]
+/// #     }
+/// # }
+///
+/// let dictionary = Dictionary::parse("which 100\ncrane 50\naahed 1\n").unwrap();
+/// let mut guesser = Familiar::new(Obscure, &dictionary, 50.0);
+/// assert_eq!(guesser.guess(&[]), "crane");
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Familiar<G> {
+    guesser: G,
+    /// Every word no more obscure than the limit.
+    familiar: Set<Word>,
+}
+
+impl<G> Familiar<G> {
+    /// Only suggest words of `dictionary` with at most `max_obscurity` percent of it more
+    /// common than them; 100 allows every word.
+    pub fn new(guesser: G, dictionary: &Dictionary, max_obscurity: f64) -> Self {
+        let familiar = dictionary
+            .words()
+            .iter()
+            .map(|&(word, _)| word)
+            .filter(|&word| {
+                let percentile = dictionary
+                    .percentile(word)
+                    .expect("word is in the dictionary");
+                100.0 - percentile <= max_obscurity
+            })
+            .collect();
+        Self { guesser, familiar }
+    }
+}
+
+impl<G: Guesser> Guesser for Familiar<G> {
+    fn guess(&mut self, history: &[Guess]) -> Word {
+        self.ranked(history)[0]
+    }
+
+    /// The familiar words of the wrapped guesser's ranking, in its order, or only its first
+    /// choice if there are none.
+    fn ranked(&mut self, history: &[Guess]) -> Vec<Word> {
+        let ranked = self.guesser.ranked(history);
+        let familiar: Vec<Word> = ranked
+            .iter()
+            .copied()
+            .filter(|word| self.familiar.contains(word))
+            .collect();
+        match familiar.is_empty() {
+            false => familiar,
+            true => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!("every ranked word is too obscure; keeping the first");
+                ranked.into_iter().take(1).collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::Familiar;
+    use crate::{Dictionary, Guess, Guesser, Word};
+
+    struct Ranked(Vec<Word>);
+
+    impl Guesser for Ranked {
+        fn guess(&mut self, _: &[Guess]) -> Word {
+            self.0[0]
+        }
+
+        fn ranked(&mut self, _: &[Guess]) -> Vec<Word> {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn skips_obscure_words() {
+        let dictionary = Dictionary::parse("which 100\ncrane 50\nslate 50\naahed 1\n").unwrap();
+        let words = vec![Word::new("aahed"), Word::new("slate"), Word::new("which")];
+
+        let mut guesser = Familiar::new(Ranked(words.clone()), &dictionary, 50.0);
+        assert_eq!(guesser.guess(&[]), "slate");
+        assert_eq!(guesser.ranked(&[]), ["slate", "which"]);
+
+        // only `which` has nothing more common
+        let mut guesser = Familiar::new(Ranked(words.clone()), &dictionary, 0.0);
+        assert_eq!(guesser.ranked(&[]), ["which"]);
+
+        let mut guesser = Familiar::new(Ranked(words.clone()), &dictionary, 100.0);
+        assert_eq!(guesser.ranked(&[]), words);
+    }
+
+    #[test]
+    fn falls_back_to_the_first_choice() {
+        let dictionary = Dictionary::parse("which 100\ncrane 50\naahed 1\n").unwrap();
+        let mut guesser = Familiar::new(Ranked(vec![Word::new("aahed")]), &dictionary, 10.0);
+        assert_eq!(guesser.guess(&[]), "aahed");
+    }
+}
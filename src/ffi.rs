@@ -0,0 +1,194 @@
+//! A C API for embedding the solver in programs written in other languages.
+//!
+//! The declarations are in `include/roget.h`, generated with
+//! `cbindgen --config cbindgen.toml --output include/roget.h src/ffi.rs`. As with the `wasm`
+//! bindings this crate is not a `cdylib`, so the `roget-ffi` crate in `ffi/` builds the
+//! library:
+//!
+//! ```sh
+//! cargo build --release -p roget-ffi
+//! cc game.c -Iinclude -Ltarget/release -lroget_ffi
+//! ```
+//!
+//! That gives `target/release/libroget_ffi.so` (`.dylib` on macOS) to link dynamically, and
+//! `libroget_ffi.a` to link statically.
+//!
+//! A solver is created with [`roget_solver_new`], asked for words with
+//! [`roget_solver_next_guess`], told what the game showed with
+//! [`roget_solver_apply_feedback`], and released with [`roget_solver_free`].
+
+use core::ffi::{c_char, CStr};
+
+use crate::algorithms::{Candidates, Naive, Normalization};
+use crate::{parse_mask, Dictionary, Guess, Guesser, Word};
+
+/// What a call did, as every function returns.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RogetStatus {
+    Ok = 0,
+    /// A pointer argument was null.
+    NullPointer,
+    /// The word is not valid UTF-8 or not a five-letter word.
+    InvalidWord,
+    /// The mask is not five of `g`, `y`, and `x`.
+    InvalidMask,
+    /// The output buffer cannot hold the word and its terminating NUL.
+    BufferTooSmall,
+    /// No answer is consistent with the feedback given so far.
+    NoCandidates,
+}
+
+/// One game in progress against the embedded dictionary and its official answers.
+pub struct RogetSolver {
+    guesser: Naive,
+    candidates: Candidates,
+    history: Vec<Guess>,
+    suggestion: Word,
+}
+
+/// Start a game. Release the solver with [`roget_solver_free`].
+#[no_mangle]
+pub extern "C" fn roget_solver_new() -> *mut RogetSolver {
+    let dictionary = Dictionary::official();
+    let mut guesser = Naive::with_dictionary(&dictionary);
+    let suggestion = guesser.guess(&[]);
+    let solver = RogetSolver {
+        guesser,
+        candidates: Candidates::from_priors(
+            &Normalization::default().weights(dictionary.answers()),
+        ),
+        history: Vec::new(),
+        suggestion,
+    };
+    Box::into_raw(Box::new(solver))
+}
+
+/// Write the word to play next into `out` as NUL-terminated UTF-8. Eleven bytes is always
+/// enough, since each letter takes at most two.
+///
+/// # Safety
+///
+/// `solver` must come from [`roget_solver_new`], and `out` must be valid for writes of `len`
+/// bytes.
+#[no_mangle]
+pub unsafe extern "C" fn roget_solver_next_guess(
+    solver: *const RogetSolver,
+    out: *mut c_char,
+    len: usize,
+) -> RogetStatus {
+    // SAFETY: the caller promises `solver` is live
+    let Some(solver) = (unsafe { solver.as_ref() }) else {
+        return RogetStatus::NullPointer;
+    };
+    if out.is_null() {
+        return RogetStatus::NullPointer;
+    }
+    if solver.candidates.is_empty() {
+        return RogetStatus::NoCandidates;
+    }
+
+    let word = solver.suggestion.to_string();
+    if word.len() >= len {
+        return RogetStatus::BufferTooSmall;
+    }
+    // SAFETY: the caller promises `out` has room for `len` bytes, and we write fewer
+    unsafe {
+        core::ptr::copy_nonoverlapping(word.as_ptr(), out.cast::<u8>(), word.len());
+        *out.add(word.len()) = 0;
+    }
+    RogetStatus::Ok
+}
+
+/// Record that `word` was played and the game showed `mask`, such as `gxxyy`, for it.
+///
+/// # Safety
+///
+/// `solver` must come from [`roget_solver_new`], and `word` and `mask` must be
+/// NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn roget_solver_apply_feedback(
+    solver: *mut RogetSolver,
+    word: *const c_char,
+    mask: *const c_char,
+) -> RogetStatus {
+    // SAFETY: the caller promises `solver` is live and not used elsewhere during the call
+    let Some(solver) = (unsafe { solver.as_mut() }) else {
+        return RogetStatus::NullPointer;
+    };
+    if word.is_null() || mask.is_null() {
+        return RogetStatus::NullPointer;
+    }
+    // SAFETY: the caller promises both are NUL-terminated
+    let (word, mask) = unsafe { (CStr::from_ptr(word), CStr::from_ptr(mask)) };
+
+    let Some(word) = word.to_str().ok().and_then(|w| w.parse().ok()) else {
+        return RogetStatus::InvalidWord;
+    };
+    let Some(mask) = mask.to_str().ok().and_then(|m| parse_mask(m).ok()) else {
+        return RogetStatus::InvalidMask;
+    };
+    let guess = Guess { word, mask };
+    solver.candidates.filter(&guess);
+    solver.history.push(guess);
+    if !solver.candidates.is_empty() {
+        solver.suggestion = solver.guesser.guess(&solver.history);
+    }
+    RogetStatus::Ok
+}
+
+/// Release a solver. Passing null does nothing.
+///
+/// # Safety
+///
+/// `solver` must come from [`roget_solver_new`] and not be used again afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn roget_solver_free(solver: *mut RogetSolver) {
+    if !solver.is_null() {
+        // SAFETY: the caller promises this is the box `roget_solver_new` handed out
+        drop(unsafe { Box::from_raw(solver) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ffi::{c_char, CStr};
+
+    use super::*;
+
+    fn next_guess(solver: *const RogetSolver) -> Result<String, RogetStatus> {
+        let mut out = [0 as c_char; 11];
+        match unsafe { roget_solver_next_guess(solver, out.as_mut_ptr(), out.len()) } {
+            RogetStatus::Ok => Ok(unsafe { CStr::from_ptr(out.as_ptr()) }
+                .to_str()
+                .unwrap()
+                .to_string()),
+            status => Err(status),
+        }
+    }
+
+    #[test]
+    fn plays_a_game() {
+        let solver = roget_solver_new();
+        assert_eq!(next_guess(solver).as_deref(), Ok("which"));
+
+        let feedback = |word: &CStr, mask: &CStr| unsafe {
+            roget_solver_apply_feedback(solver, word.as_ptr(), mask.as_ptr())
+        };
+        assert_eq!(feedback(c"which", c"xxxxx"), RogetStatus::Ok);
+        assert_eq!(next_guess(solver).as_deref(), Ok("about"));
+        assert_eq!(feedback(c"about", c"nope"), RogetStatus::InvalidMask);
+        assert_eq!(feedback(c"ab", c"xxxxx"), RogetStatus::InvalidWord);
+
+        let mut small = [0 as c_char; 5];
+        let status = unsafe { roget_solver_next_guess(solver, small.as_mut_ptr(), small.len()) };
+        assert_eq!(status, RogetStatus::BufferTooSmall);
+
+        // nothing can be both all gray and all green
+        assert_eq!(feedback(c"which", c"ggggg"), RogetStatus::Ok);
+        assert_eq!(next_guess(solver), Err(RogetStatus::NoCandidates));
+
+        unsafe { roget_solver_free(solver) };
+        unsafe { roget_solver_free(core::ptr::null_mut()) };
+    }
+}
@@ -0,0 +1,279 @@
+use alloc::vec::Vec;
+
+use crate::{Correctness, Guess, Luck, Matrix, Speed, Word};
+
+/// How well a finished game was played, guess by guess, in the style of the New York Times'
+/// WordleBot; see [`grade`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grade {
+    pub guesses: Vec<GradedGuess>,
+    /// The average skill of the guesses, from 0 to 99.
+    pub skill: u8,
+    /// The average luck of the guesses, from 0 to 99.
+    pub luck: u8,
+}
+
+/// How one guess of a graded game did.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradedGuess {
+    pub guess: Guess,
+    /// How many answers were still possible before the guess.
+    pub before: usize,
+    /// How many answers were still possible after it.
+    pub after: usize,
+    /// The bits of information the guess was expected to gain, and did.
+    pub bits: Luck,
+    /// The guess that would have gained the most information on average, of those the search
+    /// tried, and how much.
+    pub best: Word,
+    pub best_bits: f64,
+    /// How close the guess came to the best one, from 0 to 99: the share of the best
+    /// guess's expected information it would gain too. With one answer left, only playing
+    /// it scores.
+    pub skill: u8,
+    /// How lucky the mask it got was, from 0 to 99: the share of the possible answers that
+    /// would have left more of them, counting those that would have left as many as half.
+    pub luck: u8,
+}
+
+/// Grade the game that played `guesses` against `answer`, with every answer still possible
+/// counting as equally likely, or `None` if the answer or a guess is not in `matrix`.
+/// Guesses after the one that found the answer are left out.
+///
+/// Each guess is compared against every allowed guess at that point, or with a faster
+/// `speed` against a share of them, and the search is spread across every core with the
+/// `parallel` feature, or the threads of the [`Runtime`](crate::Runtime) it runs in.
+/// Against fewer guesses the best one found may be worse, so skill can come out higher.
+///
+/// ```
+/// use roget::{grade, Dictionary, Matrix, Speed, Word};
+///
+/// let dictionary = Dictionary::parse("crane\ntrace\nslate\nplate\nlousy\n").unwrap();
+/// let matrix = Matrix::compute(&dictionary);
+/// let guesses = [Word::new("slate"), Word::new("plate")];
+/// let grade = grade(&matrix, Word::new("plate"), &guesses, Speed::Best).unwrap();
+/// assert_eq!(grade.guesses[0].after, 1);
+/// assert_eq!(grade.guesses[1].skill, 99);
+/// ```
+pub fn grade(matrix: &Matrix, answer: Word, guesses: &[Word], speed: Speed) -> Option<Grade> {
+    let answer = matrix.answer_index(answer)?;
+    let mut remaining: Vec<usize> = (0..matrix.answers().len()).collect();
+    let mut graded = Vec::with_capacity(guesses.len());
+    for &word in guesses {
+        let guess = matrix.guess_index(word)?;
+        let pattern = matrix.pattern(guess, answer);
+        let buckets = matrix.partition(guess, remaining.iter().copied());
+        let (best, best_bits) = best(matrix, &remaining, speed);
+        let expected = bits(&buckets, remaining.len());
+
+        let total = remaining.len() as f64;
+        let left = buckets[usize::from(pattern)];
+        let skill = match remaining.len() {
+            1 => match word == matrix.answers()[answer] {
+                true => 1.0,
+                false => 0.0,
+            },
+            _ => expected / best_bits,
+        };
+        // every answer in a bucket of `n` would have left `n`
+        let (more, same) = buckets.iter().fold((0, 0), |(more, same), &n| match n {
+            n if n > left => (more + n, same),
+            n if n == left => (more, same + n),
+            _ => (more, same),
+        });
+        let luck = (more as f64 + same as f64 / 2.0) / total;
+
+        graded.push(GradedGuess {
+            guess: Guess {
+                word,
+                mask: Correctness::unpack(pattern),
+            },
+            before: remaining.len(),
+            after: left,
+            bits: Luck {
+                expected,
+                actual: libm::log2(total / left as f64),
+            },
+            best: matrix.guesses()[best],
+            best_bits,
+            skill: score(skill),
+            luck: score(luck),
+        });
+
+        if pattern == Correctness::pack([Correctness::Correct; 5]) {
+            break;
+        }
+        let row = matrix.row(guess);
+        remaining.retain(|&a| row[a] == pattern);
+    }
+
+    let mean = |field: fn(&GradedGuess) -> u8| {
+        let sum: f64 = graded.iter().map(|g| f64::from(field(g))).sum();
+        score(sum / 99.0 / graded.len().max(1) as f64)
+    };
+    Some(Grade {
+        skill: mean(|g| g.skill),
+        luck: mean(|g| g.luck),
+        guesses: graded,
+    })
+}
+
+/// The guess that gains the most bits on average against `remaining`, preferring one that
+/// could be the answer on ties, and how many bits that is. `remaining` must be in order.
+fn best(matrix: &Matrix, remaining: &[usize], speed: Speed) -> (usize, f64) {
+    let candidate = |guess: usize| {
+        matrix
+            .answer_index(matrix.guesses()[guess])
+            .is_some_and(|a| remaining.binary_search(&a).is_ok())
+    };
+    let tried = |&guess: &usize| speed.tries(guess) || candidate(guess);
+    let rate = |guess: usize| {
+        let buckets = matrix.partition(guess, remaining.iter().copied());
+        let bits = bits(&buckets, remaining.len());
+        (guess, bits, candidate(guess))
+    };
+    let better = |a: &(usize, f64, bool), b: &(usize, f64, bool)| {
+        a.1.total_cmp(&b.1)
+            .then(a.2.cmp(&b.2))
+            // the earliest guess wins the rest, so the result does not depend on threads
+            .then(b.0.cmp(&a.0))
+    };
+
+    #[cfg(feature = "parallel")]
+    let best = {
+        use rayon::prelude::*;
+        (0..matrix.guesses().len())
+            .into_par_iter()
+            .filter(tried)
+            .map(rate)
+            .max_by(better)
+    };
+    #[cfg(not(feature = "parallel"))]
+    let best = (0..matrix.guesses().len())
+        .filter(tried)
+        .map(rate)
+        .max_by(better);
+
+    // the answer is always among `remaining`, so there is always a candidate to try
+    let (guess, bits, _) = best.expect("a matrix has guesses");
+    (guess, bits)
+}
+
+/// The entropy of splitting `total` answers into `buckets`.
+fn bits(buckets: &[usize; 243], total: usize) -> f64 {
+    let total = total as f64;
+    buckets
+        .iter()
+        .filter(|&&n| n > 0)
+        .map(|&n| {
+            let p = n as f64 / total;
+            -p * libm::log2(p)
+        })
+        .sum()
+}
+
+/// A share from 0 to 1 as a score from 0 to 99.
+fn score(share: f64) -> u8 {
+    libm::round(share.clamp(0.0, 1.0) * 99.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::grade;
+    use crate::{Correctness, Dictionary, Matrix, Speed, Word};
+
+    fn matrix() -> Matrix {
+        let dictionary =
+            Dictionary::parse("crane\ntrace\nslate\nplate\nshale\nwhale\nlousy\nfjord\n").unwrap();
+        Matrix::compute(&dictionary)
+    }
+
+    #[test]
+    fn grades_each_guess() {
+        let matrix = matrix();
+        let guesses = [Word::new("fjord"), Word::new("slate"), Word::new("whale")];
+        let grade = grade(&matrix, Word::new("whale"), &guesses, Speed::Best).unwrap();
+        assert_eq!(grade.guesses.len(), 3);
+
+        let [first, second, third] = &grade.guesses[..] else {
+            unreachable!()
+        };
+        // fjord leaves slate, plate, shale, and whale together
+        assert_eq!(first.before, 8);
+        assert_eq!(first.after, 4);
+        assert!(first.skill < 99);
+        assert!(first.bits.expected < first.best_bits);
+        assert_eq!(second.before, first.after);
+        assert_eq!(third.guess.mask, [Correctness::Correct; 5]);
+        assert_eq!(third.after, 1);
+        assert!(grade.skill > 0 && grade.skill < 99);
+    }
+
+    #[test]
+    fn the_best_guess_is_full_skill() {
+        let matrix = matrix();
+        let first = grade(
+            &matrix,
+            Word::new("lousy"),
+            &[Word::new("lousy")],
+            Speed::Best,
+        )
+        .unwrap();
+        let best = first.guesses[0].best;
+        let grade = grade(&matrix, Word::new("lousy"), &[best], Speed::Best).unwrap();
+        assert_eq!(grade.guesses[0].skill, 99);
+    }
+
+    #[test]
+    fn luck_follows_the_bucket() {
+        let matrix = matrix();
+        // crane leaves only itself when it is the answer, and more when it is not
+        let lucky = grade(
+            &matrix,
+            Word::new("crane"),
+            &[Word::new("crane")],
+            Speed::Best,
+        )
+        .unwrap();
+        let unlucky = grade(
+            &matrix,
+            Word::new("shale"),
+            &[Word::new("crane")],
+            Speed::Best,
+        )
+        .unwrap();
+        assert!(lucky.luck > unlucky.luck);
+        assert_eq!(lucky.skill, unlucky.skill);
+    }
+
+    #[test]
+    fn faster_searches_try_fewer_guesses() {
+        let matrix = matrix();
+        let guesses = [Word::new("fjord")];
+        let best = grade(&matrix, Word::new("whale"), &guesses, Speed::Best).unwrap();
+        let fast = grade(&matrix, Word::new("whale"), &guesses, Speed::Fast).unwrap();
+        let (best, fast) = (&best.guesses[0], &fast.guesses[0]);
+        assert!(fast.best_bits <= best.best_bits);
+        assert!(fast.skill >= best.skill);
+        assert_eq!(fast.bits, best.bits);
+    }
+
+    #[test]
+    fn unknown_words_are_not_graded() {
+        let matrix = matrix();
+        assert!(grade(
+            &matrix,
+            Word::new("zzzzz"),
+            &[Word::new("crane")],
+            Speed::Best
+        )
+        .is_none());
+        assert!(grade(
+            &matrix,
+            Word::new("crane"),
+            &[Word::new("zzzzz")],
+            Speed::Best
+        )
+        .is_none());
+    }
+}
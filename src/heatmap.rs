@@ -0,0 +1,140 @@
+use alloc::vec::Vec;
+
+use crate::Word;
+
+/// How often each letter appears in each position over a set of words, say the answers
+/// still possible partway through a game.
+///
+/// Letters are tracked by their Latin-1 code point, like [`Keyboard`](crate::Keyboard), so
+/// accented letters are counted too.
+///
+/// ```
+/// use roget::{Heatmap, Word};
+///
+/// let heatmap: Heatmap = ["crane", "crate", "slate"].iter().map(|&w| Word::new(w)).collect();
+/// assert_eq!(heatmap.count(0, 'c'), 2);
+/// assert_eq!(heatmap.anywhere('t'), 2);
+/// assert_eq!(heatmap.share(4, 'e'), 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heatmap {
+    /// `positions[i][b]` is the number of words with the letter `b` at position `i`.
+    positions: [[usize; 256]; Word::LEN],
+    /// `anywhere[b]` is the number of words the letter `b` appears in at all.
+    anywhere: [usize; 256],
+    words: usize,
+}
+
+impl Default for Heatmap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Heatmap {
+    pub fn new() -> Self {
+        Self {
+            positions: [[0; 256]; Word::LEN],
+            anywhere: [0; 256],
+            words: 0,
+        }
+    }
+
+    /// Count one more word.
+    pub fn add(&mut self, word: Word) {
+        let mut seen = [false; 256];
+        for (counts, &b) in self.positions.iter_mut().zip(word.as_bytes()) {
+            counts[usize::from(b)] += 1;
+            if !core::mem::replace(&mut seen[usize::from(b)], true) {
+                self.anywhere[usize::from(b)] += 1;
+            }
+        }
+        self.words += 1;
+    }
+
+    /// How many words were counted.
+    pub fn words(&self) -> usize {
+        self.words
+    }
+
+    /// How many words have `letter` at `position` (0-based).
+    ///
+    /// # Panics
+    ///
+    /// If `position` is not less than [`Word::LEN`].
+    pub fn count(&self, position: usize, letter: char) -> usize {
+        index(letter).map_or(0, |b| self.positions[position][b])
+    }
+
+    /// The share of words, between 0 and 1, with `letter` at `position`.
+    pub fn share(&self, position: usize, letter: char) -> f64 {
+        self.count(position, letter) as f64 / self.words.max(1) as f64
+    }
+
+    /// How many words have `letter` in any position.
+    pub fn anywhere(&self, letter: char) -> usize {
+        index(letter).map_or(0, |b| self.anywhere[b])
+    }
+
+    /// Every letter that appears in some word, in code point order.
+    pub fn letters(&self) -> Vec<char> {
+        (0..=u8::MAX)
+            .filter(|&b| self.anywhere[usize::from(b)] > 0)
+            .map(char::from)
+            .collect()
+    }
+}
+
+impl Extend<Word> for Heatmap {
+    fn extend<I: IntoIterator<Item = Word>>(&mut self, words: I) {
+        for word in words {
+            self.add(word);
+        }
+    }
+}
+
+impl FromIterator<Word> for Heatmap {
+    fn from_iter<I: IntoIterator<Item = Word>>(words: I) -> Self {
+        let mut heatmap = Self::new();
+        heatmap.extend(words);
+        heatmap
+    }
+}
+
+/// Words are stored lowercase, so look letters up the same way.
+fn index(letter: char) -> Option<usize> {
+    let lower = letter.to_lowercase().next()?;
+    u8::try_from(lower).ok().map(usize::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Heatmap;
+    use crate::Word;
+
+    #[test]
+    fn counts() {
+        let heatmap: Heatmap = ["eerie", "crane", "slate"]
+            .iter()
+            .map(|&w| Word::new(w))
+            .collect();
+        assert_eq!(heatmap.words(), 3);
+        assert_eq!(heatmap.count(4, 'e'), 3);
+        assert_eq!(heatmap.count(0, 'E'), 1);
+        assert_eq!(heatmap.count(2, 'z'), 0);
+        // a repeated letter counts once per word
+        assert_eq!(heatmap.anywhere('e'), 3);
+        assert_eq!(heatmap.share(1, 'r'), 1.0 / 3.0);
+        assert_eq!(
+            heatmap.letters(),
+            ['a', 'c', 'e', 'i', 'l', 'n', 'r', 's', 't']
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let heatmap = Heatmap::new();
+        assert_eq!(heatmap.share(0, 'a'), 0.0);
+        assert!(heatmap.letters().is_empty());
+    }
+}
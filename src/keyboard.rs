@@ -0,0 +1,126 @@
+use crate::{Correctness, Guess};
+
+/// The best-known status of every letter, as shown on Wordle's on-screen keyboard.
+///
+/// Letters are tracked by their Latin-1 code point, so accented letters have keys too.
+///
+/// A letter is `None` until it has been guessed. After that it keeps the best
+/// [`Correctness`] it has ever received, so a letter that was once green stays green even
+/// if a later guess places it elsewhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Keyboard {
+    keys: [Option<Correctness>; 256],
+}
+
+impl Default for Keyboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Keyboard {
+    pub fn new() -> Self {
+        Self { keys: [None; 256] }
+    }
+
+    pub fn from_history(history: &[Guess]) -> Self {
+        let mut keyboard = Self::new();
+        for guess in history {
+            keyboard.update(guess);
+        }
+        keyboard
+    }
+
+    /// Fold the feedback from one more guess into the keyboard.
+    pub fn update(&mut self, guess: &Guess) {
+        for (letter, &c) in guess.word.letters().zip(&guess.mask) {
+            let Some(key) = Self::index(letter) else {
+                continue;
+            };
+            let known = &mut self.keys[key];
+            if known.is_none_or(|known| rank(c) > rank(known)) {
+                *known = Some(c);
+            }
+        }
+    }
+
+    /// The status of `letter`, or `None` if it has not been guessed yet.
+    pub fn get(&self, letter: char) -> Option<Correctness> {
+        Self::index(letter).and_then(|key| self.keys[key])
+    }
+
+    /// Every letter from `a` to `z` alongside its status.
+    pub fn iter(&self) -> impl Iterator<Item = (char, Option<Correctness>)> + '_ {
+        self.letters(crate::Language::English.alphabet())
+    }
+
+    /// Every letter of `alphabet`, in order, alongside its status.
+    pub fn letters<'a>(
+        &'a self,
+        alphabet: &'a str,
+    ) -> impl Iterator<Item = (char, Option<Correctness>)> + 'a {
+        alphabet.chars().map(|letter| (letter, self.get(letter)))
+    }
+
+    fn index(letter: char) -> Option<usize> {
+        if !letter.is_alphabetic() {
+            return None;
+        }
+        let lower = letter.to_lowercase().next()?;
+        u8::try_from(lower).ok().map(usize::from)
+    }
+}
+
+fn rank(c: Correctness) -> u8 {
+    match c {
+        Correctness::Wrong => 0,
+        Correctness::Misplaced => 1,
+        Correctness::Correct => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Keyboard;
+    use crate::{Correctness, Guess, Word};
+
+    fn guess(word: &str, mask: [Correctness; 5]) -> Guess {
+        Guess {
+            word: Word::new(word),
+            mask,
+        }
+    }
+
+    #[test]
+    fn unknown_until_guessed() {
+        let keyboard = Keyboard::new();
+        assert!(keyboard.iter().all(|(_, c)| c.is_none()));
+        assert_eq!(keyboard.get('q'), None);
+    }
+
+    #[test]
+    fn keeps_best_status() {
+        use Correctness::*;
+        let keyboard = Keyboard::from_history(&[
+            guess("speed", [Wrong, Wrong, Misplaced, Wrong, Wrong]),
+            guess("abide", [Wrong, Wrong, Wrong, Wrong, Correct]),
+            guess("eerie", [Wrong, Wrong, Wrong, Wrong, Correct]),
+        ]);
+
+        assert_eq!(keyboard.get('e'), Some(Correct));
+        assert_eq!(keyboard.get('s'), Some(Wrong));
+        assert_eq!(keyboard.get('z'), None);
+    }
+
+    #[test]
+    fn accented_letters() {
+        use Correctness::*;
+        let keyboard =
+            Keyboard::from_history(&[guess("größe", [Wrong, Correct, Misplaced, Wrong, Wrong])]);
+
+        assert_eq!(keyboard.get('ö'), Some(Misplaced));
+        assert_eq!(keyboard.get('Ö'), Some(Misplaced));
+        assert_eq!(keyboard.get('ß'), Some(Wrong));
+        assert_eq!(keyboard.get('ü'), None);
+    }
+}
@@ -0,0 +1,105 @@
+use alloc::sync::Arc;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{Dictionary, Word};
+
+/// A language Wordle can be played in, which decides the letters words may use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Self; 3] = [Self::English, Self::Spanish, Self::German];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::English => "english",
+            Self::Spanish => "spanish",
+            Self::German => "german",
+        }
+    }
+
+    /// Every lowercase letter a word may use, in alphabetical order, with Spanish ñ after n
+    /// and the other letters beyond a to z after z.
+    pub fn alphabet(self) -> &'static str {
+        match self {
+            Self::English => "abcdefghijklmnopqrstuvwxyz",
+            Self::Spanish => "abcdefghijklmnñopqrstuvwxyzáéíóúü",
+            Self::German => "abcdefghijklmnopqrstuvwxyzäöüß",
+        }
+    }
+
+    /// Whether every letter of `word` belongs to this language's alphabet.
+    pub fn allows(self, word: Word) -> bool {
+        word.letters()
+            .all(|letter| self.alphabet().contains(letter))
+    }
+
+    /// The dictionary compiled into the crate for this language, if there is one.
+    pub fn dictionary(self) -> Option<Arc<Dictionary>> {
+        match self {
+            #[cfg(feature = "embedded-dictionary")]
+            Self::English => Some(Dictionary::embedded()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Language {
+    type Err = UnknownLanguage;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|language| language.name().eq_ignore_ascii_case(s))
+            .ok_or(UnknownLanguage)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownLanguage;
+
+impl fmt::Display for UnknownLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected one of english, spanish, or german")
+    }
+}
+
+impl core::error::Error for UnknownLanguage {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::Language;
+    use crate::Word;
+
+    #[test]
+    fn alphabets() {
+        assert!(Language::German.allows(Word::new("größe")));
+        assert!(!Language::English.allows(Word::new("größe")));
+        assert!(Language::Spanish.allows(Word::new("niños")));
+        assert!(!Language::Spanish.allows(Word::new("größe")));
+    }
+
+    #[test]
+    fn names() {
+        for language in Language::ALL {
+            assert_eq!(language.to_string().parse(), Ok(language));
+        }
+        assert!("klingon".parse::<Language>().is_err());
+    }
+}
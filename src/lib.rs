@@ -1,51 +1,542 @@
-use std::collections::HashSet;
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::ops::ControlFlow;
+use core::time::Duration;
+
+mod absurdle;
 pub mod algorithms;
+mod anti;
+#[cfg(all(feature = "tokio", not(target_family = "wasm")))]
+mod async_guesser;
+mod bitset;
+mod board;
+mod cancel;
+mod collections;
+mod compliant;
+mod config;
+mod debugger;
+pub mod dictionary;
+mod endgame;
+mod explain;
+mod familiar;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod grade;
+mod heatmap;
+mod keyboard;
+mod language;
+mod luck;
+mod mask;
+pub mod mastermind;
+mod matrix;
+mod multi;
+mod openers;
+mod oracle;
+#[cfg(feature = "python")]
+mod python;
+mod query;
+mod recorder;
+#[cfg(feature = "network")]
+mod remote;
+mod reverse;
+mod rng;
+mod rules;
+mod runtime;
+mod session;
+mod share;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd;
+mod speed;
+mod stats;
+mod streak;
+#[cfg(feature = "embedded-dictionary")]
+mod sync;
+mod tree;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod word;
 
-const DICTIONARY: &str = include_str!("../dictionary.txt");
+pub use absurdle::Absurdle;
+pub use anti::{AntiWordle, AntiWordleError};
+#[cfg(all(feature = "tokio", not(target_family = "wasm")))]
+pub use async_guesser::AsyncGuesser;
+pub use bitset::Bitset;
+pub use board::Board;
+pub use cancel::Cancel;
+pub use compliant::Compliant;
+pub use config::{GameConfig, Invalid, InvalidGuess};
+pub use debugger::{Alternative, Debugger};
+pub use dictionary::Dictionary;
+pub use endgame::{Endgame, Move, Tablebase};
+pub use explain::{Explanation, Outcome, Reason, Rejected};
+pub use familiar::Familiar;
+pub use grade::{grade, Grade, GradedGuess};
+pub use heatmap::Heatmap;
+pub use keyboard::Keyboard;
+pub use language::{Language, UnknownLanguage};
+pub use luck::Luck;
+pub use mask::{parse_mask, MaskParser, ParseMaskError};
+pub use matrix::Matrix;
+pub use multi::MultiWordle;
+pub use openers::{openers, Opener};
+pub use oracle::Oracle;
+pub use query::{Hint, Query};
+#[cfg(feature = "tracing")]
+pub use recorder::Events;
+pub use recorder::{Call, Recorder, Sink};
+#[cfg(feature = "network")]
+pub use remote::{Remote, RemoteError};
+pub use reverse::infer_answers;
+pub use rules::{Rules, UnknownRules};
+pub use runtime::{Runtime, RuntimeError};
+pub use session::Session;
+pub use share::{ImpossibleGrid, ParseShareError, ShareGrid, SharedGame};
+pub use speed::{Speed, UnknownSpeed};
+pub use stats::{Elo, GameStats, Paired};
+pub use streak::Streaks;
+pub use tree::{DecisionTree, Dot, ParseTreeError, TreeError};
+pub use word::{ParseWordError, Word};
 
 // check whether the guess is valid
+#[derive(Debug, Clone)]
 pub struct Wordle {
-    dictionary: HashSet<&'static str>,
+    dictionary: Arc<Dictionary>,
+    /// How [`Wordle::play_game`] and the other `play` methods play.
+    defaults: GameConfig,
+}
+
+#[cfg(feature = "embedded-dictionary")]
+impl Default for Wordle {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Wordle {
+    /// A game using the embedded dictionary.
+    #[cfg(feature = "embedded-dictionary")]
     pub fn new() -> Self {
+        Self::with_dictionary(Dictionary::embedded())
+    }
+
+    pub fn with_dictionary(dictionary: Arc<Dictionary>) -> Self {
         Self {
-            dictionary: HashSet::from_iter(
-                DICTIONARY
-                    .lines()
-                    .map(|line| line.split_once(' ').expect("word + space + freq").0),
-            ),
+            dictionary,
+            defaults: GameConfig::new(),
+        }
+    }
+
+    /// Keep the answers still possible after each guess in [`GameResult::candidates`].
+    ///
+    /// Off by default: a game against the full answer list keeps thousands of words.
+    pub fn keep_candidates(mut self, keep: bool) -> Self {
+        self.defaults = self.defaults.keep_candidates(keep);
+        self
+    }
+
+    /// Play [`Wordle::play_game`] and the other `play` methods by `rules`, rather than
+    /// allowing any word in the dictionary.
+    pub fn rules(mut self, rules: Rules) -> Self {
+        self.defaults = self.defaults.rules(rules);
+        self
+    }
+
+    pub fn dictionary(&self) -> &Arc<Dictionary> {
+        &self.dictionary
+    }
+
+    /// All possible answers, with their frequencies, that are still consistent with
+    /// `history`.
+    pub fn candidates<'a>(
+        &'a self,
+        history: &'a [Guess],
+    ) -> impl Iterator<Item = (Word, usize)> + 'a {
+        self.dictionary
+            .answers()
+            .iter()
+            .copied()
+            .filter(move |&(word, _)| history.iter().all(|guess| guess.matches(word)))
+    }
+
+    /// How often each letter appears in each position among the answers still possible
+    /// after `history`; with no history, over every answer.
+    pub fn heatmap(&self, history: &[Guess]) -> Heatmap {
+        self.candidates(history).map(|(word, _)| word).collect()
+    }
+
+    /// Whether `word` is an allowed guess.
+    pub fn contains(&self, word: Word) -> bool {
+        self.dictionary.contains(word)
+    }
+
+    /// Whether `word` is an allowed guess, and if not, the error a game would reject it
+    /// with, suggesting up to three dictionary words it may have been meant to be.
+    pub fn check(&self, word: Word) -> Result<(), InvalidGuess> {
+        match self.dictionary.contains(word) {
+            true => Ok(()),
+            false => Err(InvalidGuess::NotInDictionary {
+                word,
+                closest: self.dictionary.closest(word, 3),
+            }),
         }
     }
 
-    pub fn play<G: Guesser>(&self, answer: &'static str, mut guesser: G) -> Option<usize> {
-        let mut history = Vec::new();
+    /// Like [`Wordle::check`], but also by the [`rules`](Wordle::rules) games are played by,
+    /// for `word` played after `history`.
+    pub fn check_guess(&self, history: &[Guess], word: Word) -> Result<(), InvalidGuess> {
+        self.check(word)
+            .and_then(|()| self.defaults.rules.check(history, word))
+    }
+
+    /// How often `word` occurs in the dictionary, or `None` if it is not an allowed guess.
+    pub fn frequency(&self, word: Word) -> Option<usize> {
+        self.dictionary.frequency(word)
+    }
+
+    /// How common `word` is relative to the rest of the dictionary, as a percentile; see
+    /// [`Dictionary::percentile`].
+    pub fn percentile(&self, word: Word) -> Option<f64> {
+        self.dictionary.percentile(word)
+    }
+
+    pub fn play<G: Guesser>(&self, answer: &str, guesser: G) -> Option<usize> {
+        self.play_game(answer, guesser).score
+    }
+
+    /// Like [`Wordle::play`], but keeps every guess and its mask.
+    pub fn play_game<G: Guesser>(&self, answer: &str, guesser: G) -> GameResult {
+        let answer: Word = answer.parse().expect("answer is a five-letter word");
+
+        self.play_seeded(answer, guesser, &[])
+    }
+
+    /// Like [`Wordle::play_game`], but the game opens with the guesses in `seed` whatever
+    /// the guesser would have played. They count towards the score, and the guesser sees
+    /// them in its history as if it had made them.
+    pub fn play_seeded<G: Guesser>(&self, answer: Word, guesser: G, seed: &[Word]) -> GameResult {
+        self.play_with_config(answer, guesser, &self.defaults.clone().seed(seed))
+    }
+
+    /// Play a game against `answer` by the rules in `config`.
+    ///
+    /// # Panics
+    ///
+    /// If the guesser plays a word the game does not allow and `config` says to panic.
+    pub fn play_with_config<G: Guesser>(
+        &self,
+        answer: Word,
+        guesser: G,
+        config: &GameConfig,
+    ) -> GameResult {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("game", %answer).entered();
+        let (guesses, trace, score) = self.run(answer, guesser, config);
+        trace.result(answer, guesses, score)
+    }
 
-        // WORDLE only allows 6 guesses.
-        // We allow more to avoid chopping off the score distribution for stats purposes.
-        for i in 1..=32 {
-            let guess = guesser.guess(&history);
+    /// Like [`Wordle::play_with_config`], for a guesser that waits on something else for its
+    /// words.
+    ///
+    /// Unlike a blocking game, one that runs past its
+    /// [time limit](GameConfig::time_limit) ends as soon as it does, dropping the guess in
+    /// progress.
+    ///
+    /// # Panics
+    ///
+    /// If the guesser plays a word the game does not allow and `config` says to panic, or
+    /// if `config` has a time limit and this is not run on a tokio runtime with its timer
+    /// enabled.
+    #[cfg(all(feature = "tokio", not(target_family = "wasm")))]
+    pub async fn play_async<G: AsyncGuesser>(
+        &self,
+        answer: Word,
+        mut guesser: G,
+        config: &GameConfig,
+    ) -> GameResult {
+        let play = async {
+            let mut game = Game::new(self, answer, config);
+            for turn in 1..=config.max_guesses {
+                let Some(token) = game.token() else {
+                    return game.finish(None);
+                };
+                let start = std::time::Instant::now();
+                let guess = guesser.guess_until(&game.history, &token);
+                let guess = match game.cancel.deadline() {
+                    Some(deadline) => match tokio::time::timeout_at(deadline.into(), guess).await {
+                        Ok(guess) => guess,
+                        Err(_) => {
+                            #[cfg(feature = "tracing")]
+                            tracing::debug!(guesses = game.history.len(), "out of time");
+                            return game.finish(None);
+                        }
+                    },
+                    None => guess.await,
+                };
+                game.trace.guess_times.push(start.elapsed());
+                if let ControlFlow::Break(score) = game.play(turn, guess) {
+                    return game.finish(score);
+                }
+            }
+            game.finish(None)
+        };
+        #[cfg(feature = "tracing")]
+        let play = tracing::Instrument::instrument(play, tracing::debug_span!("game", %answer));
+        let (guesses, trace, score) = play.await;
+        trace.result(answer, guesses, score)
+    }
 
-            if guess == answer {
-                return Some(i);
+    /// Play `answers` in order as in Hurdle, each with a fresh guesser from
+    /// `make_guesser`: every game after the first opens with the answer of the one before.
+    ///
+    /// Stops at the first game the guesser does not solve, which is the last one returned.
+    pub fn play_hurdle<F, G>(&self, answers: &[Word], make_guesser: F) -> Vec<GameResult>
+    where
+        F: Fn() -> G,
+        G: Guesser,
+    {
+        let mut results: Vec<GameResult> = Vec::with_capacity(answers.len());
+        for &answer in answers {
+            let seed = results.last().map(|previous| previous.answer);
+            let result = self.play_seeded(answer, make_guesser(), seed.as_slice());
+            let solved = result.score.is_some();
+            results.push(result);
+            if !solved {
+                break;
             }
+        }
+        results
+    }
 
-            assert!(self.dictionary.contains(&*guess));
+    /// Play a game against each of `answers` in parallel, each with a fresh guesser from
+    /// `make_guesser`, and return the results in the same order as `answers`. The games
+    /// spread across every core, or the threads of the [`Runtime`] they are installed in.
+    #[cfg(feature = "parallel")]
+    pub fn play_all<F, G>(&self, answers: &[&str], make_guesser: F) -> Vec<GameResult>
+    where
+        F: Fn() -> G + Sync,
+        G: Guesser,
+    {
+        use rayon::prelude::*;
 
-            let correctness = Correctness::compute(answer, &guess);
-            history.push(Guess {
-                word: guess,
-                mask: correctness,
-            });
+        answers
+            .par_iter()
+            .map(|answer| self.play_game(answer, make_guesser()))
+            .collect()
+    }
+
+    /// Play a game hosted by `oracle`, giving up after `max_guesses` guesses.
+    ///
+    /// Returns the number of guesses it took to get an all-green mask, or `None` if it took
+    /// more, or the oracle [failed](Oracle::failed) before then.
+    pub fn play_against<O: Oracle, G: Guesser>(
+        &self,
+        oracle: O,
+        guesser: G,
+        max_guesses: usize,
+    ) -> Option<usize> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("game").entered();
+        let config = self.defaults.clone().max_guesses(max_guesses);
+        self.run(oracle, guesser, &config).2
+    }
+
+    /// Play a game of [`AntiWordle`] against `answer`, giving up after `max_guesses`
+    /// guesses.
+    ///
+    /// The score is the guess that hit the answer, so here a higher score is better and
+    /// `None` means the guesser dodged it to the end.
+    ///
+    /// # Panics
+    ///
+    /// If the guesser plays a word the answer could no longer be.
+    pub fn play_anti<G: Guesser>(
+        &self,
+        answer: Word,
+        mut guesser: G,
+        max_guesses: usize,
+    ) -> GameResult {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("game", %answer, anti = true).entered();
+        let mut game = AntiWordle::new(answer);
+        let mut remaining = self.answers();
+        let mut trace = Trace::new(&self.defaults);
+        let mut score = None;
+        for i in 1..=max_guesses {
+            let word = trace.time(|| guesser.guess(game.history()));
+            assert!(self.dictionary.contains(word));
+            if let Err(e) = game.guess(word) {
+                panic!("{word} breaks the rules: {e}");
+            }
+            let guess = game.history().last().expect("the guess was just made");
+            trace.narrow(&mut remaining, guess);
+            if game.is_over() {
+                score = Some(i);
+                break;
+            }
         }
+        trace.result(answer, game.history().to_vec(), score)
+    }
 
-        None
+    /// How lucky each guess of `result` was: how much it narrowed down the answer against
+    /// how much it would on average.
+    pub fn luck(&self, result: &GameResult) -> Vec<Luck> {
+        let mut remaining = self.answers();
+        result
+            .guesses
+            .iter()
+            .map(|guess| {
+                let luck = Luck::of(guess.word, result.answer, &remaining);
+                Progress::narrow(&mut remaining, guess);
+                luck
+            })
+            .collect()
+    }
+
+    /// Every possible answer, to be narrowed down by [`Progress::narrow`].
+    fn answers(&self) -> Vec<Word> {
+        self.dictionary.answers().iter().map(|&(w, _)| w).collect()
+    }
+
+    fn run<O: Oracle, G: Guesser>(
+        &self,
+        oracle: O,
+        mut guesser: G,
+        config: &GameConfig,
+    ) -> (Vec<Guess>, Trace, Option<usize>) {
+        let mut game = Game::new(self, oracle, config);
+        for turn in 1..=config.max_guesses {
+            let Some(token) = game.token() else {
+                return game.finish(None);
+            };
+            // the guesser still takes its turn on seeded guesses, since guessers may keep
+            // state that expects to see every step of the history
+            let guess = game
+                .trace
+                .time(|| guesser.guess_until(&game.history, &token));
+            if let ControlFlow::Break(score) = game.play(turn, guess) {
+                return game.finish(score);
+            }
+        }
+        game.finish(None)
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A game in progress, kept between guesses however the guesser is asked for them.
+struct Game<'a, O> {
+    wordle: &'a Wordle,
+    config: &'a GameConfig,
+    oracle: O,
+    history: Vec<Guess>,
+    remaining: Vec<Word>,
+    trace: Trace,
+    /// Cancelled when the whole game is, including by its time limit.
+    cancel: Cancel,
+}
+
+impl<'a, O: Oracle> Game<'a, O> {
+    fn new(wordle: &'a Wordle, oracle: O, config: &'a GameConfig) -> Self {
+        let remaining = match &config.answers {
+            Some(answers) => answers.clone(),
+            None => wordle.answers(),
+        };
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        let cancel = match config.time_limit {
+            Some(limit) => config.cancel.until(std::time::Instant::now() + limit),
+            None => config.cancel.clone(),
+        };
+        #[cfg(not(all(feature = "std", not(target_family = "wasm"))))]
+        let cancel = config.cancel.clone();
+        Self {
+            wordle,
+            config,
+            oracle,
+            history: Vec::new(),
+            remaining,
+            trace: Trace::new(config),
+            cancel,
+        }
+    }
+
+    /// The token to hand the guesser for its next guess, or `None` if the game has been
+    /// cancelled.
+    fn token(&self) -> Option<Cancel> {
+        if self.cancel.is_cancelled() {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(guesses = self.history.len(), "cancelled");
+            return None;
+        }
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        if let Some(limit) = self.config.guess_time_limit {
+            return Some(self.cancel.until(std::time::Instant::now() + limit));
+        }
+        Some(self.cancel.clone())
+    }
+
+    /// Play `guess` as guess number `turn`, unless the config seeds that turn, and grade
+    /// it. Breaks with the score once the game is over.
+    fn play(&mut self, turn: usize, guess: Word) -> ControlFlow<Option<usize>> {
+        let config = self.config;
+        let word = config.seed.get(turn - 1).copied().unwrap_or(guess);
+        let broken = self
+            .wordle
+            .check(word)
+            .and_then(|()| config.rules.check(&self.history, word));
+        if let Err(broken) = broken {
+            match config.on_invalid {
+                Invalid::Panic => panic!("{broken}"),
+                Invalid::Forfeit => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(guess = %word, reason = %broken, "forfeited");
+                    self.trace.guess_times.truncate(self.history.len());
+                    return ControlFlow::Break(None);
+                }
+            }
+        }
+
+        let correctness = self.oracle.grade(word);
+        if self.oracle.failed() {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(guess = %word, "the oracle failed");
+            self.trace.guess_times.truncate(self.history.len());
+            return ControlFlow::Break(None);
+        }
+        let guess = Guess {
+            word,
+            mask: correctness,
+        };
+        self.trace.narrow(&mut self.remaining, &guess);
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            turn,
+            guess = %word,
+            candidates = self.remaining.len(),
+            bits = self.trace.progress.last().map_or(0.0, Progress::bits),
+            "guessed"
+        );
+        self.history.push(guess);
+        if correctness == [Correctness::Correct; 5] {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(score = turn, "solved");
+            return ControlFlow::Break(Some(turn));
+        }
+        if turn == config.max_guesses {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(guesses = turn, "gave up");
+            return ControlFlow::Break(None);
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn finish(self, score: Option<usize>) -> (Vec<Guess>, Trace, Option<usize>) {
+        (self.history, self.trace, score)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Correctness {
     /// Green
     Correct,
@@ -55,70 +546,244 @@ pub enum Correctness {
     Wrong,
 }
 impl Correctness {
-    fn compute(answer: &str, guess: &str) -> [Self; 5] {
-        assert_eq!(answer.len(), 5);
-        assert_eq!(guess.len(), 5);
+    /// Encode a mask in one byte, as a base-3 number with the first letter least
+    /// significant. Every mask gets a distinct value below 243.
+    pub fn pack(mask: [Self; 5]) -> u8 {
+        mask.iter().rev().fold(0, |packed, c| {
+            let digit = match c {
+                Self::Wrong => 0,
+                Self::Misplaced => 1,
+                Self::Correct => 2,
+            };
+            packed * 3 + digit
+        })
+    }
 
-        let mut c = [Correctness::Wrong; 5];
+    /// The mask [`Correctness::pack`] encoded as `packed`.
+    pub fn unpack(mut packed: u8) -> [Self; 5] {
+        let mut mask = [Self::Wrong; 5];
+        for c in &mut mask {
+            *c = match packed % 3 {
+                0 => Self::Wrong,
+                1 => Self::Misplaced,
+                _ => Self::Correct,
+            };
+            packed /= 3;
+        }
+        mask
+    }
 
-        // mark green
-        for (i, (a, g)) in answer.chars().zip(guess.chars()).enumerate() {
-            if a == g {
-                c[i] = Correctness::Correct
+    /// Append the [packed](Correctness::pack) mask of `guess` against each of `answers` to
+    /// `out`, in order.
+    ///
+    /// With the `simd` feature on x86_64 this grades many answers per instruction, which is
+    /// what scoring a guess against every remaining candidate needs.
+    pub fn compute_packed(guess: Word, answers: &[Word], out: &mut Vec<u8>) {
+        out.reserve(answers.len());
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        let answers = {
+            let chunks = answers.chunks_exact(simd::LANES);
+            let rest = chunks.remainder();
+            for chunk in chunks {
+                let chunk = chunk.try_into().expect("chunks are exactly LANES long");
+                out.extend_from_slice(&simd::compute(guess, chunk));
             }
-        }
+            rest
+        };
+        out.extend(
+            answers
+                .iter()
+                .map(|&answer| Self::pack(Self::compute(answer, guess))),
+        );
+    }
 
-        // mark yellow
-        let mut used = [false; 5];
+    /// Grade `guess` against `answer`, letter by letter.
+    ///
+    /// Letters are compared by their [`Word`] bytes, so accented letters are simply
+    /// different letters from their plain forms, as in Spanish or German Wordle. This is
+    /// [`mastermind::feedback`] with letters for symbols.
+    pub(crate) fn compute(answer: Word, guess: Word) -> [Self; 5] {
+        mastermind::feedback(answer.as_bytes(), guess.as_bytes())
+    }
+}
 
-        for (i, &c) in c.iter().enumerate() {
-            if c == Correctness::Correct {
-                used[i] = true;
-            }
-        }
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Guess {
+    pub word: Word,
+    pub mask: [Correctness; 5],
+}
 
-        for (i, g) in guess.chars().enumerate() {
-            if c[i] == Correctness::Correct {
-                // already marked green
-                continue;
-            }
+impl Guess {
+    /// Whether `word` could be the answer given that this guess produced this mask.
+    pub fn matches(&self, word: Word) -> bool {
+        Correctness::compute(word, self.word) == self.mask
+    }
+}
 
-            if let Some(_) = answer.chars().enumerate().find_map(|(idx, chr)| {
-                if chr == g && !used[idx] {
-                    used[idx] = true;
-                    return Some(idx);
-                }
-                None
-            }) {
-                c[i] = Correctness::Misplaced;
-            }
+/// The full record of one game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameResult {
+    pub answer: Word,
+    /// Every guess in order, including the final all-green one when solved.
+    pub guesses: Vec<Guess>,
+    /// How many guesses it took, or `None` if the guesser never found the answer.
+    pub score: Option<usize>,
+    /// How much each guess narrowed down the answer, in the same order as `guesses`.
+    ///
+    /// Empty for games that were not played through [`Wordle`], such as ones read from a
+    /// [`DecisionTree`], since the possible answers are not known.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub progress: Vec<Progress>,
+    /// The answers still possible after each guess, in the same order as `guesses`.
+    ///
+    /// Empty unless the game was played with [`Wordle::keep_candidates`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub candidates: Vec<Vec<Word>>,
+    /// How long the guesser took over each guess, in the same order as `guesses`.
+    ///
+    /// Empty where there is no clock: without the `std` feature, and on WebAssembly.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub guess_times: Vec<Duration>,
+}
+
+/// How many answers were possible before and after one guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Progress {
+    pub before: usize,
+    pub after: usize,
+}
+
+impl Progress {
+    /// The bits of information the guess gained: each bit halves the possible answers.
+    pub fn bits(&self) -> f64 {
+        match self.after {
+            // a guess no answer agrees with tells us nothing we can use
+            0 => 0.0,
+            after => libm::log2(self.before as f64 / after as f64),
         }
+    }
 
-        c
+    /// Drop the words in `remaining` that `guess` rules out, and record how many that left.
+    fn narrow(remaining: &mut Vec<Word>, guess: &Guess) -> Self {
+        let before = remaining.len();
+        remaining.retain(|&word| guess.matches(word));
+        Self {
+            before,
+            after: remaining.len(),
+        }
     }
 }
 
-pub struct Guess {
-    pub word: String,
-    pub mask: [Correctness; 5],
+/// What a game learns guess by guess, kept for its [`GameResult`].
+struct Trace {
+    keep_candidates: bool,
+    progress: Vec<Progress>,
+    candidates: Vec<Vec<Word>>,
+    guess_times: Vec<Duration>,
+}
+
+impl Trace {
+    fn new(config: &GameConfig) -> Self {
+        Self {
+            keep_candidates: config.keep_candidates,
+            progress: Vec::new(),
+            candidates: Vec::new(),
+            guess_times: Vec::new(),
+        }
+    }
+
+    /// Ask the guesser for a word with `guess`, timing it if there is a clock.
+    fn time(&mut self, guess: impl FnOnce() -> Word) -> Word {
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        {
+            let start = std::time::Instant::now();
+            let word = guess();
+            self.guess_times.push(start.elapsed());
+            word
+        }
+        #[cfg(not(all(feature = "std", not(target_family = "wasm"))))]
+        guess()
+    }
+
+    fn result(self, answer: Word, guesses: Vec<Guess>, score: Option<usize>) -> GameResult {
+        GameResult {
+            answer,
+            guesses,
+            score,
+            progress: self.progress,
+            candidates: self.candidates,
+            guess_times: self.guess_times,
+        }
+    }
+
+    /// Narrow `remaining` down by `guess`, as [`Progress::narrow`] does, and record it.
+    fn narrow(&mut self, remaining: &mut Vec<Word>, guess: &Guess) {
+        self.progress.push(Progress::narrow(remaining, guess));
+        if self.keep_candidates {
+            self.candidates.push(remaining.clone());
+        }
+    }
 }
 
 pub trait Guesser {
-    fn guess(&mut self, history: &[Guess]) -> String;
+    /// The next word to play, which must be in the game's dictionary.
+    ///
+    /// [`Word`] is a small `Copy` value, so guessing never allocates; it only becomes a
+    /// string where it is shown.
+    fn guess(&mut self, history: &[Guess]) -> Word;
+
+    /// Like [`Guesser::guess`], but a guesser that searches for a while should poll
+    /// `cancel` and, once it is cancelled, answer with the best word it has found so far.
+    ///
+    /// Games always call this one. The default ignores `cancel`, which suits guessers that
+    /// answer quickly.
+    fn guess_until(&mut self, history: &[Guess], cancel: &Cancel) -> Word {
+        let _ = cancel;
+        self.guess(history)
+    }
+
+    /// The words this guesser would consider playing, best first, so the first is the one
+    /// [`Guesser::guess`] would play. Call it in place of `guess`, since guessers may keep
+    /// state that expects to see every step of the history.
+    ///
+    /// The default only has the guess itself; [`Debugger`] shows the rest.
+    fn ranked(&mut self, history: &[Guess]) -> Vec<Word> {
+        alloc::vec![self.guess(history)]
+    }
 }
 
-impl Guesser for fn(history: &[Guess]) -> String {
-    fn guess(&mut self, history: &[Guess]) -> String {
+impl Guesser for fn(history: &[Guess]) -> Word {
+    fn guess(&mut self, history: &[Guess]) -> Word {
         (*self)(history)
     }
 }
 
-#[cfg(test)]
+impl<G: Guesser + ?Sized> Guesser for Box<G> {
+    fn guess(&mut self, history: &[Guess]) -> Word {
+        (**self).guess(history)
+    }
+
+    fn guess_until(&mut self, history: &[Guess], cancel: &Cancel) -> Word {
+        (**self).guess_until(history, cancel)
+    }
+
+    fn ranked(&mut self, history: &[Guess]) -> Vec<Word> {
+        (**self).ranked(history)
+    }
+}
+
+#[cfg(all(test, feature = "embedded-dictionary"))]
 macro_rules! guesser {
     (|$history: ident| $impl: block) => {{
         struct G;
         impl $crate::Guesser for G {
-            fn guess(&mut self, $history: &[Guess]) -> String {
+            fn guess(&mut self, $history: &[Guess]) -> $crate::Word {
                 $impl
             }
         }
@@ -128,14 +793,21 @@ macro_rules! guesser {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "embedded-dictionary")]
     mod game {
+        use alloc::string::ToString;
+        use alloc::vec;
+        use alloc::vec::Vec;
 
-        use crate::{Guess, Wordle};
+        use crate::{
+            Cancel, Correctness, GameConfig, Guess, Guesser, Invalid, InvalidGuess, Rules, Word,
+            Wordle,
+        };
 
         #[test]
         fn genius() {
             let word = Wordle::new();
-            let guesser = guesser!(|_history| { "right".to_string() });
+            let guesser = guesser!(|_history| { Word::new("right") });
 
             assert_eq!(word.play("right", guesser), Some(1));
         }
@@ -145,9 +817,9 @@ mod tests {
             let word = Wordle::new();
             let guesser = guesser!(|history| {
                 if history.len() == 1 {
-                    return "right".to_string();
+                    return Word::new("right");
                 }
-                return "wrong".to_string();
+                return Word::new("wrong");
             });
 
             assert_eq!(word.play("right", guesser), Some(2));
@@ -158,24 +830,390 @@ mod tests {
             let word = Wordle::new();
             let guesser = guesser!(|history| {
                 if history.len() == 2 {
-                    return "right".to_string();
+                    return Word::new("right");
                 }
-                return "wrong".to_string();
+                return Word::new("wrong");
             });
 
             assert_eq!(word.play("right", guesser), Some(3));
         }
 
+        #[test]
+        fn candidates() {
+            let word = Wordle::new();
+            let history = [Guess {
+                word: Word::new("crane"),
+                mask: [Correctness::Correct; 5],
+            }];
+
+            let candidates: Vec<_> = word.candidates(&history).map(|(w, _)| w).collect();
+            assert_eq!(candidates, ["crane"]);
+            assert_eq!(word.candidates(&[]).count(), 12947);
+        }
+
+        #[test]
+        fn recorded() {
+            let word = Wordle::new();
+            let guesser = guesser!(|history| {
+                if history.len() == 1 {
+                    return Word::new("right");
+                }
+                return Word::new("wrong");
+            });
+
+            let result = word.play_game("right", guesser);
+            assert_eq!(result.score, Some(2));
+            assert_eq!(result.guesses.len(), 2);
+            assert_eq!(result.guesses[0].word, "wrong");
+            assert_eq!(result.guesses[1].mask, [Correctness::Correct; 5]);
+        }
+
+        #[test]
+        #[cfg(feature = "parallel")]
+        fn play_all() {
+            let word = Wordle::new();
+            let answers = ["right", "wrong", "crane"];
+            let results = word.play_all(&answers, || guesser!(|_history| { Word::new("right") }));
+            let scores: Vec<_> = results.iter().map(|r| r.score).collect();
+            assert_eq!(scores, [Some(1), None, None]);
+            assert_eq!(results[1].answer, "wrong");
+        }
+
+        #[test]
+        fn progress() {
+            let word = Wordle::new();
+            let guesser = guesser!(|history| {
+                if history.len() == 1 {
+                    return Word::new("right");
+                }
+                return Word::new("wrong");
+            });
+            let result = word.play_game("right", guesser);
+            let progress = &result.progress;
+            assert_eq!(progress.len(), 2);
+            assert_eq!(progress[0].before, word.candidates(&[]).count());
+            assert_eq!(progress[0].after, progress[1].before);
+            assert_eq!(progress[1].after, 1);
+            assert!(progress.iter().all(|step| step.bits() > 0.0));
+            assert!(result.candidates.is_empty());
+        }
+
+        #[test]
+        fn candidates_kept() {
+            let word = Wordle::new().keep_candidates(true);
+            let guesser = guesser!(|history| {
+                if history.len() == 1 {
+                    return Word::new("right");
+                }
+                return Word::new("wrong");
+            });
+            let result = word.play_game("right", guesser);
+            let expected: Vec<_> = word
+                .candidates(&result.guesses[..1])
+                .map(|(w, _)| w)
+                .collect();
+            assert_eq!(result.candidates.len(), 2);
+            assert_eq!(result.candidates[0], expected);
+            assert_eq!(result.candidates[1], [Word::new("right")]);
+            for (step, candidates) in result.progress.iter().zip(&result.candidates) {
+                assert_eq!(step.after, candidates.len());
+            }
+        }
+
+        #[test]
+        fn seeded() {
+            let word = Wordle::new();
+            let guesser = guesser!(|history| {
+                match history.first() {
+                    Some(seeded) if seeded.word == "crane" => Word::new("right"),
+                    _ => Word::new("wrong"),
+                }
+            });
+            let result = word.play_seeded(Word::new("right"), guesser, &[Word::new("crane")]);
+            assert_eq!(result.score, Some(2));
+            assert_eq!(result.guesses[0].word, "crane");
+        }
+
+        #[test]
+        fn config() {
+            let word = Wordle::new();
+            let wrong = || guesser!(|_history| { Word::new("wrong") });
+            let config = GameConfig::new().max_guesses(3);
+            let result = word.play_with_config(Word::new("right"), wrong(), &config);
+            assert_eq!(result.score, None);
+            assert_eq!(result.guesses.len(), 3);
+
+            let config = GameConfig::new().answers(vec![Word::new("right"), Word::new("wrong")]);
+            let result = word.play_with_config(Word::new("right"), wrong(), &config);
+            assert_eq!(result.progress[0].before, 2);
+            assert_eq!(result.progress[0].after, 1);
+        }
+
+        #[test]
+        fn hard_mode_forfeit() {
+            let word = Wordle::new();
+            let guesser = || {
+                guesser!(|history| {
+                    match history.len() {
+                        0 => Word::new("crate"),
+                        _ => Word::new("fuzzy"),
+                    }
+                })
+            };
+            let config = GameConfig::new()
+                .hard_mode(true)
+                .on_invalid(Invalid::Forfeit);
+            let result = word.play_with_config(Word::new("crane"), guesser(), &config);
+            assert_eq!(result.score, None);
+            assert_eq!(result.guesses.len(), 1);
+            assert!(result.guess_times.len() <= 1);
+
+            // the same guesses are fine outside hard mode
+            let result = word.play_with_config(Word::new("crane"), guesser(), &GameConfig::new());
+            assert_eq!(result.guesses[1].word, "fuzzy");
+        }
+
+        #[test]
+        #[should_panic(expected = "hard mode")]
+        fn hard_mode_panics() {
+            let word = Wordle::new();
+            let guesser = guesser!(|history| {
+                match history.len() {
+                    0 => Word::new("crate"),
+                    _ => Word::new("fuzzy"),
+                }
+            });
+            let config = GameConfig::new().hard_mode(true);
+            word.play_with_config(Word::new("crane"), guesser, &config);
+        }
+
+        #[test]
+        fn ultra_hard_forfeit() {
+            let word = Wordle::new();
+            // playing crate again keeps every green, but its t is known to be gray
+            let guesser = || guesser!(|_history| { Word::new("crate") });
+            let config = GameConfig::new()
+                .rules(Rules::UltraHard)
+                .on_invalid(Invalid::Forfeit);
+            let result = word.play_with_config(Word::new("crane"), guesser(), &config);
+            assert_eq!(result.score, None);
+            assert_eq!(result.guesses.len(), 1);
+
+            let config = config.rules(Rules::Hard).max_guesses(2);
+            let result = word.play_with_config(Word::new("crane"), guesser(), &config);
+            assert_eq!(result.guesses.len(), 2);
+            assert_eq!(
+                word.rules(Rules::UltraHard)
+                    .check_guess(&result.guesses[..1], Word::new("crate")),
+                Err(InvalidGuess::Contradicts(Word::new("crate")))
+            );
+        }
+
+        #[test]
+        fn unknown_words_suggest_the_closest() {
+            let word = Wordle::new();
+            assert_eq!(word.check(Word::new("crane")), Ok(()));
+            let invalid = word.check(Word::new("cranx")).unwrap_err();
+            let InvalidGuess::NotInDictionary { closest, .. } = &invalid else {
+                panic!("{invalid:?} should be NotInDictionary");
+            };
+            assert!(closest.contains(&Word::new("crane")));
+            assert!(invalid
+                .to_string()
+                .starts_with("cranx is not in the dictionary; did you mean "));
+
+            let nothing_close = word.check(Word::new("qqqqq")).unwrap_err();
+            assert_eq!(nothing_close.to_string(), "qqqqq is not in the dictionary");
+        }
+
+        #[test]
+        #[should_panic(expected = "did you mean")]
+        fn unknown_words_panic_with_suggestions() {
+            let word = Wordle::new();
+            word.play_game("crane", guesser!(|_history| { Word::new("cranx") }));
+        }
+
+        /// Plays `wrong`, cancelling its token after `turns` guesses.
+        struct Impatient {
+            token: Cancel,
+            turns: usize,
+        }
+
+        impl Guesser for Impatient {
+            fn guess(&mut self, history: &[Guess]) -> Word {
+                if history.len() + 1 == self.turns {
+                    self.token.cancel();
+                }
+                Word::new("wrong")
+            }
+        }
+
+        #[test]
+        fn cancelled() {
+            let word = Wordle::new();
+            let token = Cancel::new();
+            let config = GameConfig::new().cancel(token.clone());
+            let guesser = Impatient { token, turns: 2 };
+            let result = word.play_with_config(Word::new("right"), guesser, &config);
+            assert_eq!(result.score, None);
+            assert_eq!(result.guesses.len(), 2);
+        }
+
+        #[test]
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        fn time_limits() {
+            use core::time::Duration;
+
+            /// Plays `wrong`, checking the token is cancelled as it guesses.
+            struct Late;
+            impl Guesser for Late {
+                fn guess(&mut self, _history: &[Guess]) -> Word {
+                    Word::new("wrong")
+                }
+
+                fn guess_until(&mut self, history: &[Guess], cancel: &Cancel) -> Word {
+                    assert!(cancel.is_cancelled());
+                    self.guess(history)
+                }
+            }
+
+            let word = Wordle::new();
+            let config = GameConfig::new().time_limit(Duration::ZERO);
+            let result = word.play_with_config(Word::new("right"), Late, &config);
+            assert_eq!(result.score, None);
+            assert!(result.guesses.is_empty());
+
+            // a guess that runs over its own limit still counts
+            let config = GameConfig::new()
+                .max_guesses(2)
+                .guess_time_limit(Duration::ZERO);
+            let result = word.play_with_config(Word::new("right"), Late, &config);
+            assert_eq!(result.guesses.len(), 2);
+        }
+
+        #[test]
+        fn hurdle() {
+            let word = Wordle::new();
+            let answers = [Word::new("right"), Word::new("wrong"), Word::new("crane")];
+            let results = word.play_hurdle(&answers, || {
+                guesser!(|history| {
+                    if history.is_empty() {
+                        return Word::new("right");
+                    }
+                    Word::new("wrong")
+                })
+            });
+            let scores: Vec<_> = results.iter().map(|r| r.score).collect();
+            // the second game opens with `right` and solves on the guesser's first move; the
+            // third opens with `wrong` and never finds `crane`
+            assert_eq!(scores, [Some(1), Some(2), None]);
+            assert_eq!(results[2].guesses[0].word, "wrong");
+        }
+
+        #[test]
+        fn anti() {
+            let word = Wordle::new();
+            let guesser = guesser!(|history| {
+                match history.len() {
+                    0 => Word::new("grace"),
+                    _ => Word::new("crane"),
+                }
+            });
+            let result = word.play_anti(Word::new("crane"), guesser, 6);
+            assert_eq!(result.score, Some(2));
+        }
+
+        #[test]
+        #[should_panic(expected = "breaks the rules")]
+        fn anti_rules() {
+            let word = Wordle::new();
+            let guesser = guesser!(|_history| { Word::new("slate") });
+            word.play_anti(Word::new("crane"), guesser, 6);
+        }
+
         #[test]
         fn oppsie() {
             let word = Wordle::new();
-            let guesser = guesser!(|_history| { "wrong".to_string() });
+            let guesser = guesser!(|_history| { Word::new("wrong") });
 
             assert_eq!(word.play("right", guesser), None);
         }
     }
+    mod properties {
+        use alloc::string::String;
+
+        use proptest::prelude::*;
+
+        use crate::mastermind::Pegs;
+        use crate::{Correctness, Guess, Word};
+
+        /// Words over `letters`; a short alphabet means plenty of repeated letters.
+        fn words(letters: &'static str) -> impl Strategy<Value = Word> {
+            let letters: alloc::vec::Vec<char> = letters.chars().collect();
+            proptest::array::uniform5(proptest::sample::select(letters))
+                .prop_map(|letters| Word::new(&letters.iter().collect::<String>()))
+        }
+
+        fn pairs() -> impl Strategy<Value = (Word, Word)> {
+            prop_oneof![
+                (
+                    words("abcdefghijklmnopqrstuvwxyz"),
+                    words("abcdefghijklmnopqrstuvwxyz")
+                ),
+                (words("abc"), words("abc")),
+                (words("ab"), words("ab")),
+            ]
+        }
+
+        proptest! {
+            #[test]
+            fn greens_are_the_matching_positions((answer, guess) in pairs()) {
+                let mask = Correctness::compute(answer, guess);
+                let letters = answer.as_bytes().iter().zip(guess.as_bytes());
+                for (c, (a, g)) in mask.iter().zip(letters) {
+                    prop_assert_eq!(*c == Correctness::Correct, a == g);
+                }
+            }
+
+            #[test]
+            fn each_answer_letter_explains_one_tile((answer, guess) in pairs()) {
+                let mask = Correctness::compute(answer, guess);
+                for &letter in guess.as_bytes() {
+                    let count = |word: Word| word.as_bytes().iter().filter(|&&l| l == letter).count();
+                    let colored = (0..Word::LEN)
+                        .filter(|&i| guess.as_bytes()[i] == letter && mask[i] != Correctness::Wrong)
+                        .count();
+                    // as many tiles light up as the answer has copies to give, and no more
+                    prop_assert_eq!(colored, count(answer).min(count(guess)));
+                }
+            }
+
+            #[test]
+            fn a_word_is_all_green_against_itself(word in pairs().prop_map(|(w, _)| w)) {
+                prop_assert_eq!(Correctness::compute(word, word), [Correctness::Correct; 5]);
+            }
+
+            #[test]
+            fn agrees_with_matches((answer, guess) in pairs()) {
+                let guess = Guess { word: guess, mask: Correctness::compute(answer, guess) };
+                prop_assert!(guess.matches(answer));
+                // only a solved guess is consistent with itself
+                prop_assert_eq!(guess.matches(guess.word), guess.word == answer);
+            }
+
+            #[test]
+            fn pegs_are_symmetric((answer, guess) in pairs()) {
+                prop_assert_eq!(
+                    Pegs::grade(answer.as_bytes(), guess.as_bytes()),
+                    Pegs::grade(guess.as_bytes(), answer.as_bytes())
+                );
+            }
+        }
+    }
+
     mod compute {
-        use crate::Correctness;
+        use crate::{Correctness, Word};
+        use alloc::vec::Vec;
 
         macro_rules! mask {
             (C) => {
@@ -196,42 +1234,155 @@ mod tests {
 
         #[test]
         fn all_green() {
-            assert_eq!(Correctness::compute("abcde", "abcde"), mask![C C C C C])
+            assert_eq!(
+                Correctness::compute(Word::new("abcde"), Word::new("abcde")),
+                mask![C C C C C]
+            )
         }
 
         #[test]
         fn all_gray() {
-            assert_eq!(Correctness::compute("abcde", "lmnop"), mask![W W W W W])
+            assert_eq!(
+                Correctness::compute(Word::new("abcde"), Word::new("lmnop")),
+                mask![W W W W W]
+            )
         }
 
         #[test]
         fn all_yellow() {
-            assert_eq!(Correctness::compute("abcde", "cdbea"), mask![M M M M M])
+            assert_eq!(
+                Correctness::compute(Word::new("abcde"), Word::new("cdbea")),
+                mask![M M M M M]
+            )
         }
 
         #[test]
         fn repeat_green() {
-            assert_eq!(Correctness::compute("aabbb", "aaccc"), mask![C C W W W])
+            assert_eq!(
+                Correctness::compute(Word::new("aabbb"), Word::new("aaccc")),
+                mask![C C W W W]
+            )
         }
 
         #[test]
         fn repeat_yellow() {
-            assert_eq!(Correctness::compute("aabbb", "ccaac"), mask![W W M M W])
+            assert_eq!(
+                Correctness::compute(Word::new("aabbb"), Word::new("ccaac")),
+                mask![W W M M W]
+            )
         }
 
         #[test]
         fn repeat_some_green() {
-            assert_eq!(Correctness::compute("aabbb", "caacc"), mask![W C M W W])
+            assert_eq!(
+                Correctness::compute(Word::new("aabbb"), Word::new("caacc")),
+                mask![W C M W W]
+            )
         }
 
         #[test]
         fn random_1() {
-            assert_eq!(Correctness::compute("azzaz", "aaabb"), mask![C M W W W])
+            assert_eq!(
+                Correctness::compute(Word::new("azzaz"), Word::new("aaabb")),
+                mask![C M W W W]
+            )
         }
 
         #[test]
         fn random_2() {
-            assert_eq!(Correctness::compute("abcde", "aacde"), mask![C W C C C])
+            assert_eq!(
+                Correctness::compute(Word::new("abcde"), Word::new("aacde")),
+                mask![C W C C C]
+            )
+        }
+
+        #[test]
+        fn packing() {
+            assert_eq!(Correctness::pack(mask![W W W W W]), 0);
+            assert_eq!(Correctness::pack(mask![M W W W W]), 1);
+            assert_eq!(Correctness::pack(mask![C C C C C]), 242);
+            for packed in 0..243 {
+                assert_eq!(Correctness::pack(Correctness::unpack(packed)), packed);
+            }
+        }
+
+        /// The rule as a player would state it, with no bookkeeping to get wrong: a letter is
+        /// green in place, and otherwise yellow only while the answer has more copies of it
+        /// outside the greens than the guess has used up to this point.
+        #[cfg(feature = "embedded-dictionary")]
+        fn reference(answer: Word, guess: Word) -> [Correctness; 5] {
+            let (answer, guess) = (answer.as_bytes(), guess.as_bytes());
+            let green = |i: usize| answer[i] == guess[i];
+            let mut mask = [Correctness::Wrong; 5];
+            for i in 0..5 {
+                let letter = guess[i];
+                let spare = (0..5).filter(|&j| !green(j) && answer[j] == letter).count();
+                let used = (0..=i).filter(|&j| !green(j) && guess[j] == letter).count();
+                mask[i] = if green(i) {
+                    Correctness::Correct
+                } else if used <= spare {
+                    Correctness::Misplaced
+                } else {
+                    Correctness::Wrong
+                };
+            }
+            mask
+        }
+
+        #[test]
+        #[cfg(feature = "embedded-dictionary")]
+        fn matches_reference() {
+            // a sample of the whole cross product, with strides that do not line up
+            let dictionary = crate::Dictionary::embedded();
+            let words: Vec<Word> = dictionary.words().iter().map(|&(w, _)| w).collect();
+            let answers: Vec<Word> = words.iter().copied().step_by(37).collect();
+            let mut packed = Vec::new();
+            for &guess in words.iter().step_by(41) {
+                packed.clear();
+                Correctness::compute_packed(guess, &answers, &mut packed);
+                for (&answer, &packed) in answers.iter().zip(&packed) {
+                    let expected = reference(answer, guess);
+                    assert_eq!(Correctness::unpack(packed), expected, "{guess} vs {answer}");
+                    assert_eq!(Correctness::compute(answer, guess), expected);
+                }
+            }
+        }
+
+        #[test]
+        fn packed_matches_compute() {
+            // enough words to fill whole SIMD chunks and leave a remainder, with plenty of
+            // repeated letters
+            let words: Vec<Word> = [
+                "aabbb", "ccaac", "caacc", "azzaz", "aaabb", "abcde", "cdbea", "lmnop", "eerie",
+                "speed", "abide", "crane", "slate", "trace", "react", "cacao", "cocoa", "llama",
+                "mamma", "sassy", "geese", "emcee", "error", "offer", "größe", "niñas", "señal",
+                "zzzzz", "eeeee", "level", "radar", "kayak", "xylyl",
+            ]
+            .into_iter()
+            .map(Word::new)
+            .collect();
+
+            for &guess in &words {
+                let mut packed = Vec::new();
+                Correctness::compute_packed(guess, &words, &mut packed);
+                let expected: Vec<u8> = words
+                    .iter()
+                    .map(|&answer| Correctness::pack(Correctness::compute(answer, guess)))
+                    .collect();
+                assert_eq!(packed, expected, "guess {guess}");
+            }
+        }
+
+        #[test]
+        fn accents_are_distinct_letters() {
+            assert_eq!(
+                Correctness::compute(Word::new("größe"), Word::new("grose")),
+                mask![C C W W C]
+            );
+            assert_eq!(
+                Correctness::compute(Word::new("niñas"), Word::new("señal")),
+                mask![M W C C W]
+            )
         }
     }
 }
@@ -0,0 +1,115 @@
+use core::iter::Sum;
+use core::ops::Add;
+
+use crate::{Correctness, Word};
+
+/// How much a guess narrowed down the answer, against how much it was expected to.
+///
+/// Both are in bits over the answers still possible before the guess, each taken as equally
+/// likely. `expected` is the skill in picking the guess: what it would gain on average,
+/// whatever the answer. The difference is luck, so a game can be won in few guesses by
+/// good guesses, or by guesses that happened to land well.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Luck {
+    pub expected: f64,
+    pub actual: f64,
+}
+
+impl Luck {
+    /// How `guess` does against `answer` when `remaining` are the answers still possible.
+    pub fn of(guess: Word, answer: Word, remaining: &[Word]) -> Self {
+        if remaining.is_empty() {
+            return Self::default();
+        }
+        let mut buckets = [0usize; 243];
+        for &word in remaining {
+            buckets[usize::from(Correctness::pack(Correctness::compute(word, guess)))] += 1;
+        }
+        let total = remaining.len() as f64;
+        let bits = |n: usize| match n {
+            0 => 0.0,
+            n => libm::log2(total / n as f64),
+        };
+        let expected = buckets.iter().map(|&n| n as f64 / total * bits(n)).sum();
+        let mask = Correctness::compute(answer, guess);
+        Self {
+            expected,
+            actual: bits(buckets[usize::from(Correctness::pack(mask))]),
+        }
+    }
+
+    /// Bits gained beyond those expected; negative when the guess did worse than usual.
+    pub fn luck(&self) -> f64 {
+        self.actual - self.expected
+    }
+}
+
+impl Add for Luck {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            expected: self.expected + other.expected,
+            actual: self.actual + other.actual,
+        }
+    }
+}
+
+impl Sum for Luck {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::Luck;
+    use crate::Word;
+
+    fn words(words: &[&str]) -> Vec<Word> {
+        words.iter().map(|&w| Word::new(w)).collect()
+    }
+
+    #[test]
+    fn split_evenly() {
+        // every answer gives a different mask, so any guess pins it down
+        let remaining = words(&["crane", "trace", "slosh", "cramp"]);
+        let luck = Luck::of(Word::new("crane"), Word::new("slosh"), &remaining);
+        assert_eq!(luck.expected, 2.0);
+        assert_eq!(luck.actual, 2.0);
+        assert_eq!(luck.luck(), 0.0);
+    }
+
+    #[test]
+    fn lucky_and_unlucky() {
+        // `fizzy` cannot tell crane from slosh, but singles out the other two
+        let remaining = words(&["crane", "slosh", "pudgy", "fuzzy"]);
+        let guess = Word::new("fizzy");
+        let lucky = Luck::of(guess, Word::new("fuzzy"), &remaining);
+        let unlucky = Luck::of(guess, Word::new("crane"), &remaining);
+        assert!(lucky.luck() > 0.0);
+        assert!(unlucky.luck() < 0.0);
+        assert_eq!(lucky.expected, unlucky.expected);
+    }
+
+    #[test]
+    fn sums() {
+        let a = Luck {
+            expected: 1.0,
+            actual: 2.0,
+        };
+        let b = Luck {
+            expected: 3.0,
+            actual: 1.0,
+        };
+        assert_eq!(
+            [a, b].into_iter().sum::<Luck>(),
+            Luck {
+                expected: 4.0,
+                actual: 3.0
+            }
+        );
+    }
+}
@@ -1,10 +1,1119 @@
-use roget::Wordle;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
 
-const GAMES: &str = include_str!("../answers.txt");
-fn main() {
-    for answer in GAMES.split_whitespace() {
-        let word = Wordle::new();
-        let guesser = roget::algorithms::Naive::new();
-        word.play(&answer, guesser);
+use anyhow::Context;
+
+use chrono::NaiveDate;
+use clap::{Parser, Subcommand, ValueEnum};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use roget::algorithms::{Mixed, Naive, Normalization, Random};
+use roget::dictionary::Combine;
+use roget::{
+    Compliant, Dictionary, Familiar, Guess, Guesser, Language, Rules, Runtime, Session, Speed,
+    Word, Wordle,
+};
+use serde::{Deserialize, Serialize};
+use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+
+mod cli;
+
+use cli::config::Config;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Read settings from this TOML file instead of ./roget.toml; flags override it.
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// The guessing algorithm to play with [default: naive]
+    #[arg(short, long, value_enum, global = true)]
+    implementation: Option<Implementation>,
+
+    /// Stop after this many games.
+    #[arg(short = 'n', long, global = true)]
+    games: Option<usize>,
+
+    /// Play this many answers sampled at random instead of going through the list in order.
+    #[arg(long, global = true)]
+    random: Option<usize>,
+
+    /// Seed for --random, so a sample can be replayed; a fresh one is printed if omitted.
+    /// Also seeds the days `streak` simulates, which otherwise use 0.
+    #[arg(long, global = true)]
+    seed: Option<u64>,
+
+    /// How to report the results of a batch run [default: summary]
+    #[arg(long, value_enum)]
+    format: Option<cli::batch::Format>,
+
+    /// Print every guess, its mask, and how many candidates it left.
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// Save the answers still possible after each guess with every game, in --format
+    /// jsonl and --checkpoint files; this makes them much larger.
+    #[arg(long)]
+    candidates: bool,
+
+    /// Save each finished game to this file so an interrupted run can be resumed.
+    #[arg(long)]
+    checkpoint: Option<PathBuf>,
+
+    /// Skip games already saved in the --checkpoint file.
+    #[arg(long)]
+    resume: bool,
+
+    /// Write a CSV row for each game to this file as it finishes.
+    #[arg(long)]
+    out: Option<PathBuf>,
+
+    /// Log every word asked of the guesser to this file as JSON lines: the answer, the
+    /// history it was given, what it played, and how long it took.
+    #[arg(long)]
+    record: Option<PathBuf>,
+
+    /// Store each game and its guesses in this SQLite database as it finishes; `roget db`
+    /// queries it.
+    #[cfg(feature = "sqlite")]
+    #[arg(long, global = true)]
+    db: Option<PathBuf>,
+
+    /// Read answers from this file (whitespace-separated) instead of the built-in list.
+    #[arg(long, global = true)]
+    answers: Option<PathBuf>,
+
+    /// Only words from the answer list can be the answer, while any dictionary word can
+    /// still be guessed.
+    #[arg(long, global = true)]
+    restrict_answers: bool,
+
+    /// Never suggest or play as the answer the words in this file (whitespace-separated),
+    /// such as past answers or words that should not be shown, though they can still be
+    /// guessed; repeat for more files.
+    #[arg(long, global = true)]
+    exclude: Vec<PathBuf>,
+
+    /// Weigh the official answers used before today's puzzle, or the one `daily` plays, by
+    /// this much, since the game never repeats one: from 0, never the answer, to 1, as
+    /// likely as before.
+    #[arg(long, global = true, value_name = "WEIGHT")]
+    past_answers: Option<f64>,
+
+    /// When suggesting guesses for a game played by hand, skip words with more than this
+    /// percentage of the dictionary more common than them, since nobody can play a word
+    /// they do not know; e.g. 20 keeps to the most common fifth [default: 100]
+    #[arg(long, global = true, value_name = "PERCENT")]
+    max_obscurity: Option<f64>,
+
+    /// How much of the searches through every allowed guess, behind `grade` and `openers`,
+    /// to give up for time: best tries them all, good a quarter, and fast a twentieth of
+    /// those that cannot be the answer [default: best]
+    #[arg(long, global = true)]
+    speed: Option<Speed>,
+
+    /// Play perfectly once this many answers or fewer are left, from a table of every such
+    /// endgame the algorithm reaches, solved exactly and cached on first use
+    #[arg(long, global = true, value_name = "ANSWERS")]
+    endgame: Option<usize>,
+
+    /// Which guesses games allow: hard keeps green letters in place and plays yellow ones
+    /// again, and ultra-hard only allows words that could still be the answer [default:
+    /// normal]
+    #[arg(long, global = true)]
+    rules: Option<Rules>,
+
+    /// The language to play in; other than english this needs --dictionary [default: english]
+    #[arg(long, global = true)]
+    language: Option<Language>,
+
+    /// Allow guesses from this word list instead of the built-in dictionary; each line is a
+    /// word, optionally followed by its frequency.
+    #[arg(long, global = true)]
+    dictionary: Option<PathBuf>,
+
+    /// How many threads batch runs may use [default: all cores]
+    #[arg(long, global = true)]
+    threads: Option<usize>,
+
+    /// Refuse to build or load cached tables, like the pattern matrix behind `grade` and
+    /// `openers`, that take more than this many megabytes [default: no cap]
+    #[arg(long, global = true, value_name = "MB")]
+    memory: Option<usize>,
+
+    /// Show tiles as plain letters, [G]reen, (Y)ellow and gray, instead of in color; the
+    /// default when stdout is not a terminal or NO_COLOR is set.
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// Exit with an error if the average score is above this.
+    #[arg(long)]
+    max_average: Option<f64>,
+
+    /// Exit with an error if any game takes more guesses than this, or is never solved.
+    #[arg(long)]
+    max_worst: Option<usize>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Suggest guesses for a game you are playing elsewhere.
+    Assist {
+        /// Save the game to this file after every guess, and pick it up from there if the
+        /// file exists, so a game can be left and finished later; the file is removed once
+        /// the game is solved.
+        #[arg(long, value_name = "FILE")]
+        save: Option<PathBuf>,
+
+        /// Explain each suggestion: which letters it tests, how many candidates each likely
+        /// set of colors would leave, and why the next best words lost out.
+        #[arg(long)]
+        teach: bool,
+    },
+    /// Play Wordle yourself against a random answer.
+    Play {
+        /// Play this many boards at once with the same guesses, as in Quordle (4) or
+        /// Octordle (8).
+        #[arg(long, default_value_t = 1)]
+        boards: usize,
+
+        /// Play against a host that never commits to an answer, as in Absurdle.
+        #[arg(long, conflicts_with = "boards")]
+        absurdle: bool,
+
+        /// Try not to guess the answer, as in Antiwordle: every guess must fit the colors
+        /// so far, and the game ends on the answer.
+        #[arg(long, conflicts_with_all = ["boards", "absurdle"])]
+        anti: bool,
+    },
+    /// Play or get help with a game full-screen, with the keyboard, the answers still
+    /// possible, and the algorithm's suggestions on screen.
+    #[cfg(feature = "tui")]
+    Tui {
+        /// Help with a game played elsewhere, as `assist` does, instead of hosting one.
+        #[arg(long)]
+        assist: bool,
+    },
+    /// Play the algorithm against a host that dodges every guess, as in Absurdle.
+    Absurdle,
+    /// Play Antiwordle on every answer with a guesser that avoids the answer as long as it
+    /// can, and report how long it lasts.
+    Anti,
+    /// Solve random answers back to back as in Hurdle, each board opening with the last
+    /// board's answer.
+    Hurdle {
+        /// How many boards to chain.
+        #[arg(long, default_value_t = 5)]
+        boards: usize,
+    },
+    /// Solve a single word, showing every guess along the way.
+    Solve {
+        /// The word to solve.
+        #[arg(long)]
+        answer: Word,
+
+        /// Print a share grid at the end.
+        #[arg(long)]
+        share: bool,
+    },
+    /// Check whether a posted share grid could really have come from a game against an
+    /// answer, and show guesses that give it.
+    Check {
+        /// The answer the grid is meant to be for.
+        #[arg(long)]
+        answer: Word,
+
+        /// The share block as posted; read from stdin if left out.
+        #[arg(long)]
+        grid: Option<String>,
+    },
+    /// Grade a game you played, guess by guess: its skill against the best guess there
+    /// was, and its luck in the colors it got.
+    Grade {
+        /// The game's answer.
+        #[arg(long)]
+        answer: Word,
+
+        /// Your guesses, in order.
+        #[arg(required = true)]
+        guesses: Vec<Word>,
+    },
+    /// Work out the answer from share grids posted for the same puzzle, which show only
+    /// the colors, and rank the answers that fit them all by how likely each is.
+    Reverse {
+        /// Each share block as posted; read from stdin if left out, where each block
+        /// starts at its `Wordle` title line.
+        #[arg(value_name = "GRID")]
+        grids: Vec<String>,
+
+        /// How many of the likeliest answers to list.
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Time every algorithm on the same answers and compare the results.
+    Bench,
+    /// Play the same answers with several algorithms and compare them side by side.
+    Compare {
+        /// The algorithms to compare.
+        #[arg(value_enum, required = true)]
+        implementations: Vec<Implementation>,
+    },
+    /// Rank the answers from hardest to easiest by how many guesses the algorithms need.
+    Difficulty {
+        /// The algorithms to average over; defaults to every one.
+        #[arg(value_enum)]
+        implementations: Vec<Implementation>,
+
+        /// Only show this many of the hardest answers.
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Write the ranking as CSV.
+        #[arg(long)]
+        csv: bool,
+    },
+    /// Play the same answers with many algorithms, and programs speaking the engine
+    /// protocol, and rank them in a cross-table of head-to-head results.
+    ///
+    /// With --db, each pairing also counts as a match for Elo ratings kept in the database
+    /// from run to run; `roget db ratings` lists them.
+    Tournament {
+        /// The algorithms to enter; defaults to every one.
+        #[arg(value_enum)]
+        implementations: Vec<Implementation>,
+
+        /// Also enter a program that answers the commands `roget engine` does, as
+        /// NAME=COMMAND; may be repeated.
+        #[arg(long = "plugin", value_name = "NAME=COMMAND")]
+        plugins: Vec<String>,
+    },
+    /// Race two algorithms over the same answers, listing every word one of them won.
+    Race {
+        #[arg(value_enum)]
+        first: Implementation,
+        #[arg(value_enum)]
+        second: Implementation,
+    },
+    /// Play every answer and fail unless the algorithm solves each one within six guesses,
+    /// showing the games it lost.
+    Verify {
+        /// The most guesses a game may take.
+        #[arg(long, default_value_t = 6)]
+        max_guesses: usize,
+    },
+    /// Simulate playing the daily puzzle every day for years, and report how often a game
+    /// is lost, how long streaks last, and how much scores and streaks vary, not just the
+    /// average score.
+    ///
+    /// The games go like the algorithm's on the answers, or like a player's own statistics
+    /// with --distribution.
+    Streak {
+        /// How many days to play.
+        #[arg(long, default_value_t = 3650)]
+        days: usize,
+
+        /// The most guesses a game may take before it is lost.
+        #[arg(long, default_value_t = 6)]
+        max_guesses: usize,
+
+        /// A guess distribution to play instead of the algorithm: the games solved in 1 to 6
+        /// guesses then the games lost, as the statistics screen shows them, e.g.
+        /// `1,10,40,50,20,5,3`.
+        #[arg(long, value_name = "COUNTS")]
+        distribution: Option<String>,
+    },
+    /// Check every algorithm's score on each answer against the snapshots checked in under
+    /// `golden/`, so a refactor can be shown not to change how any of them plays.
+    Golden {
+        /// Where the snapshots are kept.
+        #[arg(long, default_value = "golden")]
+        dir: PathBuf,
+
+        /// Write new snapshots instead of checking against them.
+        #[arg(long)]
+        update: bool,
+    },
+    /// Play a game hosted elsewhere by a Wordle-compatible HTTP API, sending each guess as
+    /// `{"guess": "crane"}` and reading the colors back from the reply's JSON.
+    #[cfg(feature = "network")]
+    Remote {
+        /// Where to send each guess.
+        url: String,
+
+        /// Also send KEY=VALUE with every guess, such as which game it is; may be repeated.
+        #[arg(long = "param", value_name = "KEY=VALUE")]
+        params: Vec<String>,
+
+        /// The field of the reply the colors are in, in any notation `assist` reads.
+        #[arg(long, default_value = "colors")]
+        field: String,
+
+        /// The most guesses to make before giving up.
+        #[arg(long, default_value_t = 6)]
+        max_guesses: usize,
+    },
+    /// Solve the daily puzzle and print a share grid.
+    Daily {
+        /// The puzzle's date as YYYY-MM-DD; defaults to today.
+        #[arg(long)]
+        date: Option<NaiveDate>,
+    },
+    /// Take commands on stdin and answer on stdout, for programs driving the solver.
+    Engine,
+    /// Show how good one word is as an opening guess, and where it ranks among every
+    /// allowed word.
+    Opener {
+        /// The opening guess to score.
+        word: Word,
+    },
+    /// Rank every allowed word as an opening guess, by the average score of a game that
+    /// follows it by always playing the most likely answer left.
+    Openers {
+        /// How many of the best openers to show.
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+
+        /// Write the leaderboard as CSV.
+        #[arg(long)]
+        csv: bool,
+
+        /// Write every allowed word's scores to this file as CSV, ignoring --limit.
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Show how often each letter appears in each position among the answers still
+    /// possible, to help pick a guess by hand.
+    Heatmap {
+        /// The guesses so far, each a word followed by the colors it got, e.g.
+        /// `crane xyxxg slate xxgxx`; leave out to cover every answer.
+        #[arg(value_name = "WORD COLORS")]
+        guesses: Vec<String>,
+    },
+    /// Suggest a guess from the colors seen so far in a game played elsewhere, without
+    /// entering each guess.
+    Hint {
+        /// The green letters, with `.` where the letter is not known, e.g. `c..n.`.
+        #[arg(long)]
+        green: Option<String>,
+
+        /// A yellow letter followed by the positions it came back yellow at, e.g. `r2` or
+        /// `a13`; repeat for each letter, and once per copy of a repeated letter.
+        #[arg(long)]
+        yellow: Vec<String>,
+
+        /// The letters that came back gray, e.g. `tsl`.
+        #[arg(long)]
+        gray: Option<String>,
+
+        /// Only answers this regular expression matches, e.g. `^s..te$`.
+        #[arg(long)]
+        regex: Option<String>,
+
+        /// How many of the most common candidates to list.
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Compute every guess's mask against every answer once and cache it for later runs.
+    Precompute,
+    /// Answer suggestion requests over HTTP: POST /suggest and POST /score.
+    #[cfg(feature = "server")]
+    Serve {
+        /// The port to listen on, on localhost.
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+    },
+    /// Play every answer and write the algorithm's strategy as a Graphviz (DOT) graph.
+    Tree {
+        /// Follow the strategy in this file instead, checking which answers it solves; each
+        /// line is a path like `salet BBBBB1 courd BBBBB2 nymph BYBBB3 whump GGGGG4`.
+        #[arg(long)]
+        import: Option<PathBuf>,
+
+        /// Write the graph here instead of stdout.
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Answer the same requests as `serve` over gRPC, and stream batch runs; see
+    /// proto/roget.proto.
+    #[cfg(feature = "grpc")]
+    Grpc {
+        /// The port to listen on, on localhost.
+        #[arg(long, default_value_t = 50051)]
+        port: u16,
+    },
+    /// Summarize games saved with --format jsonl or --checkpoint, by algorithm.
+    Report {
+        /// JSON-lines files of games.
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+
+        /// Write a self-contained HTML page here instead, with every game.
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Query the results stored with --db.
+    #[cfg(feature = "sqlite")]
+    #[command(subcommand)]
+    Db(DbCommand),
+    /// Maintain word lists for --dictionary.
+    #[command(subcommand)]
+    Dict(DictCommand),
+    /// Manage the cache of pattern matrices, opener scores, and decision trees.
+    #[command(subcommand)]
+    Cache(CacheCommand),
+}
+
+#[derive(Subcommand, Debug)]
+enum CacheCommand {
+    /// Show where the cache is and what it holds.
+    Info,
+    /// Delete everything cached; it is computed again when next needed.
+    Clear,
+}
+
+#[cfg(feature = "sqlite")]
+#[derive(Subcommand, Debug)]
+enum DbCommand {
+    /// The average score of every algorithm over all its stored games.
+    Averages,
+    /// The answers that took the most guesses over all stored games.
+    Hardest {
+        /// How many answers to list.
+        #[arg(long, default_value_t = 10)]
+        limit: usize,
+    },
+    /// The latest Elo rating of everything `roget tournament` has rated.
+    Ratings {
+        /// Show how this algorithm's rating changed run by run instead.
+        #[arg(long, value_name = "NAME")]
+        history: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum DictCommand {
+    /// Merge word lists into one, combining the counts of words found in several.
+    Merge {
+        /// The word lists to merge.
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+
+        /// Keep the largest count of a repeated word instead of adding them up.
+        #[arg(long)]
+        max: bool,
+
+        /// Write the merged list here instead of stdout.
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Download a word list and use it instead of the built-in dictionary for --language.
+    #[cfg(feature = "network")]
+    Update {
+        /// Where to download the list from; each line is a word, optionally followed by its
+        /// frequency.
+        #[arg(long)]
+        url: String,
+    },
+}
+
+#[derive(ValueEnum, Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+enum Implementation {
+    #[default]
+    Naive,
+    /// A random candidate each turn, as a baseline to beat.
+    Random,
+    /// One of the few most likely candidates each turn, picked at random by their weights,
+    /// to explore different lines of play.
+    Mixed,
+}
+
+/// Starts a fresh guesser for each game, sharing whatever the algorithm precomputed.
+type Factory = Box<dyn Fn() -> Box<dyn Guesser> + Send + Sync>;
+
+impl Implementation {
+    /// Do the algorithm's setup for `dictionary` once, for every game played with it.
+    fn factory(self, dictionary: &Dictionary) -> Factory {
+        let priors = Normalization::default().weights(dictionary.answers());
+        Box::new(move || Box::new(self.start(&priors)))
     }
+
+    /// A guesser for one game, from prior weights computed once for the dictionary.
+    fn start(self, priors: &[(Word, f64)]) -> Algorithm {
+        match self {
+            Self::Naive => Algorithm::Naive(Naive::from_priors(priors)),
+            // every game starts from the same seed, so runs can be repeated
+            Self::Random => Algorithm::Random(Random::from_priors(priors, 0)),
+            Self::Mixed => Algorithm::Mixed(Mixed::from_priors(priors, 0)),
+        }
+    }
+}
+
+/// A game's guesser for any [`Implementation`], which unlike a boxed one can be saved along
+/// with everything it has worked out.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+enum Algorithm {
+    Naive(Naive),
+    Random(Random),
+    Mixed(Mixed),
+}
+
+impl Guesser for Algorithm {
+    fn guess(&mut self, history: &[Guess]) -> Word {
+        match self {
+            Self::Naive(guesser) => guesser.guess(history),
+            Self::Random(guesser) => guesser.guess(history),
+            Self::Mixed(guesser) => guesser.guess(history),
+        }
+    }
+
+    fn ranked(&mut self, history: &[Guess]) -> Vec<Word> {
+        match self {
+            Self::Naive(guesser) => guesser.ranked(history),
+            Self::Random(guesser) => guesser.ranked(history),
+            Self::Mixed(guesser) => guesser.ranked(history),
+        }
+    }
+}
+
+impl Args {
+    /// Fill in anything not given on the command line from `config`.
+    fn merge(&mut self, config: Config) {
+        self.implementation = self.implementation.or(config.implementation);
+        self.games = self.games.or(config.games);
+        self.random = self.random.or(config.random);
+        self.seed = self.seed.or(config.seed);
+        self.format = self.format.or(config.format);
+        self.answers = self.answers.take().or(config.answers);
+        self.restrict_answers |= config.restrict_answers.unwrap_or(false);
+        self.past_answers = self.past_answers.or(config.past_answers);
+        self.max_obscurity = self.max_obscurity.or(config.max_obscurity);
+        self.speed = self.speed.or(config.speed);
+        self.endgame = self.endgame.or(config.endgame);
+        self.rules = self.rules.or(config.rules);
+        if self.exclude.is_empty() {
+            self.exclude = config.exclude.unwrap_or_default();
+        }
+        self.language = self.language.or(config.language);
+        self.dictionary = self.dictionary.take().or(config.dictionary);
+        self.checkpoint = self.checkpoint.take().or(config.checkpoint);
+        self.out = self.out.take().or(config.out);
+        self.record = self.record.take().or(config.record);
+        #[cfg(feature = "sqlite")]
+        {
+            self.db = self.db.take().or(config.db);
+        }
+        self.threads = self.threads.or(config.threads);
+        self.memory = self.memory.or(config.memory);
+        self.max_average = self.max_average.or(config.max_average);
+        self.max_worst = self.max_worst.or(config.max_worst);
+    }
+
+    fn implementation(&self) -> Implementation {
+        self.implementation.unwrap_or(Implementation::Naive)
+    }
+
+    /// The answers a batch should play, honoring --random, --seed, and --games.
+    fn select<'a>(&self, answers: &'a str) -> Vec<&'a str> {
+        let mut answers: Vec<&str> = answers.split_whitespace().collect();
+        if let Some(n) = self.random {
+            let seed = self.seed.unwrap_or_else(|| {
+                let seed = rand::random();
+                eprintln!("sampling with --seed {seed}");
+                seed
+            });
+            let mut rng = StdRng::seed_from_u64(seed);
+            answers = answers.choose_multiple(&mut rng, n).copied().collect();
+        }
+        answers.truncate(self.games.unwrap_or(usize::MAX));
+        answers
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let mut args = Args::parse();
+    args.merge(Config::load(args.config.as_deref())?);
+    anyhow::ensure!(
+        args.random.is_some()
+            || args.seed.is_none()
+            || matches!(args.command, Some(Command::Streak { .. })),
+        "--seed only applies together with --random"
+    );
+    anyhow::ensure!(
+        args.checkpoint.is_some() || !args.resume,
+        "--resume needs a --checkpoint file"
+    );
+    let mut runtime = Runtime::new();
+    if let Some(threads) = args.threads {
+        runtime = runtime.threads(threads);
+    }
+    if let Some(megabytes) = args.memory {
+        runtime = runtime.memory(megabytes.saturating_mul(1 << 20));
+    }
+    runtime.install_global()?;
+
+    // logs go to stderr so they never mix with results; RUST_LOG=debug shows every guess
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::builder()
+                .with_default_directive(LevelFilter::WARN.into())
+                .from_env_lossy(),
+        )
+        .with_writer(std::io::stderr)
+        .with_ansi(!args.no_color)
+        .init();
+    cli::render::init(args.no_color);
+
+    if let Some(Command::Report { files, out }) = &args.command {
+        return cli::report::report(files, out.as_deref());
+    }
+
+    let language = args.language.unwrap_or_default();
+    #[cfg(feature = "sqlite")]
+    if let Some(Command::Db(command)) = &args.command {
+        let path = args.db.as_deref().context("roget db needs --db")?;
+        let conn = cli::db::open(path)?;
+        match command {
+            DbCommand::Averages => cli::db::print("algorithm", &cli::db::averages(&conn)?),
+            DbCommand::Hardest { limit } => {
+                cli::db::print("answer", &cli::db::hardest(&conn, *limit)?)
+            }
+            DbCommand::Ratings { history: None } => {
+                cli::db::print_ratings("name", &cli::db::leaderboard(&conn)?)
+            }
+            DbCommand::Ratings {
+                history: Some(name),
+            } => cli::db::print_ratings("rated", &cli::db::history(&conn, name)?),
+        }
+        return Ok(());
+    }
+
+    if let Some(Command::Cache(command)) = &args.command {
+        return match command {
+            CacheCommand::Info => cli::cache::info(),
+            CacheCommand::Clear => cli::cache::clear(),
+        };
+    }
+
+    if let Some(Command::Dict(command)) = &args.command {
+        return match command {
+            DictCommand::Merge { files, max, out } => {
+                let combine = if *max { Combine::Max } else { Combine::Sum };
+                cli::dict::merge(files, combine, out.as_deref())
+            }
+            #[cfg(feature = "network")]
+            DictCommand::Update { url } => cli::dict::update(url, language),
+        };
+    }
+
+    let load = |path: &std::path::Path| {
+        Dictionary::load(path).with_context(|| format!("loading {}", path.display()))
+    };
+    // an explicit file wins, then one installed by `dict update`, then the built-in one
+    let installed = cli::dict::installed(language).filter(|path| path.exists());
+    let dictionary = match args.dictionary.as_deref().or(installed.as_deref()) {
+        Some(path) => Arc::new(load(path)?),
+        None => language.dictionary().with_context(|| {
+            format!(
+                "there is no built-in {language} dictionary; pass one with --dictionary or \
+                 install one with `roget dict update`"
+            )
+        })?,
+    };
+    if dictionary.skipped() > 0 {
+        eprintln!(
+            "skipped {} dictionary words that are not {} letters long",
+            dictionary.skipped(),
+            Word::LEN
+        );
+    }
+    if let Some((word, _)) = dictionary
+        .words()
+        .iter()
+        .find(|&&(w, _)| !language.allows(w))
+    {
+        anyhow::bail!("\"{word}\" uses letters outside the {language} alphabet");
+    }
+
+    let mut excluded = HashSet::new();
+    for path in &args.exclude {
+        let text =
+            std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        // lists of other lengths can be used as they are, like dictionaries
+        excluded.extend(
+            text.split_whitespace()
+                .filter_map(|w| w.parse::<Word>().ok()),
+        );
+    }
+
+    let answers = match &args.answers {
+        Some(path) => std::fs::read_to_string(path)?,
+        None if language == Language::English => roget::dictionary::ANSWERS.to_string(),
+        // there is no curated answer list for other languages, so any word can come up
+        None => {
+            let words: Vec<_> = dictionary
+                .words()
+                .iter()
+                .map(|(w, _)| w.to_string())
+                .collect();
+            words.join("\n")
+        }
+    };
+
+    let mut dictionary = match args.restrict_answers {
+        false => dictionary,
+        true => {
+            let words = answers.split_whitespace().filter_map(|w| w.parse().ok());
+            Arc::new(dictionary.as_ref().clone().with_answers(words))
+        }
+    };
+    if let Some(weight) = args.past_answers {
+        anyhow::ensure!(
+            (0.0..=1.0).contains(&weight),
+            "--past-answers takes a weight from 0 to 1"
+        );
+        anyhow::ensure!(
+            language == Language::English,
+            "there are no past answers for {language}"
+        );
+        let date = match args.command {
+            Some(Command::Daily { date: Some(date) }) => date,
+            _ => chrono::Local::now().date_naive(),
+        };
+        let used = roget::dictionary::used_answers(cli::daily::number(date)?);
+        match weight == 0.0 {
+            true => excluded.extend(used),
+            false => {
+                dictionary = Arc::new(dictionary.as_ref().clone().down_weighting(used, weight))
+            }
+        }
+    }
+    // the daily puzzle is looked up by its position in the whole list
+    let listed = answers.clone();
+    let (dictionary, answers) = match excluded.is_empty() {
+        true => (dictionary, answers),
+        false => {
+            let answers: Vec<&str> = answers
+                .split_whitespace()
+                .filter(|answer| {
+                    answer
+                        .parse::<Word>()
+                        .map_or(true, |w| !excluded.contains(&w))
+                })
+                .collect();
+            let answers = answers.join("\n");
+            let dictionary = dictionary.as_ref().clone().excluding(excluded);
+            (Arc::new(dictionary), answers)
+        }
+    };
+    let rules = args.rules.unwrap_or_default();
+    let wordle = Wordle::with_dictionary(dictionary)
+        .keep_candidates(args.candidates)
+        .rules(rules);
+    let known = |answers: &str| -> anyhow::Result<()> {
+        // a guesser can only find answers its dictionary lets it guess
+        let missing = answers
+            .split_whitespace()
+            .find(|answer| answer.parse().map_or(true, |word| !wordle.contains(word)));
+        match missing {
+            Some(answer) => anyhow::bail!("answer {answer:?} is not in the dictionary"),
+            None => Ok(()),
+        }
+    };
+    let words = |answers: &str| -> Vec<Word> {
+        answers
+            .split_whitespace()
+            .map(|answer| answer.parse().expect("answers are five-letter words"))
+            .collect()
+    };
+    let mut factory = args.implementation().factory(wordle.dictionary());
+    if let Some(max) = args.endgame {
+        factory = cli::endgame::endgame(&wordle, args.implementation(), max, factory)?;
+    }
+    if rules != Rules::Normal {
+        let dictionary = Arc::clone(wordle.dictionary());
+        factory = Box::new(move || Box::new(Compliant::new(factory(), &dictionary, rules)));
+    }
+    let guesser = || factory();
+    // suggestions for people, as opposed to the guesser playing by itself
+    let max_obscurity = args.max_obscurity.unwrap_or(100.0);
+    let speed = args.speed.unwrap_or_default();
+    let suggester = || {
+        let priors = Normalization::default().weights(wordle.dictionary().answers());
+        let guesser = args.implementation().start(&priors);
+        Familiar::new(guesser, wordle.dictionary(), max_obscurity)
+    };
+
+    if !matches!(
+        args.command,
+        Some(
+            Command::Assist { .. }
+                | Command::Solve { .. }
+                | Command::Precompute
+                | Command::Opener { .. }
+                | Command::Openers { .. }
+                | Command::Heatmap { .. }
+                | Command::Hint { .. }
+                | Command::Check { .. }
+                | Command::Grade { .. }
+                | Command::Reverse { .. }
+                | Command::Engine
+                | Command::Streak {
+                    distribution: Some(_),
+                    ..
+                }
+        )
+    ) {
+        known(&answers)?;
+    }
+    match args.command {
+        Some(Command::Assist { ref save, teach }) => {
+            let saved = match save {
+                Some(path) => cli::assist::load(path)?,
+                None => None,
+            };
+            let session = saved.unwrap_or_else(|| Session::new(suggester()));
+            cli::assist::assist(&wordle, session, save.as_deref(), teach)?
+        }
+        Some(Command::Play { absurdle: true, .. }) => {
+            cli::play::play_absurdle(&wordle, &words(&answers))
+        }
+        Some(Command::Play { anti: true, .. }) => {
+            let answers: Vec<&str> = answers.split_whitespace().collect();
+            let answer = answers
+                .choose(&mut rand::thread_rng())
+                .context("there are no answers to play")?;
+            cli::play::play_anti(&wordle, answer.parse()?)
+        }
+        #[cfg(feature = "tui")]
+        Some(Command::Tui { assist }) => {
+            let answer = match assist {
+                true => None,
+                false => {
+                    let answers: Vec<&str> = answers.split_whitespace().collect();
+                    let answer = answers
+                        .choose(&mut rand::thread_rng())
+                        .context("there are no answers to play")?;
+                    Some(answer.parse()?)
+                }
+            };
+            cli::tui::tui(&wordle, answer, suggester())?
+        }
+        Some(Command::Anti) => cli::anti::anti(&wordle, &args.select(&answers)),
+        Some(Command::Absurdle) => cli::play::absurdle(&wordle, &words(&answers), guesser()),
+        Some(Command::Play { boards, .. }) => {
+            let answers: Vec<&str> = answers.split_whitespace().collect();
+            anyhow::ensure!(
+                (1..=answers.len()).contains(&boards),
+                "--boards must be between 1 and the number of answers, {}",
+                answers.len()
+            );
+            let mut chosen: Vec<Word> = answers
+                .choose_multiple(&mut rand::thread_rng(), boards)
+                .map(|answer| answer.parse().expect("answers are five-letter words"))
+                .collect();
+            match boards {
+                1 => cli::play::play(&wordle, chosen.remove(0)),
+                _ => cli::play::play_boards(&wordle, chosen),
+            }
+        }
+        Some(Command::Hurdle { boards }) => {
+            let answers: Vec<&str> = answers.split_whitespace().collect();
+            anyhow::ensure!(
+                (1..=answers.len()).contains(&boards),
+                "--boards must be between 1 and the number of answers, {}",
+                answers.len()
+            );
+            let chosen: Vec<Word> = answers
+                .choose_multiple(&mut rand::thread_rng(), boards)
+                .map(|answer| answer.parse().expect("answers are five-letter words"))
+                .collect();
+            cli::hurdle::hurdle(&wordle, &chosen, guesser)?
+        }
+        Some(Command::Solve { answer, share }) => {
+            known(&answer.to_string())?;
+            cli::solve::solve(&wordle, answer, guesser(), share)?
+        }
+        Some(Command::Grade {
+            answer,
+            ref guesses,
+        }) => cli::grade::grade(&wordle, answer, guesses, speed, &runtime)?,
+        Some(Command::Check { answer, ref grid }) => {
+            cli::check::check(&wordle, answer, grid.as_deref())?
+        }
+        Some(Command::Reverse { ref grids, limit }) => {
+            cli::reverse::reverse(&wordle, grids, limit, &runtime)?
+        }
+        Some(Command::Bench) => cli::bench::bench(&wordle, &args.select(&answers)),
+        Some(Command::Compare {
+            ref implementations,
+        }) => cli::compare::compare(&wordle, implementations, &args.select(&answers)),
+        Some(Command::Difficulty {
+            ref implementations,
+            limit,
+            csv,
+        }) => {
+            let implementations = match implementations.is_empty() {
+                true => Implementation::value_variants(),
+                false => implementations,
+            };
+            cli::difficulty::difficulty(
+                &wordle,
+                implementations,
+                &args.select(&answers),
+                limit,
+                csv,
+            )?
+        }
+        Some(Command::Tournament {
+            ref implementations,
+            ref plugins,
+        }) => {
+            let implementations = match implementations.is_empty() && plugins.is_empty() {
+                true => Implementation::value_variants(),
+                false => implementations,
+            };
+            let plugins = plugins
+                .iter()
+                .map(|spec| cli::plugin::Plugin::new(spec))
+                .collect::<anyhow::Result<_>>()?;
+            cli::tournament::tournament(
+                &wordle,
+                implementations,
+                plugins,
+                &args.select(&answers),
+                #[cfg(feature = "sqlite")]
+                args.db.as_deref(),
+            )?
+        }
+        Some(Command::Race { first, second }) => {
+            cli::race::race(&wordle, first, second, &args.select(&answers))
+        }
+        Some(Command::Verify { max_guesses }) => cli::verify::verify(
+            &wordle,
+            args.implementation(),
+            &args.select(&answers),
+            max_guesses,
+        )?,
+        Some(Command::Streak {
+            days,
+            max_guesses,
+            ref distribution,
+        }) => {
+            let (stats, name) = match distribution {
+                Some(counts) => (
+                    cli::streak::distribution(counts)?,
+                    "the distribution".into(),
+                ),
+                None => cli::streak::played(&wordle, args.implementation(), &args.select(&answers)),
+            };
+            let seed = args.seed.unwrap_or(0);
+            cli::streak::streak(&stats, &name, max_guesses, days, seed)?
+        }
+        Some(Command::Golden { ref dir, update }) => {
+            cli::golden::golden(&wordle, &args.select(&answers), dir, update)?
+        }
+        Some(Command::Daily { date }) => {
+            let date = date.unwrap_or_else(|| chrono::Local::now().date_naive());
+            let answers: Vec<&str> = listed.split_whitespace().collect();
+            cli::daily::daily(&wordle, date, &answers, guesser())?;
+        }
+        #[cfg(feature = "network")]
+        Some(Command::Remote {
+            ref url,
+            ref params,
+            ref field,
+            max_guesses,
+        }) => cli::remote::remote(&wordle, url, params, field, max_guesses, guesser())?,
+        Some(Command::Engine) => cli::engine::engine(
+            &wordle,
+            args.implementation(),
+            std::io::stdin().lock(),
+            std::io::stdout().lock(),
+        )?,
+        Some(Command::Opener { word }) => cli::openers::opener(&wordle, word, &runtime)?,
+        Some(Command::Openers {
+            limit,
+            csv,
+            ref out,
+        }) => cli::openers::openers(&wordle, limit, csv, out.as_deref(), speed, &runtime)?,
+        Some(Command::Heatmap { ref guesses }) => cli::heatmap::heatmap(&wordle, guesses)?,
+        Some(Command::Hint {
+            ref green,
+            ref yellow,
+            ref gray,
+            ref regex,
+            limit,
+        }) => cli::hint::hint(
+            &wordle,
+            green.as_deref(),
+            yellow,
+            gray.as_deref(),
+            regex.as_deref(),
+            limit,
+            |dictionary| {
+                let guesser = args.implementation().factory(dictionary)();
+                Familiar::new(guesser, wordle.dictionary(), max_obscurity)
+            },
+        )?,
+        Some(Command::Precompute) => cli::matrix::precompute(wordle.dictionary(), &runtime)?,
+        Some(Command::Tree {
+            ref import,
+            ref out,
+        }) => {
+            let answers = args.select(&answers);
+            match import {
+                Some(path) => cli::tree::import(&wordle, path, &answers, out.as_deref())?,
+                None => {
+                    let implementation = args.implementation();
+                    let name = implementation
+                        .to_possible_value()
+                        .expect("no variants are skipped");
+                    let name = name.get_name();
+                    cli::tree::export(&wordle, name, guesser, &answers, out.as_deref())?
+                }
+            }
+        }
+        #[cfg(feature = "server")]
+        Some(Command::Serve { port }) => cli::serve::serve(&wordle, factory, port)?,
+        #[cfg(feature = "grpc")]
+        Some(Command::Grpc { port }) => {
+            cli::grpc::serve(&wordle, factory, &args.select(&answers), port)?
+        }
+        Some(Command::Dict(_) | Command::Cache(_) | Command::Report { .. }) => {
+            unreachable!("handled before loading a dictionary")
+        }
+        #[cfg(feature = "sqlite")]
+        Some(Command::Db(_)) => unreachable!("handled before loading a dictionary"),
+        None => {
+            let options = cli::batch::Options {
+                algorithm: args.implementation(),
+                format: args.format.unwrap_or_default(),
+                verbose: args.verbose,
+                checkpoint: args.checkpoint.clone(),
+                resume: args.resume,
+                rules,
+                endgame: args.endgame,
+                out: args.out.clone(),
+                record: args.record.clone(),
+                #[cfg(feature = "sqlite")]
+                db: args.db.clone(),
+                thresholds: cli::batch::Thresholds {
+                    max_average: args.max_average,
+                    max_worst: args.max_worst,
+                },
+            };
+            let answers = args.select(&answers);
+            cli::batch::play(&wordle, guesser, &answers, options)?
+        }
+    }
+
+    Ok(())
 }
@@ -0,0 +1,248 @@
+use core::fmt;
+
+use crate::Correctness;
+
+/// Parse the colors Wordle showed for a guess, one character per tile, in any notation
+/// [`MaskParser::new`] accepts.
+///
+/// `g` is green, `y` is yellow, and `x` is gray, in either case — so a guess whose first
+/// tile was green and last two were yellow is `gxxyy`, which may also be written `🟩⬛⬛🟨🟨`
+/// or `20011`.
+pub fn parse_mask(s: &str) -> Result<[Correctness; 5], ParseMaskError> {
+    MaskParser::new().parse(s)
+}
+
+/// Reads the colors of a guess's tiles, one character per tile, in the notations people
+/// write them in, so every place that takes a mask accepts the same ones:
+///
+/// | | green | yellow | gray |
+/// |-|-|-|-|
+/// | emoji | 🟩 or 🟧 | 🟨 or 🟦 | ⬛ or ⬜ |
+/// | letters | `g` | `y` | `x` or `b` |
+/// | correctness letters | `c` | `m` | `w` |
+/// | digits | `2` | `1` | `0` |
+///
+/// Letters may be in either case, and notations may be mixed. The emoji include the
+/// high-contrast and light-mode squares the official game shares, and may carry the
+/// variation selector some keyboards add.
+///
+/// ```
+/// use roget::Correctness::{Correct, Misplaced, Wrong};
+/// use roget::{MaskParser, ParseMaskError};
+///
+/// let parser = MaskParser::new();
+/// let mask = [Correct, Misplaced, Wrong, Wrong, Correct];
+/// assert_eq!(parser.parse("gyxxg"), Ok(mask));
+/// assert_eq!(parser.parse("CMWBG"), Ok(mask));
+/// assert_eq!(parser.parse("21002"), Ok(mask));
+/// assert_eq!(parser.parse("🟩🟨⬛⬜🟩"), Ok(mask));
+///
+/// // the third tile starts 8 bytes in, after two emoji
+/// let error = parser.parse("🟩🟨?⬛🟩").unwrap_err();
+/// assert_eq!(error, ParseMaskError::Invalid { tile: 2, offset: 8, found: '?' });
+/// assert_eq!(MaskParser::emoji().parse("gyxxg").unwrap_err().offset(), Some(0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaskParser {
+    /// Whether letters and digits are tiles too, as well as emoji.
+    text: bool,
+}
+
+impl Default for MaskParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MaskParser {
+    /// Accept every notation.
+    pub const fn new() -> Self {
+        Self { text: true }
+    }
+
+    /// Accept only the emoji, as a share grid has them, so a line of text after the grid
+    /// is not mistaken for a row of it.
+    pub const fn emoji() -> Self {
+        Self { text: false }
+    }
+
+    /// The color `c` stands for, if it is a tile.
+    pub fn tile(&self, c: char) -> Option<Correctness> {
+        let tile = match c {
+            '🟩' | '🟧' => Correctness::Correct,
+            '🟨' | '🟦' => Correctness::Misplaced,
+            '⬛' | '⬜' => Correctness::Wrong,
+            _ if !self.text => return None,
+            'g' | 'G' | 'c' | 'C' | '2' => Correctness::Correct,
+            'y' | 'Y' | 'm' | 'M' | '1' => Correctness::Misplaced,
+            'x' | 'X' | 'b' | 'B' | 'w' | 'W' | '0' => Correctness::Wrong,
+            _ => return None,
+        };
+        Some(tile)
+    }
+
+    /// Parse the five tiles of `s`.
+    pub fn parse(&self, s: &str) -> Result<[Correctness; 5], ParseMaskError> {
+        let mut mask = [Correctness::Wrong; 5];
+        let mut tiles = 0;
+        for (offset, c) in s.char_indices() {
+            // emoji may carry a variation selector after them
+            if c == '\u{fe0f}' {
+                continue;
+            }
+            let tile = self.tile(c).ok_or(ParseMaskError::Invalid {
+                tile: tiles,
+                offset,
+                found: c,
+            })?;
+            if let Some(slot) = mask.get_mut(tiles) {
+                *slot = tile;
+            }
+            tiles += 1;
+        }
+        if tiles != 5 {
+            return Err(ParseMaskError::Length(tiles));
+        }
+        Ok(mask)
+    }
+}
+
+/// Tiles display as the emoji Wordle shares (🟩🟨⬛), or with `{:#}` as the letters
+/// [`parse_mask`] accepts.
+impl fmt::Display for Correctness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tile = match (self, f.alternate()) {
+            (Correctness::Correct, false) => "🟩",
+            (Correctness::Misplaced, false) => "🟨",
+            (Correctness::Wrong, false) => "⬛",
+            (Correctness::Correct, true) => "g",
+            (Correctness::Misplaced, true) => "y",
+            (Correctness::Wrong, true) => "x",
+        };
+        f.write_str(tile)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseMaskError {
+    /// The mask did not have exactly five tiles.
+    Length(usize),
+    /// A character is not a tile color.
+    Invalid {
+        /// Which tile it was read as, from 0.
+        tile: usize,
+        /// Where it starts in the input, in bytes.
+        offset: usize,
+        found: char,
+    },
+}
+
+impl ParseMaskError {
+    /// Where in the input the mask went wrong, in bytes, if it was at one character.
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Self::Length(_) => None,
+            Self::Invalid { offset, .. } => Some(*offset),
+        }
+    }
+}
+
+impl fmt::Display for ParseMaskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length(n) => write!(f, "expected five tiles, got {n}"),
+            Self::Invalid { tile, found, .. } => write!(
+                f,
+                "tile {} is {found:?}; use g (green), y (yellow), or x (gray), or 🟩🟨⬛",
+                tile + 1
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ParseMaskError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_mask, MaskParser, ParseMaskError};
+    use crate::Correctness::*;
+
+    #[test]
+    fn colors() {
+        assert_eq!(
+            parse_mask("gyxXG"),
+            Ok([Correct, Misplaced, Wrong, Wrong, Correct])
+        );
+    }
+
+    #[test]
+    fn display_round_trips() {
+        use alloc::string::String;
+        use core::fmt::Write;
+
+        let mask = [Correct, Misplaced, Wrong, Wrong, Correct];
+        let mut letters = String::new();
+        for c in mask {
+            write!(letters, "{c:#}").unwrap();
+        }
+        assert_eq!(letters, "gyxxg");
+        assert_eq!(parse_mask(&letters), Ok(mask));
+    }
+
+    #[test]
+    fn bad_input() {
+        assert_eq!(parse_mask("gyx"), Err(ParseMaskError::Length(3)));
+        assert_eq!(parse_mask("gyxxyy"), Err(ParseMaskError::Length(6)));
+        assert_eq!(
+            parse_mask("gyaxy"),
+            Err(ParseMaskError::Invalid {
+                tile: 2,
+                offset: 2,
+                found: 'a'
+            })
+        );
+    }
+
+    #[test]
+    fn notations() {
+        let mask = [Correct, Misplaced, Wrong, Wrong, Correct];
+        for s in [
+            "GYXXG",
+            "gybbg",
+            "cmwwc",
+            "21002",
+            "🟩🟨⬛⬜🟩",
+            "🟧🟦⬜⬜🟧",
+            "g🟨0wG",
+        ] {
+            assert_eq!(parse_mask(s), Ok(mask), "{s}");
+        }
+        assert_eq!(
+            parse_mask("🟩\u{fe0f}🟨⬛⬛🟩"),
+            Ok([Correct, Misplaced, Wrong, Wrong, Correct])
+        );
+    }
+
+    #[test]
+    fn emoji_only() {
+        let parser = MaskParser::emoji();
+        assert_eq!(parser.parse("🟩🟨⬛⬛🟩"), parse_mask("gyxxg"));
+        assert_eq!(
+            parser.parse("🟩🟨x⬛🟩"),
+            Err(ParseMaskError::Invalid {
+                tile: 2,
+                offset: 8,
+                found: 'x'
+            })
+        );
+        // the variation selector takes up bytes but no tile
+        assert_eq!(
+            parser.parse("🟩\u{fe0f}?").unwrap_err(),
+            ParseMaskError::Invalid {
+                tile: 1,
+                offset: 7,
+                found: '?'
+            }
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! The grading rule behind Wordle, for codes of any length over any alphabet.
+//!
+//! Wordle, Primel (five-digit primes) and Mastermind (pegs of a few colors) all give the same
+//! feedback: a symbol in the right place is [`Correct`](Correctness::Correct), and each other
+//! symbol is [`Misplaced`](Correctness::Misplaced) only while the answer has an unmatched
+//! copy of it left. A [`Word`](crate::Word) grades guesses by this rule as an
+//! [`Oracle`](crate::Oracle), and [`Correctness::compute_packed`] grades one guess against
+//! many answers.
+
+use crate::Correctness;
+
+/// Grade `guess` against `answer`, symbol by symbol.
+///
+/// ```
+/// use roget::mastermind::feedback;
+/// use roget::Correctness::{Correct as G, Misplaced as Y, Wrong as X};
+///
+/// // Primel: the answer 12113 against the guess 11311
+/// let mask = feedback(b"12113", b"11311");
+/// assert_eq!(mask, [G, Y, Y, G, X]);
+/// ```
+pub fn feedback<T: PartialEq, const N: usize>(answer: &[T; N], guess: &[T; N]) -> [Correctness; N] {
+    let mut c = [Correctness::Wrong; N];
+
+    // mark green
+    let mut used = [false; N];
+    for i in 0..N {
+        if answer[i] == guess[i] {
+            c[i] = Correctness::Correct;
+            used[i] = true;
+        }
+    }
+
+    // mark yellow
+    for (i, g) in guess.iter().enumerate() {
+        if c[i] == Correctness::Correct {
+            // already marked green
+            continue;
+        }
+
+        if let Some(j) = (0..N).find(|&j| answer[j] == *g && !used[j]) {
+            used[j] = true;
+            c[i] = Correctness::Misplaced;
+        }
+    }
+
+    c
+}
+
+/// Mastermind's feedback, which only says how many pegs are right and not which ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pegs {
+    /// Right color in the right place.
+    pub black: usize,
+    /// Right color in the wrong place.
+    pub white: usize,
+}
+
+impl Pegs {
+    /// Count the greens and yellows of `mask`.
+    pub fn from_mask(mask: &[Correctness]) -> Self {
+        let count = |c| mask.iter().filter(|&&m| m == c).count();
+        Self {
+            black: count(Correctness::Correct),
+            white: count(Correctness::Misplaced),
+        }
+    }
+
+    /// The pegs `guess` earns against `answer`.
+    pub fn grade<T: PartialEq, const N: usize>(answer: &[T; N], guess: &[T; N]) -> Self {
+        Self::from_mask(&feedback(answer, guess))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{feedback, Pegs};
+    use crate::Correctness::{Correct as G, Misplaced as Y, Wrong as X};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Color {
+        Red,
+        Green,
+        Blue,
+        Yellow,
+    }
+    use Color::*;
+
+    #[test]
+    fn colors() {
+        let answer = [Red, Red, Blue, Green];
+        // the guess's second red is yellow for the answer's other red
+        assert_eq!(feedback(&answer, &[Green, Red, Yellow, Red]), [Y, G, X, Y]);
+        assert_eq!(
+            Pegs::grade(&answer, &[Green, Red, Yellow, Red]),
+            Pegs { black: 1, white: 2 }
+        );
+        assert_eq!(Pegs::grade(&answer, &answer), Pegs { black: 4, white: 0 });
+    }
+
+    #[test]
+    fn any_length() {
+        assert_eq!(feedback(&[1, 2, 3], &[3, 2, 1]), [Y, G, Y]);
+        assert_eq!(feedback(&['a'; 7], &['a'; 7]), [G; 7]);
+    }
+}
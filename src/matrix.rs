@@ -0,0 +1,250 @@
+use alloc::vec::Vec;
+
+use crate::collections::Map;
+use crate::{Correctness, Dictionary, Runtime, RuntimeError, Word};
+
+/// The mask of every allowed guess against every possible answer, computed once so
+/// algorithms can look patterns up instead of grading with
+/// [`Correctness::compute_packed`] in their inner loops.
+///
+/// Each mask is stored [packed](Correctness::pack) into one byte, so the table for the
+/// embedded dictionary takes as many bytes as there are guess and answer pairs. With `std`
+/// it can be [saved](Matrix::save) and [loaded](Matrix::load) so that cost is only paid once.
+#[derive(Debug, Clone)]
+pub struct Matrix {
+    guesses: Vec<Word>,
+    answers: Vec<Word>,
+    guess_index: Map<Word, usize>,
+    answer_index: Map<Word, usize>,
+    /// Row-major: the row for guess `g` starts at `g * answers.len()`.
+    patterns: Vec<u8>,
+}
+
+impl Matrix {
+    /// Grade every word in `dictionary` against every one of its possible answers.
+    pub fn compute(dictionary: &Dictionary) -> Self {
+        let (guesses, answers) = Self::words(dictionary);
+        let mut patterns = Vec::with_capacity(guesses.len() * answers.len());
+        for &guess in &guesses {
+            Correctness::compute_packed(guess, &answers, &mut patterns);
+        }
+        Self::from_parts(guesses, answers, patterns)
+    }
+
+    /// Like [`Matrix::compute`], unless the matrix would take more memory than `runtime`
+    /// allows.
+    pub fn compute_within(
+        dictionary: &Dictionary,
+        runtime: &Runtime,
+    ) -> Result<Self, RuntimeError> {
+        runtime.check_memory(Self::size(dictionary))?;
+        Ok(Self::compute(dictionary))
+    }
+
+    /// How many bytes of masks the matrix for `dictionary` holds, which is nearly all it
+    /// takes.
+    pub fn size(dictionary: &Dictionary) -> usize {
+        dictionary.words().len() * dictionary.answers().len()
+    }
+
+    fn words(dictionary: &Dictionary) -> (Vec<Word>, Vec<Word>) {
+        let guesses = dictionary.words().iter().map(|&(w, _)| w).collect();
+        let answers = dictionary.answers().iter().map(|&(w, _)| w).collect();
+        (guesses, answers)
+    }
+
+    fn from_parts(guesses: Vec<Word>, answers: Vec<Word>, patterns: Vec<u8>) -> Self {
+        let index = |words: &[Word]| words.iter().enumerate().map(|(i, &w)| (w, i)).collect();
+        Self {
+            guess_index: index(&guesses),
+            answer_index: index(&answers),
+            guesses,
+            answers,
+            patterns,
+        }
+    }
+
+    /// The allowed guesses, in row order.
+    pub fn guesses(&self) -> &[Word] {
+        &self.guesses
+    }
+
+    /// The possible answers, in column order.
+    pub fn answers(&self) -> &[Word] {
+        &self.answers
+    }
+
+    pub fn guess_index(&self, guess: Word) -> Option<usize> {
+        self.guess_index.get(&guess).copied()
+    }
+
+    pub fn answer_index(&self, answer: Word) -> Option<usize> {
+        self.answer_index.get(&answer).copied()
+    }
+
+    /// The packed mask for the guess and answer at these indices.
+    pub fn pattern(&self, guess: usize, answer: usize) -> u8 {
+        self.row(guess)[answer]
+    }
+
+    /// The packed mask of `guess` against every answer, in column order.
+    pub fn row(&self, guess: usize) -> &[u8] {
+        let width = self.answers.len();
+        &self.patterns[guess * width..][..width]
+    }
+
+    /// The mask `guess` gets when `answer` is the answer, if both are in the matrix.
+    pub fn get(&self, guess: Word, answer: Word) -> Option<[Correctness; 5]> {
+        let pattern = self.pattern(self.guess_index(guess)?, self.answer_index(answer)?);
+        Some(Correctness::unpack(pattern))
+    }
+
+    /// How many of `answers` (by index) fall into each packed mask when `guess` is played,
+    /// which is all an entropy calculation needs.
+    pub fn partition(
+        &self,
+        guess: usize,
+        answers: impl IntoIterator<Item = usize>,
+    ) -> [usize; 243] {
+        let row = self.row(guess);
+        let mut buckets = [0; 243];
+        for answer in answers {
+            buckets[usize::from(row[answer])] += 1;
+        }
+        buckets
+    }
+
+    /// Identifies the word lists the matrix was computed for, so a saved matrix is not
+    /// loaded for a different dictionary.
+    pub fn fingerprint(dictionary: &Dictionary) -> u64 {
+        let (guesses, answers) = Self::words(dictionary);
+        fingerprint(&guesses, &answers)
+    }
+}
+
+/// Both word lists, with their lengths so the split between them counts.
+fn fingerprint(guesses: &[Word], answers: &[Word]) -> u64 {
+    let lengths = [guesses.len() as u64, answers.len() as u64];
+    fnv(lengths
+        .iter()
+        .flat_map(|n| n.to_le_bytes())
+        .chain(guesses.iter().chain(answers).flat_map(|w| *w.as_bytes())))
+}
+
+/// FNV-1a, which is stable across Rust versions and platforms, unlike the std hasher.
+pub(crate) fn fnv(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+#[cfg(feature = "std")]
+mod file {
+    use std::io::{self, Read, Write};
+    use std::path::Path;
+
+    use super::{fingerprint, Matrix};
+    use crate::Dictionary;
+
+    const MAGIC: &[u8; 8] = b"ROGETMX1";
+
+    impl Matrix {
+        /// Write the matrix to `path`: a short header naming the word lists it was computed
+        /// for, then the packed masks.
+        pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+            let mut out = io::BufWriter::new(std::fs::File::create(path)?);
+            out.write_all(MAGIC)?;
+            out.write_all(&fingerprint(&self.guesses, &self.answers).to_le_bytes())?;
+            out.write_all(&self.patterns)?;
+            out.flush()
+        }
+
+        /// Read a matrix [saved](Matrix::save) for `dictionary`.
+        ///
+        /// Fails with [`io::ErrorKind::InvalidData`] if the file is not a matrix or was
+        /// saved for different word lists, in which case it should be recomputed.
+        pub fn load(path: impl AsRef<Path>, dictionary: &Dictionary) -> io::Result<Self> {
+            let invalid = |message| io::Error::new(io::ErrorKind::InvalidData, message);
+            let mut file = std::fs::File::open(path)?;
+
+            let mut header = [0; 16];
+            file.read_exact(&mut header)?;
+            let (magic, hash) = header.split_at(8);
+            if magic != MAGIC {
+                return Err(invalid("not a roget pattern matrix"));
+            }
+            let (guesses, answers) = Self::words(dictionary);
+            if hash != fingerprint(&guesses, &answers).to_le_bytes() {
+                return Err(invalid("pattern matrix is for a different dictionary"));
+            }
+
+            let mut patterns = Vec::with_capacity(guesses.len() * answers.len());
+            file.read_to_end(&mut patterns)?;
+            if patterns.len() != guesses.len() * answers.len() {
+                return Err(invalid("pattern matrix is truncated"));
+            }
+            Ok(Self::from_parts(guesses, answers, patterns))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Matrix;
+    use crate::{Correctness, Dictionary, Word};
+
+    fn dictionary() -> Dictionary {
+        Dictionary::parse("crane\nslate\ntrace\nreact\nzzzzz\n")
+            .unwrap()
+            .with_answers([Word::new("slate"), Word::new("trace"), Word::new("react")])
+    }
+
+    #[test]
+    fn matches_compute() {
+        let dictionary = dictionary();
+        let matrix = Matrix::compute(&dictionary);
+        assert_eq!(matrix.guesses().len(), 5);
+        assert_eq!(matrix.answers().len(), 3);
+        for &(guess, _) in dictionary.words() {
+            for &(answer, _) in dictionary.answers() {
+                assert_eq!(
+                    matrix.get(guess, answer),
+                    Some(Correctness::compute(answer, guess))
+                );
+            }
+        }
+        assert_eq!(matrix.get(Word::new("slate"), Word::new("crane")), None);
+    }
+
+    #[test]
+    fn partition() {
+        let matrix = Matrix::compute(&dictionary());
+        let guess = matrix.guess_index(Word::new("zzzzz")).unwrap();
+        let buckets = matrix.partition(guess, 0..3);
+        // no answer has a z, so they all come back gray
+        assert_eq!(
+            buckets[usize::from(Correctness::pack([Correctness::Wrong; 5]))],
+            3
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn save_and_load() {
+        let dictionary = dictionary();
+        let matrix = Matrix::compute(&dictionary);
+        let path = std::env::temp_dir().join(format!("roget-matrix-{}", std::process::id()));
+        matrix.save(&path).unwrap();
+
+        let loaded = Matrix::load(&path, &dictionary).unwrap();
+        assert_eq!(loaded.patterns, matrix.patterns);
+
+        let other = Dictionary::parse("crane\nslate\n").unwrap();
+        let err = Matrix::load(&path, &other).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,133 @@
+use alloc::vec::Vec;
+
+use crate::{Correctness, Word};
+
+/// Several boards played with one stream of guesses, as in Quordle (four boards) or
+/// Octordle (eight): every guess is graded against each board not yet solved, and the game
+/// is won once all of them are.
+#[derive(Debug, Clone)]
+pub struct MultiWordle {
+    answers: Vec<Word>,
+    /// The guess (1-based) each board was solved on.
+    solved: Vec<Option<usize>>,
+    guesses: usize,
+    max_guesses: usize,
+}
+
+impl MultiWordle {
+    /// A game with a board for each of `answers`, allowing five more guesses than there are
+    /// boards like Quordle and Octordle do.
+    pub fn new(answers: Vec<Word>) -> Self {
+        Self {
+            solved: alloc::vec![None; answers.len()],
+            max_guesses: answers.len() + 5,
+            guesses: 0,
+            answers,
+        }
+    }
+
+    /// Allow this many guesses in total.
+    pub fn max_guesses(mut self, max_guesses: usize) -> Self {
+        self.max_guesses = max_guesses;
+        self
+    }
+
+    /// Grade `guess` on every board, in order. Boards solved by an earlier guess get
+    /// `None`.
+    ///
+    /// # Panics
+    ///
+    /// If the game is already over.
+    pub fn guess(&mut self, guess: Word) -> Vec<Option<[Correctness; 5]>> {
+        assert!(!self.is_over(), "no guesses left to make");
+        self.guesses += 1;
+        self.answers
+            .iter()
+            .zip(&mut self.solved)
+            .map(|(&answer, solved)| {
+                if solved.is_some() {
+                    return None;
+                }
+                let mask = Correctness::compute(answer, guess);
+                if mask == [Correctness::Correct; 5] {
+                    *solved = Some(self.guesses);
+                }
+                Some(mask)
+            })
+            .collect()
+    }
+
+    pub fn boards(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn answers(&self) -> &[Word] {
+        &self.answers
+    }
+
+    /// The guess (1-based) each board was solved on, or `None` while it is still open.
+    pub fn solved(&self) -> &[Option<usize>] {
+        &self.solved
+    }
+
+    /// How many guesses have been made.
+    pub fn guesses(&self) -> usize {
+        self.guesses
+    }
+
+    /// How many more guesses are allowed.
+    pub fn guesses_left(&self) -> usize {
+        self.max_guesses.saturating_sub(self.guesses)
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.solved.iter().all(Option::is_some)
+    }
+
+    /// Whether every board is solved or there are no guesses left.
+    pub fn is_over(&self) -> bool {
+        self.is_won() || self.guesses >= self.max_guesses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::MultiWordle;
+    use crate::{Correctness, Word};
+
+    #[test]
+    fn solves_boards_one_at_a_time() {
+        let mut game = MultiWordle::new(vec![Word::new("crane"), Word::new("trace")]);
+        assert_eq!(game.boards(), 2);
+
+        let masks = game.guess(Word::new("trace"));
+        assert_eq!(
+            masks,
+            [
+                Some(Correctness::compute(Word::new("crane"), Word::new("trace"))),
+                Some([Correctness::Correct; 5]),
+            ]
+        );
+        assert_eq!(game.solved(), [None, Some(1)]);
+        assert!(!game.is_over());
+
+        // the solved board is no longer graded
+        let masks = game.guess(Word::new("crane"));
+        assert_eq!(masks, [Some([Correctness::Correct; 5]), None]);
+        assert_eq!(game.solved(), [Some(2), Some(1)]);
+        assert!(game.is_won());
+        assert!(game.is_over());
+    }
+
+    #[test]
+    fn runs_out_of_guesses() {
+        let mut game = MultiWordle::new(vec![Word::new("crane")]).max_guesses(1);
+        game.guess(Word::new("slate"));
+        assert!(game.is_over());
+        assert!(!game.is_won());
+        assert_eq!(game.guesses(), 1);
+        assert_eq!(game.guesses_left(), 0);
+    }
+}
@@ -0,0 +1,195 @@
+use alloc::vec::Vec;
+
+use crate::{Matrix, Speed, Word};
+
+/// How good a word is as the first guess.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Opener {
+    pub word: Word,
+    /// The information the opener gains on average, in bits, over equally likely answers.
+    pub bits: f64,
+    /// The average number of guesses a whole game takes when the opener is followed by
+    /// always playing the most likely answer still possible.
+    pub expected_score: f64,
+    /// How many different masks the opener can get, so how many ways it splits the answers.
+    pub buckets: usize,
+    /// The most answers any one mask leaves possible after the opener, so how bad the
+    /// unluckiest outcome is.
+    pub largest_bucket: usize,
+}
+
+/// Score every guess in `matrix` as an opener, best first: by expected score, then by
+/// information.
+///
+/// The follow-up is the one [`Naive`](crate::algorithms::Naive) plays, so `priors` ranks the
+/// answers as it would: by weight, then alphabetically. Each of the matrix's answers counts
+/// as equally likely to be the answer. With the `parallel` feature the openers are scored
+/// across every core, or the threads of the [`Runtime`](crate::Runtime) they run in.
+///
+/// A faster `speed` only scores the answers and a share of the other guesses.
+pub fn openers(matrix: &Matrix, priors: &[(Word, f64)], speed: Speed) -> Vec<Opener> {
+    let mut ranked = priors.to_vec();
+    ranked.sort_by(|(a, x), (b, y)| y.total_cmp(x).then(a.cmp(b)));
+    let followup = Followup {
+        matrix,
+        columns: ranked
+            .iter()
+            .filter_map(|&(word, _)| matrix.answer_index(word))
+            .collect(),
+    };
+
+    let tried = |&(guess, &word): &(usize, &Word)| {
+        speed.tries(guess) || matrix.answer_index(word).is_some()
+    };
+    let score = |(guess, &word): (usize, &Word)| followup.opener(guess, word);
+    #[cfg(feature = "parallel")]
+    let mut openers: Vec<Opener> = {
+        use rayon::prelude::*;
+        let guesses = matrix.guesses().par_iter().enumerate();
+        guesses.filter(tried).map(score).collect()
+    };
+    #[cfg(not(feature = "parallel"))]
+    let mut openers: Vec<Opener> = {
+        let guesses = matrix.guesses().iter().enumerate();
+        guesses.filter(tried).map(score).collect()
+    };
+
+    openers.sort_by(|a, b| {
+        a.expected_score
+            .total_cmp(&b.expected_score)
+            .then(b.bits.total_cmp(&a.bits))
+            .then(a.word.cmp(&b.word))
+    });
+    openers
+}
+
+/// Plays the most likely answer still possible until the answer is found.
+struct Followup<'a> {
+    matrix: &'a Matrix,
+    /// The matrix column of each answer, most likely first; answers are referred to by
+    /// their position here, so a lower number is a likelier answer.
+    columns: Vec<usize>,
+}
+
+impl Followup<'_> {
+    fn opener(&self, guess: usize, word: Word) -> Opener {
+        let row = self.matrix.row(guess);
+        let n = self.columns.len();
+        let mut answers: Vec<usize> = (0..n).collect();
+        let pattern = |a: usize| row[self.columns[a]];
+        answers.sort_unstable_by_key(|&a| (pattern(a), a));
+
+        let mut bits = 0.0;
+        // every game spends a guess on the opener
+        let mut guesses = n;
+        let (mut buckets, mut largest_bucket) = (0, 0);
+        for group in answers.chunk_by_mut(|&a, &b| pattern(a) == pattern(b)) {
+            buckets += 1;
+            largest_bucket = largest_bucket.max(group.len());
+            let p = group.len() as f64 / n as f64;
+            bits -= p * libm::log2(p);
+            if self.matrix.answers()[self.columns[group[0]]] != word {
+                guesses += self.remaining(group);
+            }
+        }
+        Opener {
+            word,
+            bits,
+            expected_score: guesses as f64 / n.max(1) as f64,
+            buckets,
+            largest_bucket,
+        }
+    }
+
+    /// The guesses it takes, added up over `answers`, to find each of them; `answers` must
+    /// be in order of likelihood.
+    fn remaining(&self, answers: &mut [usize]) -> usize {
+        // every answer here spends a guess on the next one, which solves that one
+        let mut guesses = answers.len();
+        let Some((&mut next, rest)) = answers.split_first_mut() else {
+            return 0;
+        };
+        let word = self.matrix.answers()[self.columns[next]];
+        let guess = self
+            .matrix
+            .guess_index(word)
+            .expect("every answer is an allowed guess");
+        let row = self.matrix.row(guess);
+        let pattern = |a: usize| row[self.columns[a]];
+        // sorting by pattern and then likelihood keeps each group in likelihood order
+        rest.sort_unstable_by_key(|&a| (pattern(a), a));
+
+        for group in rest.chunk_by_mut(|&a, &b| pattern(a) == pattern(b)) {
+            guesses += self.remaining(group);
+        }
+        guesses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+
+    use super::openers;
+    use crate::algorithms::{Naive, Normalization};
+    use crate::{Dictionary, Matrix, Speed, Word, Wordle};
+
+    #[test]
+    fn expected_score_matches_games() {
+        let dictionary = Dictionary::parse(
+            "crane 9\ntrace 7\ncrate 5\ngrace 4\nslate 3\nplate 3\nshale 2\nwhale 1\nbrace 1",
+        )
+        .unwrap();
+        let matrix = Matrix::compute(&dictionary);
+        let priors = Normalization::default().weights(dictionary.answers());
+        let leaderboard = openers(&matrix, &priors, Speed::Best);
+        assert_eq!(leaderboard.len(), dictionary.len());
+
+        // Naive opens with the most likely answer and plays the same follow-up
+        let opener = leaderboard.iter().find(|o| o.word == "crane").unwrap();
+        let wordle = Wordle::with_dictionary(Arc::new(dictionary));
+        let total: usize = wordle
+            .dictionary()
+            .answers()
+            .iter()
+            .map(|(answer, _)| {
+                let naive = Naive::from_priors(&priors);
+                wordle.play(&answer.to_string(), naive).unwrap()
+            })
+            .sum();
+        let average = total as f64 / wordle.dictionary().answers().len() as f64;
+        assert!((opener.expected_score - average).abs() < 1e-9);
+
+        // slate, plate, shale, and whale only share crane's a and e, in the same places
+        assert_eq!(opener.largest_bucket, 4);
+        assert_eq!(opener.buckets, 4);
+
+        // best first
+        assert!(leaderboard
+            .windows(2)
+            .all(|w| w[0].expected_score <= w[1].expected_score));
+    }
+
+    #[test]
+    fn faster_speeds_keep_the_answers() {
+        let words: Vec<Word> = (b'a'..=b'z')
+            .map(|c| Word::new(&alloc::format!("{}rane", c as char)))
+            .collect();
+        let list: String = words.iter().map(|w| alloc::format!("{w}\n")).collect();
+        let dictionary = Dictionary::parse(&list).unwrap();
+        // only the first five can be the answer
+        let dictionary = dictionary.excluding(words[5..].iter().copied());
+        let matrix = Matrix::compute(&dictionary);
+        let priors = Normalization::default().weights(dictionary.answers());
+
+        let all = openers(&matrix, &priors, Speed::Best);
+        let fast = openers(&matrix, &priors, Speed::Fast);
+        assert_eq!(all.len(), 26);
+        assert!(fast.len() < all.len());
+        for word in &words[..5] {
+            assert!(fast.iter().any(|o| o.word == *word), "{word}");
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{Correctness, Word};
+
+/// The host side of a game: it knows (or decides) the answer and grades each guess.
+///
+/// A plain [`Word`] is the usual oracle, but hosts can also pick their answer lazily,
+/// show feedback to a human, or relay guesses to a game running somewhere else.
+pub trait Oracle {
+    fn grade(&mut self, guess: Word) -> [Correctness; 5];
+
+    /// Whether the oracle could not grade the last guess, such as a game elsewhere that
+    /// stopped answering. Its mask means nothing then, and the game ends unsolved.
+    fn failed(&self) -> bool {
+        false
+    }
+}
+
+impl Oracle for Word {
+    fn grade(&mut self, guess: Word) -> [Correctness; 5] {
+        Correctness::compute(*self, guess)
+    }
+}
+
+impl<O: Oracle + ?Sized> Oracle for &mut O {
+    fn grade(&mut self, guess: Word) -> [Correctness; 5] {
+        (**self).grade(guess)
+    }
+
+    fn failed(&self) -> bool {
+        (**self).failed()
+    }
+}
@@ -0,0 +1,213 @@
+//! The `roget` Python module, for running simulations and analyzing them from notebooks.
+//!
+//! ```python
+//! import roget
+//!
+//! wordle = roget.Wordle()
+//! games = wordle.play_all(["crane", "slate"])
+//! [g.score for g in games]
+//! roget.compute("crane", "trace")  # "xggyg"
+//! wordle.candidates([("which", "xxxxx")])[:5]
+//! ```
+//!
+//! Words are passed as strings and masks as the `g`/`y`/`x` letters [`parse_mask`] reads.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::algorithms::{Naive, Normalization};
+use crate::{parse_mask, Correctness, Dictionary, GameResult, Guess, Guesser, Word, Wordle};
+
+/// The algorithms [`PyWordle::play`] accepts by name.
+const ALGORITHMS: [&str; 1] = ["naive"];
+
+fn word(word: &str) -> PyResult<Word> {
+    word.parse()
+        .map_err(|e| PyValueError::new_err(format!("{word}: {e}")))
+}
+
+fn mask_letters(mask: [Correctness; 5]) -> String {
+    mask.iter().map(|c| format!("{c:#}")).collect()
+}
+
+/// A `(word, mask)` pair from Python as a guess.
+fn guess((guess, mask): (String, String)) -> PyResult<Guess> {
+    Ok(Guess {
+        word: word(&guess)?,
+        mask: parse_mask(&mask).map_err(|e| PyValueError::new_err(e.to_string()))?,
+    })
+}
+
+/// One finished game.
+#[pyclass(name = "Game", frozen, get_all)]
+struct PyGame {
+    answer: String,
+    /// `(word, mask)` for every guess, in order.
+    guesses: Vec<(String, String)>,
+    /// How many guesses it took, or `None` if the answer was not found.
+    score: Option<usize>,
+}
+
+impl From<GameResult> for PyGame {
+    fn from(result: GameResult) -> Self {
+        Self {
+            answer: result.answer.to_string(),
+            guesses: result
+                .guesses
+                .iter()
+                .map(|g| (g.word.to_string(), mask_letters(g.mask)))
+                .collect(),
+            score: result.score,
+        }
+    }
+}
+
+#[pymethods]
+impl PyGame {
+    fn __repr__(&self) -> String {
+        format!("Game(answer={:?}, score={:?})", self.answer, self.score)
+    }
+}
+
+/// A game over the embedded dictionary.
+#[pyclass(name = "Wordle", frozen)]
+struct PyWordle {
+    wordle: Wordle,
+    priors: Vec<(Word, f64)>,
+}
+
+impl PyWordle {
+    /// `answer` as a word, if it is one of the possible answers.
+    fn answer(&self, answer: &str) -> PyResult<Word> {
+        let word = word(answer)?;
+        if !self.priors.iter().any(|&(w, _)| w == word) {
+            return Err(PyValueError::new_err(format!(
+                "{answer} is not a possible answer"
+            )));
+        }
+        Ok(word)
+    }
+
+    fn guesser(&self, algorithm: &str) -> PyResult<Naive> {
+        match algorithm {
+            "naive" => Ok(Naive::from_priors(&self.priors)),
+            _ => Err(PyValueError::new_err(format!(
+                "unknown algorithm {algorithm:?}; expected one of {ALGORITHMS:?}"
+            ))),
+        }
+    }
+}
+
+#[pymethods]
+impl PyWordle {
+    /// With `restrict_answers`, only the official answers can be the answer, as in the
+    /// real game; otherwise any dictionary word can.
+    #[new]
+    #[pyo3(signature = (restrict_answers = true))]
+    fn new(restrict_answers: bool) -> Self {
+        let dictionary = if restrict_answers {
+            Dictionary::official()
+        } else {
+            Dictionary::embedded()
+        };
+        Self {
+            priors: Normalization::default().weights(dictionary.answers()),
+            wordle: Wordle::with_dictionary(dictionary),
+        }
+    }
+
+    /// Whether `word` is an allowed guess.
+    fn contains(&self, word: &str) -> PyResult<bool> {
+        Ok(self.wordle.contains(self::word(word)?))
+    }
+
+    /// Every possible answer still consistent with `history`, a list of `(word, mask)`
+    /// pairs, with how common it is.
+    #[pyo3(signature = (history = Vec::new()))]
+    fn candidates(&self, history: Vec<(String, String)>) -> PyResult<Vec<(String, usize)>> {
+        let history = history
+            .into_iter()
+            .map(guess)
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(self
+            .wordle
+            .candidates(&history)
+            .map(|(word, count)| (word.to_string(), count))
+            .collect())
+    }
+
+    /// Play one game against `answer`.
+    #[pyo3(signature = (answer, algorithm = "naive"))]
+    fn play(&self, answer: &str, algorithm: &str) -> PyResult<PyGame> {
+        let answer = self.answer(answer)?.to_string();
+        let guesser = self.guesser(algorithm)?;
+        Ok(self.wordle.play_game(&answer, guesser).into())
+    }
+
+    /// Play a game against each of `answers` on every core, in the same order.
+    #[pyo3(signature = (answers, algorithm = "naive"))]
+    fn play_all(
+        &self,
+        py: Python<'_>,
+        answers: Vec<String>,
+        algorithm: &str,
+    ) -> PyResult<Vec<PyGame>> {
+        self.guesser(algorithm)?;
+        for answer in &answers {
+            self.answer(answer)?;
+        }
+        let answers: Vec<&str> = answers.iter().map(String::as_str).collect();
+        let results = py.allow_threads(|| {
+            self.wordle
+                .play_all(&answers, || Naive::from_priors(&self.priors))
+        });
+        Ok(results.into_iter().map(PyGame::from).collect())
+    }
+}
+
+/// A guesser to drive by hand, turn by turn.
+#[pyclass(name = "Naive")]
+struct PyNaive(Naive);
+
+#[pymethods]
+impl PyNaive {
+    #[new]
+    fn new() -> Self {
+        Self(Naive::with_dictionary(&Dictionary::official()))
+    }
+
+    /// The next guess given `history`, a list of `(word, mask)` pairs. Pass the whole
+    /// history each time, as the game would.
+    fn guess(&mut self, history: Vec<(String, String)>) -> PyResult<String> {
+        let history = history
+            .into_iter()
+            .map(guess)
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(self.0.guess(&history).to_string())
+    }
+}
+
+/// The mask `guess` gets when `answer` is the answer, as `g`/`y`/`x` letters.
+#[pyfunction]
+fn compute(answer: &str, guess: &str) -> PyResult<String> {
+    Ok(mask_letters(Correctness::compute(
+        word(answer)?,
+        word(guess)?,
+    )))
+}
+
+/// The names [`PyWordle::play`] accepts for `algorithm`.
+#[pyfunction]
+fn algorithms() -> Vec<&'static str> {
+    ALGORITHMS.to_vec()
+}
+
+#[pymodule]
+fn roget(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyWordle>()?;
+    m.add_class::<PyGame>()?;
+    m.add_class::<PyNaive>()?;
+    m.add_function(wrap_pyfunction!(compute, m)?)?;
+    m.add_function(wrap_pyfunction!(algorithms, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,254 @@
+use alloc::vec::Vec;
+
+use crate::{Dictionary, Guesser, Word};
+
+/// A search for dictionary words matching letter constraints, built with
+/// [`Dictionary::query`].
+///
+/// Constraints follow Wordle's colors, including for repeated letters: every green and
+/// yellow constraint on a letter asks for one more copy of it, and a gray letter may appear
+/// no more often than that.
+///
+/// ```
+/// use roget::Dictionary;
+///
+/// let dictionary = Dictionary::parse("crane 10\ntrace 20\ncacao 1\n").unwrap();
+/// let words = dictionary.query().green(0, 'c').yellow('a').gray('t').run();
+/// assert_eq!(words[0].0, "crane");
+/// assert_eq!(words.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+#[must_use = "a query does nothing until it is run"]
+pub struct Query<'a> {
+    dictionary: &'a Dictionary,
+    green: [Option<char>; Word::LEN],
+    /// Letters the word must contain, each with a position it must not be at, if any.
+    yellow: Vec<(char, Option<usize>)>,
+    gray: Vec<char>,
+    #[cfg(feature = "regex")]
+    regex: Vec<regex::Regex>,
+}
+
+impl<'a> Query<'a> {
+    pub(crate) fn new(dictionary: &'a Dictionary) -> Self {
+        Self {
+            dictionary,
+            green: [None; Word::LEN],
+            yellow: Vec::new(),
+            gray: Vec::new(),
+            #[cfg(feature = "regex")]
+            regex: Vec::new(),
+        }
+    }
+
+    /// The letter at `position` (0-based) is `letter`.
+    ///
+    /// Panics if `position` is not within a word.
+    pub fn green(mut self, position: usize, letter: char) -> Self {
+        self.green[position] = Some(fold(letter));
+        self
+    }
+
+    /// The word contains `letter` somewhere.
+    pub fn yellow(mut self, letter: char) -> Self {
+        self.yellow.push((fold(letter), None));
+        self
+    }
+
+    /// The word contains `letter`, but not at `position`, as when a guess had it there and
+    /// it came back yellow.
+    pub fn yellow_at(mut self, position: usize, letter: char) -> Self {
+        self.yellow.push((fold(letter), Some(position)));
+        self
+    }
+
+    /// The word has no more copies of `letter` than the green and yellow constraints ask
+    /// for, which is none if there are no such constraints.
+    pub fn gray(mut self, letter: char) -> Self {
+        self.gray.push(fold(letter));
+        self
+    }
+
+    /// The word matches `regex`, say `^s..te$`, for knowledge beyond the colors. The regex
+    /// is searched for anywhere in the word, so anchor it with `^` and `$` to match the
+    /// whole word.
+    #[cfg(feature = "regex")]
+    pub fn regex(mut self, regex: regex::Regex) -> Self {
+        self.regex.push(regex);
+        self
+    }
+
+    /// Whether `word` meets every constraint.
+    pub fn matches(&self, word: Word) -> bool {
+        let letters: Vec<char> = word.letters().collect();
+        let count = |letter: char| letters.iter().filter(|&&l| l == letter).count();
+        // the copies of `letter` the green and yellow constraints ask for
+        let required = |letter: char| {
+            let green = self.green.iter().filter(|&&g| g == Some(letter)).count();
+            let yellow = self.yellow.iter().filter(|&&(y, _)| y == letter).count();
+            green + yellow
+        };
+
+        let green = self
+            .green
+            .iter()
+            .zip(&letters)
+            .all(|(g, &l)| g.is_none_or(|g| g == l));
+        let yellow = self.yellow.iter().all(|&(letter, position)| {
+            count(letter) >= required(letter) && position.is_none_or(|p| letters[p] != letter)
+        });
+        let gray = self
+            .gray
+            .iter()
+            .all(|&letter| count(letter) <= required(letter));
+        #[cfg(feature = "regex")]
+        if !self.regex.is_empty() {
+            let word = alloc::string::ToString::to_string(&word);
+            if !self.regex.iter().all(|regex| regex.is_match(&word)) {
+                return false;
+            }
+        }
+        green && yellow && gray
+    }
+
+    /// Every possible answer that matches, most common first.
+    pub fn run(&self) -> Vec<(Word, usize)> {
+        let mut words: Vec<_> = self
+            .dictionary
+            .answers()
+            .iter()
+            .copied()
+            .filter(|&(word, _)| self.matches(word))
+            .collect();
+        words.sort_by(|(a, x), (b, y)| y.cmp(x).then(a.cmp(b)));
+        words
+    }
+
+    /// The matching answers and what to play next, for a game started elsewhere where only
+    /// the colors so far are known.
+    ///
+    /// The guesser comes from `make_guesser`, given the dictionary with its answers
+    /// narrowed down to the matching ones, and is asked for an opening guess; one that
+    /// always opens with the same word will just suggest that.
+    ///
+    /// ```
+    /// use roget::algorithms::Naive;
+    /// use roget::Dictionary;
+    ///
+    /// let dictionary = Dictionary::parse("crane 10\ntrace 20\ncacao 1\n").unwrap();
+    /// let hint = dictionary.query().yellow_at(0, 'a').gray('t').hint(Naive::with_dictionary);
+    /// assert_eq!(hint.candidates.len(), 2);
+    /// assert_eq!(hint.suggestion.unwrap(), "crane");
+    /// ```
+    pub fn hint<G: Guesser>(&self, make_guesser: impl FnOnce(&Dictionary) -> G) -> Hint {
+        let candidates = self.run();
+        let suggestion = match candidates.is_empty() {
+            true => None,
+            false => {
+                let answers = candidates.iter().map(|&(word, _)| word);
+                let dictionary = self.dictionary.clone().with_answers(answers);
+                Some(make_guesser(&dictionary).guess(&[]))
+            }
+        };
+        Hint {
+            candidates,
+            suggestion,
+        }
+    }
+}
+
+/// What [`Query::hint`] found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hint {
+    /// Every possible answer that matches, most common first, as [`Query::run`] lists
+    /// them.
+    pub candidates: Vec<(Word, usize)>,
+    /// What the guesser would play next, or `None` if no answer matches.
+    pub suggestion: Option<Word>,
+}
+
+fn fold(letter: char) -> char {
+    letter.to_lowercase().next().unwrap_or(letter)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::algorithms::Naive;
+    use crate::{Dictionary, Word};
+
+    fn dictionary() -> Dictionary {
+        Dictionary::parse("crane 10\nslate 30\ntrace 20\nreact 5\ncacao 1\ncocoa 2\n").unwrap()
+    }
+
+    fn words(query: super::Query<'_>) -> Vec<Word> {
+        query.run().into_iter().map(|(w, _)| w).collect()
+    }
+
+    #[test]
+    fn by_frequency() {
+        let dictionary = dictionary();
+        assert_eq!(
+            words(dictionary.query().yellow('a').yellow('e')),
+            ["slate", "trace", "crane", "react"]
+        );
+    }
+
+    #[test]
+    fn colors() {
+        let dictionary = dictionary();
+        assert_eq!(
+            words(dictionary.query().green(0, 'C').gray('t')),
+            ["crane", "cocoa", "cacao"]
+        );
+        assert_eq!(
+            words(dictionary.query().yellow_at(0, 'r').gray('o')),
+            ["trace", "crane"]
+        );
+    }
+
+    #[test]
+    fn hint() {
+        let dictionary = dictionary();
+        let query = dictionary.query().gray('s').gray('t');
+        let hint = query.hint(Naive::with_dictionary);
+        assert_eq!(hint.candidates, query.run());
+        assert_eq!(hint.suggestion.unwrap(), "crane");
+
+        let hint = dictionary
+            .query()
+            .green(0, 'z')
+            .hint(Naive::with_dictionary);
+        assert!(hint.candidates.is_empty());
+        assert_eq!(hint.suggestion, None);
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn regex() {
+        let dictionary = dictionary();
+        let ends_in_e = regex::Regex::new("e$").unwrap();
+        assert_eq!(
+            words(dictionary.query().regex(ends_in_e.clone())),
+            ["slate", "trace", "crane"]
+        );
+        // combined with the colors
+        assert_eq!(
+            words(dictionary.query().regex(ends_in_e).gray('t')),
+            ["crane"]
+        );
+    }
+
+    #[test]
+    fn repeated_letters() {
+        let dictionary = dictionary();
+        // one c is green and the other gray: exactly one c
+        assert_eq!(words(dictionary.query().green(0, 'c').gray('c')), ["crane"]);
+        // a green c and a yellow c: at least two
+        assert_eq!(
+            words(dictionary.query().green(0, 'c').yellow('c')),
+            ["cocoa", "cacao"]
+        );
+    }
+}
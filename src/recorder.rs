@@ -0,0 +1,162 @@
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::{Cancel, Guess, Guesser, Word};
+
+/// Wraps a guesser and reports every word asked of it to a [`Sink`], so any algorithm can
+/// be instrumented without changing it.
+///
+/// ```
+/// use roget::{Call, Recorder, Word, Wordle};
+/// # use roget::{Guess, Guesser};
+/// # struct Crane;
+/// # impl Guesser for Crane {
+/// #     fn guess(&mut self, _: &[Guess]) -> Word { Word::new("crane") }
+/// # }
+///
+/// let mut calls: Vec<Call> = Vec::new();
+/// let wordle = Wordle::new();
+/// wordle.play_game("crane", Recorder::new(Crane, &mut calls));
+/// assert_eq!(calls.len(), 1);
+/// assert_eq!(calls[0].guess, "crane");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Recorder<G, S> {
+    guesser: G,
+    sink: S,
+}
+
+/// One word a [`Recorder`] saw its guesser play.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Call {
+    /// The history the guesser was given, so the guess was number `history.len() + 1`.
+    pub history: Vec<Guess>,
+    pub guess: Word,
+    /// How long the guesser took, or `None` where there is no clock: without the `std`
+    /// feature, and on WebAssembly.
+    pub elapsed: Option<Duration>,
+}
+
+/// Where a [`Recorder`] sends its calls.
+///
+/// A `Vec<Call>` keeps them; pass `&mut` one to read them after the game. With the
+/// `tracing` feature, [`Events`] logs them instead.
+pub trait Sink {
+    fn record(&mut self, call: Call);
+}
+
+impl Sink for Vec<Call> {
+    fn record(&mut self, call: Call) {
+        self.push(call);
+    }
+}
+
+impl<S: Sink + ?Sized> Sink for &mut S {
+    fn record(&mut self, call: Call) {
+        (**self).record(call);
+    }
+}
+
+/// A [`Sink`] that logs each call as a `tracing` event at debug level.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Events;
+
+#[cfg(feature = "tracing")]
+impl Sink for Events {
+    fn record(&mut self, call: Call) {
+        tracing::debug!(
+            turn = call.history.len() + 1,
+            guess = %call.guess,
+            elapsed = ?call.elapsed,
+            "guesser called"
+        );
+    }
+}
+
+impl<G, S> Recorder<G, S> {
+    pub fn new(guesser: G, sink: S) -> Self {
+        Self { guesser, sink }
+    }
+
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    pub fn into_inner(self) -> (G, S) {
+        (self.guesser, self.sink)
+    }
+}
+
+impl<G: Guesser, S: Sink> Recorder<G, S> {
+    fn record(&mut self, history: &[Guess], guess: impl FnOnce(&mut G) -> Word) -> Word {
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        let (word, elapsed) = {
+            let start = std::time::Instant::now();
+            let word = guess(&mut self.guesser);
+            (word, Some(start.elapsed()))
+        };
+        #[cfg(not(all(feature = "std", not(target_family = "wasm"))))]
+        let (word, elapsed) = (guess(&mut self.guesser), None);
+        self.sink.record(Call {
+            history: history.to_vec(),
+            guess: word,
+            elapsed,
+        });
+        word
+    }
+}
+
+impl<G: Guesser, S: Sink> Guesser for Recorder<G, S> {
+    fn guess(&mut self, history: &[Guess]) -> Word {
+        self.record(history, |guesser| guesser.guess(history))
+    }
+
+    fn guess_until(&mut self, history: &[Guess], cancel: &Cancel) -> Word {
+        self.record(history, |guesser| guesser.guess_until(history, cancel))
+    }
+
+    fn ranked(&mut self, history: &[Guess]) -> Vec<Word> {
+        let mut ranked = Vec::new();
+        self.record(history, |guesser| {
+            ranked = guesser.ranked(history);
+            ranked[0]
+        });
+        ranked
+    }
+}
+
+#[cfg(all(test, feature = "embedded-dictionary"))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{Call, Recorder};
+    use crate::{Guess, Guesser, Word, Wordle};
+
+    /// Opens with `crane`, then plays `right`.
+    struct Right;
+
+    impl Guesser for Right {
+        fn guess(&mut self, history: &[Guess]) -> Word {
+            match history.len() {
+                0 => Word::new("crane"),
+                _ => Word::new("right"),
+            }
+        }
+    }
+
+    #[test]
+    fn records_every_call() {
+        let mut calls: Vec<Call> = Vec::new();
+        let wordle = Wordle::new();
+        let result = wordle.play_game("right", Recorder::new(Right, &mut calls));
+        assert_eq!(calls.len(), 2);
+        assert!(calls[0].history.is_empty());
+        assert_eq!(calls[1].history, result.guesses[..1]);
+        let played: Vec<Word> = calls.iter().map(|call| call.guess).collect();
+        assert_eq!(played, ["crane", "right"]);
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        assert!(calls.iter().all(|call| call.elapsed.is_some()));
+    }
+}
@@ -0,0 +1,232 @@
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Correctness, MaskParser, Oracle, ParseMaskError, Word};
+
+/// A game hosted by a Wordle-compatible HTTP API, so a guesser can play live against it
+/// with [`Wordle::play_against`](crate::Wordle::play_against).
+///
+/// Each guess is sent as `POST <url>` with a JSON body `{"guess": "crane"}`, plus whatever
+/// [`param`](Remote::param)s the host needs, such as which game it is. The reply is a JSON
+/// object whose `colors` field, or another [`field`](Remote::field), holds the tiles in any
+/// notation [`MaskParser`] reads, like `"gyxxx"`, `"🟩🟨⬛⬛⬛"`, or `"21000"`.
+///
+/// If a request fails the remote reports it through [`Oracle::failed`], so the game ends
+/// there unsolved and no more guesses are sent; [`Remote::error`] says why.
+///
+/// ```no_run
+/// use roget::algorithms::Naive;
+/// use roget::{Remote, Wordle};
+///
+/// let mut host = Remote::new("https://wordle.example/guess").param("game", "1234");
+/// let score = Wordle::new().play_against(&mut host, Naive::new(), 6);
+/// match host.error() {
+///     Some(e) => eprintln!("the game broke off: {e}"),
+///     None => println!("{score:?}"),
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Remote {
+    url: String,
+    params: Vec<(String, String)>,
+    field: String,
+    error: Option<RemoteError>,
+}
+
+impl Remote {
+    /// Play against the API at `url`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            params: Vec::new(),
+            field: "colors".to_string(),
+            error: None,
+        }
+    }
+
+    /// Send `key` with `value` in every request body alongside the guess.
+    pub fn param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.push((key.into(), value.into()));
+        self
+    }
+
+    /// Read the tiles from this field of the reply instead of `colors`.
+    pub fn field(mut self, field: impl Into<String>) -> Self {
+        self.field = field.into();
+        self
+    }
+
+    /// Why the game broke off, if a request failed.
+    pub fn error(&self) -> Option<&RemoteError> {
+        self.error.as_ref()
+    }
+
+    /// Send `guess` and read back its tiles.
+    fn send(&self, guess: Word) -> Result<[Correctness; 5], RemoteError> {
+        let mut body = serde_json::Map::new();
+        for (key, value) in &self.params {
+            body.insert(key.clone(), value.clone().into());
+        }
+        body.insert("guess".to_string(), guess.to_string().into());
+
+        let reply = ureq::post(&self.url)
+            .set("Content-Type", "application/json")
+            .send_string(&serde_json::Value::Object(body).to_string())
+            .map_err(|e| RemoteError::Request(Box::new(e)))?
+            .into_string()
+            .map_err(|e| RemoteError::Reply(e.to_string()))?;
+        let reply: serde_json::Value =
+            serde_json::from_str(&reply).map_err(|e| RemoteError::Reply(e.to_string()))?;
+        let colors = reply
+            .get(&self.field)
+            .and_then(|colors| colors.as_str())
+            .ok_or_else(|| RemoteError::Reply(alloc::format!("no {:?} string", self.field)))?;
+        MaskParser::new().parse(colors).map_err(RemoteError::Colors)
+    }
+}
+
+impl Oracle for Remote {
+    fn grade(&mut self, guess: Word) -> [Correctness; 5] {
+        if self.error.is_some() {
+            return [Correctness::Wrong; 5];
+        }
+        match self.send(guess) {
+            Ok(mask) => mask,
+            Err(e) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(%guess, "remote game broke off: {e}");
+                self.error = Some(e);
+                // not a win, for callers that never ask whether it failed
+                [Correctness::Wrong; 5]
+            }
+        }
+    }
+
+    fn failed(&self) -> bool {
+        self.error.is_some()
+    }
+}
+
+/// Why a [`Remote`] game broke off.
+#[derive(Debug)]
+pub enum RemoteError {
+    /// The request could not be sent, or the host turned it down.
+    Request(Box<ureq::Error>),
+    /// The reply is not a JSON object with the tiles in it.
+    Reply(String),
+    /// The tiles in the reply are not five colors.
+    Colors(ParseMaskError),
+}
+
+impl fmt::Display for RemoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Request(e) => write!(f, "request failed: {e}"),
+            Self::Reply(e) => write!(f, "unexpected reply: {e}"),
+            Self::Colors(e) => write!(f, "unexpected colors: {e}"),
+        }
+    }
+}
+
+impl core::error::Error for RemoteError {}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    use super::{Remote, RemoteError};
+    use crate::algorithms::Naive;
+    use crate::{Correctness, Oracle, Word};
+
+    /// Answer `requests` guesses on a local port as a host with `answer` would, giving the
+    /// tiles in `field`, and return the URL and every request body.
+    fn host(
+        answer: Word,
+        field: &'static str,
+        requests: usize,
+    ) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/guess", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut bodies = Vec::new();
+            for stream in listener.incoming().take(requests) {
+                let mut reader = BufReader::new(stream.unwrap());
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim().to_ascii_lowercase();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some(n) = line.strip_prefix("content-length:") {
+                        length = n.trim().parse().unwrap();
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let body = String::from_utf8(body).unwrap();
+                let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+                let guess: Word = json["guess"].as_str().unwrap().parse().unwrap();
+                let mask: String = Correctness::compute(answer, guess)
+                    .iter()
+                    .map(|c| format!("{c}"))
+                    .collect();
+                let reply = format!("{{\"{field}\": \"{mask}\"}}");
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{reply}",
+                    reply.len()
+                )
+                .unwrap();
+                bodies.push(body);
+            }
+            bodies
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn plays_against_a_host() {
+        let (url, host) = host(Word::new("crane"), "result", 2);
+        let mut remote = Remote::new(url).param("game", "7").field("result");
+        assert_eq!(
+            remote.grade(Word::new("trace")),
+            Correctness::compute(Word::new("crane"), Word::new("trace"))
+        );
+        assert_eq!(remote.grade(Word::new("crane")), [Correctness::Correct; 5]);
+        assert!(remote.error().is_none());
+
+        let bodies = host.join().unwrap();
+        let first: serde_json::Value = serde_json::from_str(&bodies[0]).unwrap();
+        assert_eq!(first["guess"], "trace");
+        assert_eq!(first["game"], "7");
+    }
+
+    #[test]
+    fn breaks_off_on_a_bad_reply() {
+        // the host answers under a different field than expected
+        let (url, host) = host(Word::new("crane"), "colours", 1);
+        let mut remote = Remote::new(url);
+        assert_ne!(remote.grade(Word::new("trace")), [Correctness::Correct; 5]);
+        assert!(remote.failed());
+        assert!(matches!(remote.error(), Some(RemoteError::Reply(_))));
+        host.join().unwrap();
+        // nothing more is sent once it broke off
+        assert_ne!(remote.grade(Word::new("slate")), [Correctness::Correct; 5]);
+    }
+
+    #[test]
+    fn a_broken_game_is_not_won() {
+        let (url, host) = host(Word::new("crane"), "colours", 1);
+        let mut remote = Remote::new(url);
+        let score = crate::Wordle::new().play_against(&mut remote, Naive::new(), 6);
+        assert_eq!(score, None);
+        assert!(remote.error().is_some());
+        host.join().unwrap();
+    }
+}
@@ -0,0 +1,126 @@
+use alloc::vec::Vec;
+
+use crate::{Correctness, Matrix, SharedGame, Word};
+
+/// Work out which answer share grids posted for the same puzzle were for, from their masks
+/// alone: every answer of `matrix` that some allowed guess could have given each row of
+/// each grid for, with how likely it is to be the answer, most likely first.
+///
+/// The chances start from `priors`, in the same order as the matrix's answers as
+/// [`Normalization::weights`](crate::algorithms::Normalization::weights) gives them, and
+/// are updated for each row by the share of allowed guesses that give its mask against
+/// the answer, as if the player had picked their guess at random. They add up to 1.
+///
+/// Rows are checked one at a time, so a hard mode grid whose rows each fit an answer may
+/// still need no game to be able to play them in order; see [`SharedGame::replay`].
+///
+/// ```
+/// use roget::algorithms::Normalization;
+/// use roget::{infer_answers, Dictionary, Matrix, SharedGame};
+///
+/// let dictionary = Dictionary::parse("crane\nslate\nlousy\n").unwrap();
+/// let matrix = Matrix::compute(&dictionary);
+/// let priors = Normalization::default().weights(dictionary.answers());
+/// // crane and lousy share no letters, but slate shares some with both
+/// let grid: SharedGame = "Wordle 2/6\n⬛⬛⬛⬛⬛\n🟩🟩🟩🟩🟩".parse().unwrap();
+/// let answers = infer_answers(&matrix, &priors, &[grid]);
+/// assert_eq!(answers.len(), 2);
+/// assert!(answers.iter().all(|(word, _)| *word != "slate"));
+/// ```
+pub fn infer_answers(
+    matrix: &Matrix,
+    priors: &[(Word, f64)],
+    grids: &[SharedGame],
+) -> Vec<(Word, f64)> {
+    let mut wanted: Vec<u8> = grids
+        .iter()
+        .flat_map(|grid| grid.masks.iter().map(|&mask| Correctness::pack(mask)))
+        .collect();
+    wanted.sort_unstable();
+    wanted.dedup();
+
+    // how many guesses give each wanted mask against each answer
+    let answers = matrix.answers().len();
+    let mut counts = alloc::vec![0usize; answers * wanted.len()];
+    for guess in 0..matrix.guesses().len() {
+        for (answer, pattern) in matrix.row(guess).iter().enumerate() {
+            if let Ok(i) = wanted.binary_search(pattern) {
+                counts[answer * wanted.len() + i] += 1;
+            }
+        }
+    }
+
+    let guesses = matrix.guesses().len() as f64;
+    let mut likely: Vec<(Word, f64)> = Vec::new();
+    for (answer, &(word, prior)) in priors.iter().enumerate().take(answers) {
+        let mut log = libm::log(prior);
+        for grid in grids {
+            for &mask in &grid.masks {
+                let i = wanted
+                    .binary_search(&Correctness::pack(mask))
+                    .expect("every mask is wanted");
+                log += libm::log(counts[answer * wanted.len() + i] as f64 / guesses);
+            }
+        }
+        if log > f64::NEG_INFINITY {
+            likely.push((word, log));
+        }
+    }
+
+    // scale by the likeliest before leaving logs, so tiny chances do not all round to 0
+    let top = likely
+        .iter()
+        .map(|&(_, log)| log)
+        .fold(f64::NEG_INFINITY, f64::max);
+    for (_, log) in &mut likely {
+        *log = libm::exp(*log - top);
+    }
+    let total: f64 = likely.iter().map(|&(_, p)| p).sum();
+    for (_, p) in &mut likely {
+        *p /= total;
+    }
+    likely.sort_by(|(a, x), (b, y)| y.total_cmp(x).then(a.cmp(b)));
+    likely
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::infer_answers;
+    use crate::algorithms::Normalization;
+    use crate::{Dictionary, Matrix, SharedGame, Word};
+
+    #[test]
+    fn consistent_answers_only() {
+        let dictionary =
+            Dictionary::parse("crane 10\nslate 10\ntrace 10\nreact 1\nlousy 10\n").unwrap();
+        let matrix = Matrix::compute(&dictionary);
+        let priors = Normalization::default().weights(dictionary.answers());
+        let infer = |grids: &[&str]| -> Vec<(Word, f64)> {
+            let grids: Vec<SharedGame> = grids.iter().map(|g| g.parse().unwrap()).collect();
+            infer_answers(&matrix, &priors, &grids)
+        };
+        let words = |answers: &[(Word, f64)]| -> Vec<Word> {
+            answers.iter().map(|&(word, _)| word).collect()
+        };
+
+        // every other word shares a letter with slate, and lousy is gray against three
+        let gray = "Wordle 2/6\n⬛⬛⬛⬛⬛\n🟩🟩🟩🟩🟩";
+        let answers = infer(&[gray]);
+        assert_eq!(words(&answers)[0], "lousy");
+        assert!(!words(&answers).contains(&Word::new("slate")));
+        let total: f64 = answers.iter().map(|&(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        // trace and react are the only pair that are this mask against each other
+        let anagram = "Wordle 2/6\n🟨🟨🟩🟩🟨\n🟩🟩🟩🟩🟩";
+        let answers = infer(&[gray, anagram]);
+        assert_eq!(words(&answers), ["trace", "react"]);
+        // as consistent as each other, but trace is the more common
+        assert!(answers[0].1 > 0.9);
+
+        let crane = "Wordle 2/6\n⬛🟩🟩🟨🟩\n🟩🟩🟩🟩🟩";
+        assert!(infer(&[crane, anagram]).is_empty());
+    }
+}
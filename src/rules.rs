@@ -0,0 +1,144 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::config::uses_hints;
+use crate::{Guess, InvalidGuess, Word};
+
+/// Which guesses a game allows, given what the guesses before them revealed.
+///
+/// ```
+/// use roget::{parse_mask, Guess, Rules, Word};
+///
+/// let history = [Guess {
+///     word: Word::new("crane"),
+///     mask: parse_mask("xyxxx").unwrap(),
+/// }];
+/// // plays the yellow r again, but where it was yellow, and with the gray c
+/// assert!(Rules::Hard.allows(&history, Word::new("crust")));
+/// assert!(!Rules::UltraHard.allows(&history, Word::new("crust")));
+/// assert!(Rules::UltraHard.allows(&history, Word::new("story")));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Rules {
+    /// Any word in the dictionary.
+    #[default]
+    Normal,
+    /// Wordle's hard mode: green letters stay where they are and yellow letters are played
+    /// again.
+    Hard,
+    /// Every guess could still be the answer: besides the hard mode rules, letters shown
+    /// gray are not played again beyond the copies hinted, and yellow letters move away
+    /// from where they were yellow.
+    UltraHard,
+}
+
+impl Rules {
+    pub const ALL: [Self; 3] = [Self::Normal, Self::Hard, Self::UltraHard];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::Hard => "hard",
+            Self::UltraHard => "ultra-hard",
+        }
+    }
+
+    /// Whether `word` may be played after `history`, if it is in the dictionary.
+    pub fn allows(self, history: &[Guess], word: Word) -> bool {
+        self.check(history, word).is_ok()
+    }
+
+    /// Like [`Rules::allows`], with the error a game rejects the word with if not.
+    pub fn check(self, history: &[Guess], word: Word) -> Result<(), InvalidGuess> {
+        if self != Self::Normal && !uses_hints(history, word) {
+            return Err(InvalidGuess::IgnoresHint(word));
+        }
+        // what each mask says is exactly that the answer gets it, so a word that could
+        // still be the answer goes against none of it
+        if self == Self::UltraHard && !history.iter().all(|guess| guess.matches(word)) {
+            return Err(InvalidGuess::Contradicts(word));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Rules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Rules {
+    type Err = UnknownRules;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|rules| rules.name().eq_ignore_ascii_case(s))
+            .ok_or(UnknownRules)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownRules;
+
+impl fmt::Display for UnknownRules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected one of normal, hard, or ultra-hard")
+    }
+}
+
+impl core::error::Error for UnknownRules {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::Rules;
+    use crate::{parse_mask, Guess, InvalidGuess, Word};
+
+    #[test]
+    fn names() {
+        for rules in Rules::ALL {
+            assert_eq!(rules.to_string().parse(), Ok(rules));
+        }
+        assert!("nightmare".parse::<Rules>().is_err());
+    }
+
+    #[test]
+    fn ultra_hard() {
+        let history = [Guess {
+            word: Word::new("geese"),
+            mask: parse_mask("xyxxg").unwrap(),
+        }];
+        let check = |word| Rules::UltraHard.check(&history, Word::new(word));
+        assert_eq!(check("elope"), Ok(()));
+        // only two e's were hinted, and the gray one says there are no more
+        assert_eq!(
+            check("eerie"),
+            Err(InvalidGuess::Contradicts(Word::new("eerie")))
+        );
+        // the yellow e is back where it was yellow
+        assert_eq!(
+            check("leave"),
+            Err(InvalidGuess::Contradicts(Word::new("leave")))
+        );
+        // the g was gray
+        assert_eq!(
+            check("eagle"),
+            Err(InvalidGuess::Contradicts(Word::new("eagle")))
+        );
+        // the green e moved, which not even hard mode allows
+        assert_eq!(
+            check("never"),
+            Err(InvalidGuess::IgnoresHint(Word::new("never")))
+        );
+
+        for word in ["eerie", "leave", "eagle"] {
+            assert!(Rules::Hard.allows(&history, Word::new(word)));
+        }
+        assert!(Rules::Normal.allows(&history, Word::new("fuzzy")));
+    }
+}
@@ -0,0 +1,170 @@
+use core::fmt;
+
+/// How much of the machine the solver may take: how many threads its parallel work runs on,
+/// and how much memory its caches, like a [`Matrix`](crate::Matrix), may hold.
+///
+/// By default there are no limits, and parallel work such as [`Wordle::play_all`],
+/// [`openers`](crate::openers()) and [`grade`](crate::grade()) spreads across every core.
+/// Inside a server or on a shared machine, [`install`](Runtime::install) a runtime with
+/// fewer threads around that work instead, or [`install_global`](Runtime::install_global)
+/// one for the whole process. Guessers that search in parallel run on the same threads as
+/// the games that call them.
+///
+/// [`Wordle::play_all`]: crate::Wordle::play_all
+///
+/// ```
+/// use roget::{Dictionary, Matrix, Runtime};
+///
+/// let dictionary = Dictionary::parse("crane\ntrace\nslate\n").unwrap();
+/// let runtime = Runtime::new().threads(2).memory(1 << 20);
+/// assert_eq!(runtime.max_threads(), 2);
+/// let matrix = Matrix::compute_within(&dictionary, &runtime).unwrap();
+/// assert_eq!(matrix.guesses().len(), 3);
+/// assert!(Matrix::compute_within(&dictionary, &Runtime::new().memory(4)).is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Runtime {
+    threads: Option<usize>,
+    memory: Option<usize>,
+    /// Built on first use, and shared between clones.
+    #[cfg(feature = "parallel")]
+    pool: std::sync::Arc<std::sync::OnceLock<rayon::ThreadPool>>,
+}
+
+impl Runtime {
+    /// No limits: every core, and as much memory as caches need.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run parallel work on `threads` threads; at least one always is.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = Some(threads.max(1));
+        #[cfg(feature = "parallel")]
+        {
+            self.pool = Default::default();
+        }
+        self
+    }
+
+    /// Refuse to build caches that take more than `bytes`.
+    pub fn memory(mut self, bytes: usize) -> Self {
+        self.memory = Some(bytes);
+        self
+    }
+
+    /// How many threads parallel work runs on. Without the `parallel` feature there is
+    /// only ever the caller's.
+    pub fn max_threads(&self) -> usize {
+        #[cfg(feature = "parallel")]
+        return self.threads.unwrap_or_else(rayon::current_num_threads);
+        #[cfg(not(feature = "parallel"))]
+        1
+    }
+
+    /// The most memory a cache may take, in bytes, if there is a cap.
+    pub fn max_memory(&self) -> Option<usize> {
+        self.memory
+    }
+
+    /// Whether a cache taking `bytes` fits under the memory cap.
+    pub fn check_memory(&self, bytes: usize) -> Result<(), RuntimeError> {
+        match self.memory {
+            Some(cap) if bytes > cap => Err(RuntimeError::Memory { needed: bytes, cap }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Run `op`, with any parallel work inside it, such as [`Wordle::play_all`], on this
+    /// runtime's threads. Without a thread count it runs on the pool it is called from.
+    ///
+    /// [`Wordle::play_all`]: crate::Wordle::play_all
+    #[cfg(feature = "parallel")]
+    pub fn install<R: Send>(&self, op: impl FnOnce() -> R + Send) -> Result<R, RuntimeError> {
+        let Some(threads) = self.threads else {
+            return Ok(op());
+        };
+        let pool = match self.pool.get() {
+            Some(pool) => pool,
+            None => {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .map_err(RuntimeError::Pool)?;
+                // another clone may have got there first, in which case this one is dropped
+                self.pool.get_or_init(|| pool)
+            }
+        };
+        Ok(pool.install(op))
+    }
+
+    /// Run all parallel work in the process on this runtime's threads, unless it is called
+    /// from inside [`install`](Runtime::install). It can only be done once, before any
+    /// parallel work; without a thread count it does nothing.
+    #[cfg(feature = "parallel")]
+    pub fn install_global(&self) -> Result<(), RuntimeError> {
+        let Some(threads) = self.threads else {
+            return Ok(());
+        };
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .map_err(RuntimeError::Pool)
+    }
+}
+
+/// Why a [`Runtime`] could not do what was asked of it.
+#[derive(Debug)]
+pub enum RuntimeError {
+    /// A cache would take `needed` bytes, more than the `cap`.
+    Memory { needed: usize, cap: usize },
+    /// The threads could not be started, or the global ones already were.
+    #[cfg(feature = "parallel")]
+    Pool(rayon::ThreadPoolBuildError),
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Memory { needed, cap } => {
+                write!(f, "needs {needed} bytes, more than the cap of {cap}")
+            }
+            #[cfg(feature = "parallel")]
+            Self::Pool(e) => write!(f, "cannot start the threads: {e}"),
+        }
+    }
+}
+
+impl core::error::Error for RuntimeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Runtime, RuntimeError};
+
+    #[test]
+    fn memory() {
+        let runtime = Runtime::new().memory(100);
+        assert!(runtime.check_memory(100).is_ok());
+        assert!(matches!(
+            runtime.check_memory(101),
+            Err(RuntimeError::Memory {
+                needed: 101,
+                cap: 100
+            })
+        ));
+        assert!(Runtime::new().check_memory(usize::MAX).is_ok());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn installs_its_threads() {
+        let runtime = Runtime::new().threads(3);
+        let threads = runtime.install(rayon::current_num_threads).unwrap();
+        assert_eq!(threads, 3);
+        // clones share the threads
+        let clone = runtime.clone();
+        let index = clone.install(|| rayon::current_thread_index().is_some());
+        assert!(index.unwrap());
+        assert_eq!(Runtime::new().threads(0).max_threads(), 1);
+    }
+}
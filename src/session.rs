@@ -0,0 +1,102 @@
+use alloc::vec::Vec;
+
+use crate::{Correctness, Guess, Guesser, Word};
+
+/// A game in progress against a puzzle played elsewhere: the guesser helping with it, and
+/// the guesses made so far.
+///
+/// With the `serde` feature a session serializes with everything the guesser has worked
+/// out, as the algorithms in [`algorithms`](crate::algorithms) and [`Familiar`] do, so a
+/// game started in the morning can be saved and picked up again later in the day without
+/// replaying it.
+///
+/// [`Familiar`]: crate::Familiar
+///
+/// ```
+/// use roget::algorithms::Naive;
+/// use roget::{parse_mask, Dictionary, Guess, Session};
+///
+/// let dictionary = Dictionary::parse("crane\ntrace\nslate\n").unwrap();
+/// let mut session = Session::new(Naive::with_dictionary(&dictionary));
+/// let word = session.suggest();
+/// session.record(Guess {
+///     word,
+///     mask: parse_mask("xxgxg").unwrap(),
+/// });
+/// assert_eq!(session.suggest(), "slate");
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Session<G> {
+    pub guesser: G,
+    pub history: Vec<Guess>,
+}
+
+impl<G> Session<G> {
+    /// Start a game with nothing guessed yet.
+    pub fn new(guesser: G) -> Self {
+        Self {
+            guesser,
+            history: Vec::new(),
+        }
+    }
+
+    /// Add a guess made in the game and the colors it got.
+    pub fn record(&mut self, guess: Guess) {
+        self.history.push(guess);
+    }
+
+    /// Whether the last guess found the answer.
+    pub fn is_solved(&self) -> bool {
+        self.history
+            .last()
+            .is_some_and(|guess| guess.mask == [Correctness::Correct; 5])
+    }
+}
+
+impl<G: Guesser> Session<G> {
+    /// The word the guesser would play next. Call it once per guess recorded, as a guesser
+    /// may expect to see every step of the history.
+    pub fn suggest(&mut self) -> Word {
+        self.guesser.guess(&self.history)
+    }
+
+    /// Every word the guesser would consider playing next, best first; see
+    /// [`Guesser::ranked`].
+    pub fn ranked(&mut self) -> Vec<Word> {
+        self.guesser.ranked(&self.history)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Session;
+    use crate::algorithms::Naive;
+    use crate::{Correctness, Dictionary, Guess, Word};
+
+    #[test]
+    fn resumes_where_it_left_off() {
+        let dictionary = Dictionary::parse("crane 30\ntrace 20\nslate 10\nplate 5\n").unwrap();
+        let answer = Word::new("plate");
+        let mut session = Session::new(Naive::with_dictionary(&dictionary));
+        let play = |session: &mut Session<Naive>| {
+            let word = session.suggest();
+            session.record(Guess {
+                word,
+                mask: Correctness::compute(answer, word),
+            });
+        };
+
+        play(&mut session);
+        assert!(!session.is_solved());
+        let mut resumed = session.clone();
+        while !session.is_solved() {
+            play(&mut session);
+        }
+        while !resumed.is_solved() {
+            play(&mut resumed);
+        }
+        assert_eq!(resumed.history, session.history);
+        assert_eq!(session.history.last().unwrap().word, answer);
+    }
+}
@@ -0,0 +1,522 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::collections::Set;
+use crate::{Correctness, Dictionary, Guess, MaskParser, ParseMaskError, Word};
+
+/// The spoiler-free block people post after a game:
+///
+/// ```text
+/// Wordle 1 3/6
+///
+/// ⬛🟨⬛⬛⬛
+/// ⬛🟩🟨⬛🟩
+/// 🟩🟩🟩🟩🟩
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ShareGrid<'a> {
+    puzzle: Option<usize>,
+    guesses: &'a [Guess],
+    max_guesses: usize,
+}
+
+impl<'a> ShareGrid<'a> {
+    pub fn new(guesses: &'a [Guess]) -> Self {
+        Self {
+            puzzle: None,
+            guesses,
+            max_guesses: 6,
+        }
+    }
+
+    /// Include the puzzle number in the title line.
+    pub fn puzzle(mut self, number: usize) -> Self {
+        self.puzzle = Some(number);
+        self
+    }
+
+    /// The guess limit shown after the slash.
+    pub fn max_guesses(mut self, max_guesses: usize) -> Self {
+        self.max_guesses = max_guesses;
+        self
+    }
+}
+
+impl fmt::Display for ShareGrid<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Wordle")?;
+        if let Some(puzzle) = self.puzzle {
+            write!(f, " {puzzle}")?;
+        }
+
+        let solved = self
+            .guesses
+            .last()
+            .is_some_and(|g| g.mask == [Correctness::Correct; 5]);
+        if solved && self.guesses.len() <= self.max_guesses {
+            writeln!(f, " {}/{}", self.guesses.len(), self.max_guesses)?;
+        } else {
+            writeln!(f, " X/{}", self.max_guesses)?;
+        }
+
+        for guess in self.guesses {
+            writeln!(f)?;
+            for c in guess.mask {
+                write!(f, "{c}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The game a posted share block describes: only the masks, since the block hides the
+/// words.
+///
+/// Parses what [`ShareGrid`] writes as well as what the official game shares, including
+/// light-mode (⬜) and high-contrast (🟧 🟦) squares, hard mode's `*`, thousands separators
+/// in the puzzle number, and anything after the grid such as a link.
+///
+/// ```
+/// use roget::{Correctness, SharedGame};
+///
+/// let game: SharedGame = "Wordle 1,234 2/6*\n\n⬜🟦⬜⬜🟦\n🟧🟧🟧🟧🟧".parse().unwrap();
+/// assert_eq!(game.puzzle, Some(1234));
+/// assert_eq!(game.score, Some(2));
+/// assert!(game.hard_mode);
+/// assert_eq!(game.masks[1], [Correctness::Correct; 5]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedGame {
+    pub puzzle: Option<usize>,
+    /// How many guesses it took, or `None` for an `X`.
+    pub score: Option<usize>,
+    pub max_guesses: usize,
+    pub hard_mode: bool,
+    /// The mask of every guess, in order.
+    pub masks: Vec<[Correctness; 5]>,
+}
+
+impl FromStr for SharedGame {
+    type Err = ParseShareError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().map(str::trim).filter(|l| !l.is_empty());
+        let title = lines.next().ok_or(ParseShareError::Title)?;
+        let mut game = parse_title(title).ok_or(ParseShareError::Title)?;
+
+        for line in lines {
+            match MaskParser::emoji().parse(line) {
+                Ok(mask) => game.masks.push(mask),
+                // the grid is over; the rest is whatever came after it
+                Err(ParseMaskError::Invalid { tile: 0, .. }) if !game.masks.is_empty() => {
+                    return game.check()
+                }
+                Err(e) => return Err(ParseShareError::Row(game.masks.len() + 1, e)),
+            }
+        }
+        game.check()
+    }
+}
+
+impl SharedGame {
+    /// Make sure the grid agrees with the score in the title, and does not go on after the
+    /// answer was found.
+    fn check(self) -> Result<Self, ParseShareError> {
+        let last = self.masks.len().saturating_sub(1);
+        if let Some(row) = self.masks[..last]
+            .iter()
+            .position(|&mask| mask == [Correctness::Correct; 5])
+        {
+            return Err(ParseShareError::SolvedEarly(row + 1));
+        }
+        let solved = self.masks.last() == Some(&[Correctness::Correct; 5]);
+        let expected = self.score.unwrap_or(self.max_guesses);
+        if self.masks.len() != expected || solved != self.score.is_some() {
+            return Err(ParseShareError::Score);
+        }
+        Ok(self)
+    }
+}
+
+impl SharedGame {
+    /// Guesses from `dictionary` that give this grid's masks against `answer`, the most
+    /// common words that fit, showing the grid could have come from a real game. In hard
+    /// mode every guess must also use the hints before it.
+    ///
+    /// Fails with the first row no guess could have given, which shows a posted grid was
+    /// made up, or was for another answer.
+    ///
+    /// ```
+    /// use roget::{Dictionary, SharedGame, Word};
+    ///
+    /// let dictionary = Dictionary::embedded();
+    /// let game: SharedGame = "Wordle 3/6\n⬛⬛⬛🟨⬛\n⬛🟩🟩🟨🟩\n🟩🟩🟩🟩🟩".parse().unwrap();
+    /// assert!(game.replay(Word::new("crane"), &dictionary).is_ok());
+    ///
+    /// // the last letter would have to be somewhere the other four already are
+    /// let game: SharedGame = "Wordle 2/6\n🟩🟩🟩🟩🟨\n🟩🟩🟩🟩🟩".parse().unwrap();
+    /// assert_eq!(game.replay(Word::new("crane"), &dictionary).unwrap_err().row, 1);
+    /// ```
+    pub fn replay(
+        &self,
+        answer: Word,
+        dictionary: &Dictionary,
+    ) -> Result<Vec<Guess>, ImpossibleGrid> {
+        let mut options = Vec::with_capacity(self.masks.len());
+        for (row, &mask) in (1..).zip(&self.masks) {
+            let mut words: Vec<(Word, usize)> = dictionary
+                .words()
+                .iter()
+                .copied()
+                .filter(|&(word, _)| Correctness::compute(answer, word) == mask)
+                .collect();
+            if words.is_empty() {
+                return Err(ImpossibleGrid { row });
+            }
+            words.sort_by(|(a, x), (b, y)| y.cmp(x).then(a.cmp(b)));
+            options.push(words.into_iter().map(|(word, _)| word).collect::<Vec<_>>());
+        }
+
+        if !self.hard_mode {
+            let guesses = options
+                .iter()
+                .zip(&self.masks)
+                .map(|(words, &mask)| Guess {
+                    word: words[0],
+                    mask,
+                })
+                .collect();
+            return Ok(guesses);
+        }
+        let mut search = HardMode {
+            answer,
+            options: &options,
+            masks: &self.masks,
+            dead: Set::new(),
+            deepest: 0,
+        };
+        let mut guesses = Vec::with_capacity(self.masks.len());
+        match search.extend(Hints::default(), &mut guesses) {
+            true => Ok(guesses),
+            false => Err(ImpossibleGrid {
+                row: search.deepest + 1,
+            }),
+        }
+    }
+}
+
+/// A depth-first search for a hard mode game that gives a grid.
+struct HardMode<'a> {
+    answer: Word,
+    /// The words that give each row's mask.
+    options: &'a [Vec<Word>],
+    masks: &'a [[Correctness; 5]],
+    /// The rows and hints no game can be finished from.
+    dead: Set<(usize, Hints)>,
+    /// How many rows the search managed to fill at most.
+    deepest: usize,
+}
+
+impl HardMode<'_> {
+    /// Fill the rest of the grid after `guesses`, which revealed `hints`.
+    fn extend(&mut self, hints: Hints, guesses: &mut Vec<Guess>) -> bool {
+        let row = guesses.len();
+        if row == self.masks.len() {
+            return true;
+        }
+        self.deepest = self.deepest.max(row);
+        if self.dead.contains(&(row, hints)) {
+            return false;
+        }
+        let mask = self.masks[row];
+        for &word in &self.options[row] {
+            if !hints.allow(self.answer, word) {
+                continue;
+            }
+            guesses.push(Guess { word, mask });
+            if self.extend(hints.after(self.answer, word, mask), guesses) {
+                return true;
+            }
+            guesses.pop();
+        }
+        self.dead.insert((row, hints));
+        false
+    }
+}
+
+/// What hard mode asks of the next guess, given that every guess is graded against the
+/// same answer: which of its positions are known green, and how many copies of each of its
+/// letters, counted at the letter's first position in the answer, must be played.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Hints {
+    greens: u8,
+    copies: [u8; 5],
+}
+
+impl Hints {
+    fn allow(&self, answer: Word, word: Word) -> bool {
+        let (answer, word) = (answer.as_bytes(), word.as_bytes());
+        (0..5).all(|i| {
+            let green = self.greens & (1 << i) == 0 || word[i] == answer[i];
+            let count = word.iter().filter(|&&l| l == answer[i]).count();
+            green && count >= usize::from(self.copies[i])
+        })
+    }
+
+    fn after(mut self, answer: Word, word: Word, mask: [Correctness; 5]) -> Self {
+        let (answer, letters) = (answer.as_bytes(), word.as_bytes());
+        for (i, &c) in mask.iter().enumerate() {
+            if c == Correctness::Correct {
+                self.greens |= 1 << i;
+            }
+        }
+        for i in 0..5 {
+            if answer[..i].contains(&answer[i]) {
+                continue;
+            }
+            let hinted = letters
+                .iter()
+                .zip(&mask)
+                .filter(|&(&l, &c)| l == answer[i] && c != Correctness::Wrong)
+                .count() as u8;
+            self.copies[i] = self.copies[i].max(hinted);
+        }
+        self
+    }
+}
+
+/// A grid no game could have given, from [`SharedGame::replay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImpossibleGrid {
+    /// The first row (1-based) no allowed guess could have given, after guesses that give
+    /// the rows above it.
+    pub row: usize,
+}
+
+impl fmt::Display for ImpossibleGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no allowed guess gives row {} of the grid", self.row)
+    }
+}
+
+impl core::error::Error for ImpossibleGrid {}
+
+/// `Wordle 1,234 4/6*`, where the number, the `*`, and the separators are optional.
+fn parse_title(title: &str) -> Option<SharedGame> {
+    let rest = title.strip_prefix("Wordle")?.trim();
+    let (number, result) = match rest.rsplit_once(char::is_whitespace) {
+        Some((number, result)) => (Some(number.trim()), result),
+        None => (None, rest),
+    };
+    let puzzle = match number {
+        Some(number) => {
+            let digits: alloc::string::String =
+                number.chars().filter(|&c| c != ',' && c != '.').collect();
+            Some(digits.parse().ok()?)
+        }
+        None => None,
+    };
+
+    let (result, hard_mode) = match result.strip_suffix('*') {
+        Some(result) => (result, true),
+        None => (result, false),
+    };
+    let (score, max_guesses) = result.split_once('/')?;
+    let max_guesses = max_guesses.parse().ok()?;
+    let score = match score {
+        "X" | "x" => None,
+        n => Some(n.parse().ok()?),
+    };
+    Some(SharedGame {
+        puzzle,
+        score,
+        max_guesses,
+        hard_mode,
+        masks: Vec::new(),
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseShareError {
+    /// The first line is not a `Wordle N n/6` title.
+    Title,
+    /// This grid row (1-based) is not five squares, as the error says.
+    Row(usize, ParseMaskError),
+    /// The number of rows or the last row does not match the score in the title.
+    Score,
+    /// This grid row (1-based) is all green, but more rows follow it.
+    SolvedEarly(usize),
+}
+
+impl fmt::Display for ParseShareError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Title => f.write_str("expected a title like `Wordle 951 4/6`"),
+            Self::Row(n, ParseMaskError::Length(tiles)) => {
+                write!(f, "row {n} has {tiles} squares, not five")
+            }
+            Self::Row(n, ParseMaskError::Invalid { tile, found, .. }) => {
+                write!(f, "square {} of row {n} is {found:?}", tile + 1)
+            }
+            Self::Score => f.write_str("the grid does not match the score"),
+            Self::SolvedEarly(n) => {
+                write!(f, "row {n} is all green, but the game goes on after it")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseShareError {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::{ImpossibleGrid, ParseShareError, ShareGrid, SharedGame};
+    use crate::{Correctness::*, Dictionary, Guess, ParseMaskError, Word};
+
+    #[test]
+    fn solved() {
+        let guesses = [
+            Guess {
+                word: Word::new("which"),
+                mask: [Wrong, Wrong, Misplaced, Wrong, Wrong],
+            },
+            Guess {
+                word: Word::new("tired"),
+                mask: [Correct; 5],
+            },
+        ];
+        assert_eq!(
+            ShareGrid::new(&guesses).puzzle(7).to_string(),
+            "Wordle 7 2/6\n\n⬛⬛🟨⬛⬛\n🟩🟩🟩🟩🟩"
+        );
+    }
+
+    #[test]
+    fn failed() {
+        let guesses = [Guess {
+            word: Word::new("which"),
+            mask: [Wrong; 5],
+        }];
+        assert_eq!(
+            ShareGrid::new(&guesses).max_guesses(1).to_string(),
+            "Wordle X/1\n\n⬛⬛⬛⬛⬛"
+        );
+    }
+
+    #[test]
+    fn parse_round_trips() {
+        let guesses = [
+            Guess {
+                word: Word::new("which"),
+                mask: [Wrong, Wrong, Misplaced, Wrong, Wrong],
+            },
+            Guess {
+                word: Word::new("tired"),
+                mask: [Correct; 5],
+            },
+        ];
+        let text = ShareGrid::new(&guesses).puzzle(951).to_string();
+        let game: SharedGame = text.parse().unwrap();
+        assert_eq!(game.puzzle, Some(951));
+        assert_eq!(game.score, Some(2));
+        assert!(!game.hard_mode);
+        assert_eq!(game.masks, [guesses[0].mask, guesses[1].mask]);
+    }
+
+    #[test]
+    fn parse_posted() {
+        // light mode, a variation selector, and a link after the grid
+        let text = "Wordle 951 X/6*\n\n⬜⬜⬜⬜⬜\n⬜⬜⬜⬜⬜\n⬜⬜⬜⬜⬜\n⬜⬜⬜⬜⬜\n\
+                    ⬜⬜⬜⬜⬜\n🟨\u{fe0f}🟩🟩🟩🟩\nhttps://www.nytimes.com/games/wordle\n";
+        let game: SharedGame = text.parse().unwrap();
+        assert_eq!(game.score, None);
+        assert!(game.hard_mode);
+        assert_eq!(game.masks.len(), 6);
+        assert_eq!(game.masks[5][0], Misplaced);
+
+        let untitled: SharedGame = "Wordle 1/6\n🟩🟩🟩🟩🟩".parse().unwrap();
+        assert_eq!(untitled.puzzle, None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let parse = |s: &str| s.parse::<SharedGame>().unwrap_err();
+        assert_eq!(parse("Sudoku 4/6\n🟩🟩🟩🟩🟩"), ParseShareError::Title);
+        assert_eq!(
+            parse("Wordle 1 1/6\n🟩🟩🟩🟩"),
+            ParseShareError::Row(1, ParseMaskError::Length(4))
+        );
+        assert_eq!(
+            parse("Wordle 1 2/6\n🟩🟩⬛⬛⬛\n🟩🟩g🟩🟩"),
+            ParseShareError::Row(
+                2,
+                ParseMaskError::Invalid {
+                    tile: 2,
+                    offset: 8,
+                    found: 'g'
+                }
+            )
+        );
+        assert_eq!(parse("Wordle 1 2/6\n🟩🟩🟩🟩🟩"), ParseShareError::Score);
+        assert_eq!(parse("Wordle 1 1/6\n🟩🟩🟩🟩⬛"), ParseShareError::Score);
+    }
+
+    #[test]
+    fn solved_early() {
+        let parse = |s: &str| s.parse::<SharedGame>().unwrap_err();
+        assert_eq!(
+            parse("Wordle 2/6\n🟩🟩🟩🟩🟩\n🟩🟩🟩🟩🟩"),
+            ParseShareError::SolvedEarly(1)
+        );
+        assert_eq!(
+            parse("Wordle X/3\n⬛⬛⬛⬛⬛\n🟩🟩🟩🟩🟩\n⬛⬛⬛⬛⬛"),
+            ParseShareError::SolvedEarly(2)
+        );
+        assert_eq!(
+            parse("Wordle 2/6\n🟩🟩🟩🟩🟩\n🟩🟩🟩🟩🟩").to_string(),
+            "row 1 is all green, but the game goes on after it"
+        );
+    }
+
+    #[test]
+    fn replay() {
+        let dictionary = Dictionary::parse("crane\ncrate\nslate\nlousy\n").unwrap();
+        let crane = Word::new("crane");
+        let game: SharedGame = "Wordle 3/6\n⬛⬛🟩⬛🟩\n⬛⬛⬛⬛⬛\n🟩🟩🟩🟩🟩"
+            .parse()
+            .unwrap();
+        let guesses = game.replay(crane, &dictionary).unwrap();
+        let words: alloc::vec::Vec<_> = guesses.iter().map(|g| g.word).collect();
+        assert_eq!(words, ["slate", "lousy", "crane"]);
+
+        // nothing in the list shares exactly two letters with crane in place
+        let game: SharedGame = "Wordle 2/6\n🟩🟩⬛⬛⬛\n🟩🟩🟩🟩🟩".parse().unwrap();
+        assert_eq!(
+            game.replay(crane, &dictionary),
+            Err(ImpossibleGrid { row: 1 })
+        );
+    }
+
+    #[test]
+    fn replay_hard_mode() {
+        let dictionary = Dictionary::parse("crane\ncrate\nslate\nlousy\n").unwrap();
+        let crane = Word::new("crane");
+        // after slate shows the a and e, lousy could not be played in hard mode
+        let game: SharedGame = "Wordle 3/6*\n⬛⬛🟩⬛🟩\n⬛⬛⬛⬛⬛\n🟩🟩🟩🟩🟩"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            game.replay(crane, &dictionary),
+            Err(ImpossibleGrid { row: 2 })
+        );
+
+        let game: SharedGame = "Wordle 3/6*\n⬛⬛⬛⬛⬛\n⬛⬛🟩⬛🟩\n🟩🟩🟩🟩🟩"
+            .parse()
+            .unwrap();
+        let guesses = game.replay(crane, &dictionary).unwrap();
+        assert_eq!(guesses[1].word, "slate");
+    }
+}
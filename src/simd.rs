@@ -0,0 +1,66 @@
+//! Grading one guess against many answers at once with SSE2, which every x86_64 CPU has.
+
+use core::arch::x86_64::{
+    __m128i, _mm_add_epi8, _mm_and_si128, _mm_andnot_si128, _mm_cmpeq_epi8, _mm_or_si128,
+    _mm_set1_epi8, _mm_setzero_si128,
+};
+
+use crate::Word;
+
+/// How many answers [`compute`] grades at a time.
+pub(crate) const LANES: usize = 16;
+
+/// The packed mask of `guess` against each of `answers`.
+///
+/// This is [`Correctness::compute`](crate::Correctness::compute) followed by
+/// [`Correctness::pack`](crate::Correctness::pack), run in every lane at once: a lane's
+/// bytes are all ones where a comparison holds and all zeros where it does not.
+pub(crate) fn compute(guess: Word, answers: &[Word; LANES]) -> [u8; LANES] {
+    // SAFETY: SSE2 is part of the x86_64 baseline, so every CPU this runs on has it
+    unsafe { compute_sse2(guess, answers) }
+}
+
+#[target_feature(enable = "sse2")]
+fn compute_sse2(guess: Word, answers: &[Word; LANES]) -> [u8; LANES] {
+    let guess = guess.as_bytes();
+    let splat = |byte: u8| _mm_set1_epi8(byte as i8);
+    // one vector per letter position, holding that letter of every answer
+    let answer: [__m128i; 5] =
+        core::array::from_fn(|j| load(core::array::from_fn(|k| answers[k].as_bytes()[j])));
+
+    // mark green
+    let green: [__m128i; 5] = core::array::from_fn(|i| _mm_cmpeq_epi8(answer[i], splat(guess[i])));
+    let mut used = green;
+
+    // mark yellow, and add up each position's base-3 digit as we go
+    let mut packed = _mm_setzero_si128();
+    let mut place = 1;
+    for i in 0..5 {
+        let letter = splat(guess[i]);
+        let mut yellow = _mm_setzero_si128();
+        for j in 0..5 {
+            // the first unused matching answer letter, unless this one is already marked
+            let taken = _mm_or_si128(used[j], _mm_or_si128(green[i], yellow));
+            let take = _mm_andnot_si128(taken, _mm_cmpeq_epi8(answer[j], letter));
+            used[j] = _mm_or_si128(used[j], take);
+            yellow = _mm_or_si128(yellow, take);
+        }
+        let digit = _mm_or_si128(
+            _mm_and_si128(green[i], splat(2 * place)),
+            _mm_and_si128(yellow, splat(place)),
+        );
+        packed = _mm_add_epi8(packed, digit);
+        place = place.wrapping_mul(3);
+    }
+    store(packed)
+}
+
+fn load(bytes: [u8; LANES]) -> __m128i {
+    // SAFETY: both are 16 bytes, and any bit pattern is valid for either
+    unsafe { core::mem::transmute::<[u8; LANES], __m128i>(bytes) }
+}
+
+fn store(vector: __m128i) -> [u8; LANES] {
+    // SAFETY: as for `load`
+    unsafe { core::mem::transmute::<__m128i, [u8; LANES]>(vector) }
+}
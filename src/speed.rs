@@ -0,0 +1,110 @@
+use core::fmt;
+use core::str::FromStr;
+
+/// How much of a search to give up for time, for the searches that weigh every allowed
+/// guess: [`grade`](crate::grade())'s search for the best guess, and [`openers`](crate::openers).
+///
+/// Below [`Speed::Best`] a search still tries every word that could be the answer, which are
+/// most often the best guesses anyway, but only a share of the others.
+///
+/// ```
+/// use roget::Speed;
+///
+/// assert_eq!("fast".parse(), Ok(Speed::Fast));
+/// assert_eq!(Speed::Best.sample_rate(), 1.0);
+/// assert!(Speed::Fast.sample_rate() < Speed::Good.sample_rate());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Speed {
+    /// Try every allowed guess.
+    #[default]
+    Best,
+    /// Try one in four of the guesses that cannot be the answer.
+    Good,
+    /// Try one in twenty of the guesses that cannot be the answer.
+    Fast,
+}
+
+impl Speed {
+    pub const ALL: [Self; 3] = [Self::Best, Self::Good, Self::Fast];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Best => "best",
+            Self::Good => "good",
+            Self::Fast => "fast",
+        }
+    }
+
+    /// The share of the guesses that cannot be the answer a search tries.
+    pub fn sample_rate(self) -> f64 {
+        1.0 / self.stride() as f64
+    }
+
+    /// Whether a search tries the guess at `index` of the allowed guesses, if it cannot be
+    /// the answer. The same guesses are tried every time, so results can be repeated.
+    pub(crate) fn tries(self, index: usize) -> bool {
+        index.is_multiple_of(self.stride())
+    }
+
+    fn stride(self) -> usize {
+        match self {
+            Self::Best => 1,
+            Self::Good => 4,
+            Self::Fast => 20,
+        }
+    }
+}
+
+impl fmt::Display for Speed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Speed {
+    type Err = UnknownSpeed;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|speed| speed.name().eq_ignore_ascii_case(s))
+            .ok_or(UnknownSpeed)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownSpeed;
+
+impl fmt::Display for UnknownSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected one of best, good, or fast")
+    }
+}
+
+impl core::error::Error for UnknownSpeed {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::Speed;
+
+    #[test]
+    fn names() {
+        for speed in Speed::ALL {
+            assert_eq!(speed.to_string().parse(), Ok(speed));
+        }
+        assert!("ludicrous".parse::<Speed>().is_err());
+    }
+
+    #[test]
+    fn tries_a_share() {
+        for speed in Speed::ALL {
+            let tried = (0..1000).filter(|&i| speed.tries(i)).count();
+            assert_eq!(tried as f64, 1000.0 * speed.sample_rate());
+        }
+    }
+}
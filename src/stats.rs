@@ -0,0 +1,536 @@
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::GameResult;
+
+/// The distribution of guesses-to-solve over a batch of games.
+///
+/// Statistics other than [`GameStats::dnf`] are over solved games only. Stats gathered
+/// separately, say one per thread, can be combined with [`GameStats::merge`]. Guess
+/// timings come from [`GameResult::guess_times`], so games recorded only by score are
+/// not timed.
+///
+/// ```
+/// use roget::{GameStats, Word, Wordle};
+/// # use roget::{Guess, Guesser};
+/// # struct Answer(Word);
+/// # impl Guesser for Answer {
+/// #     fn guess(&mut self, _: &[Guess]) -> Word { self.0 }
+/// # }
+///
+/// let wordle = Wordle::new();
+/// let stats: GameStats = ["crane", "slate"]
+///     .iter()
+///     .map(|&answer| wordle.play_game(answer, Answer(Word::new(answer))))
+///     .collect();
+/// assert_eq!(stats.games(), 2);
+/// assert_eq!(stats.mean(), Some(1.0));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameStats {
+    /// `histogram[i]` is the number of games solved in `i + 1` guesses.
+    histogram: Vec<usize>,
+    dnf: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    timed_guesses: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    guess_time: Duration,
+    #[cfg_attr(feature = "serde", serde(default))]
+    fastest_guess: Option<Duration>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    slowest_guess: Option<Duration>,
+}
+
+impl GameStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count one game.
+    pub fn record(&mut self, result: &GameResult) {
+        self.record_score(result.score);
+        for &time in &result.guess_times {
+            self.timed_guesses += 1;
+            self.guess_time += time;
+            self.fastest_guess = Some(self.fastest_guess.map_or(time, |t| t.min(time)));
+            self.slowest_guess = Some(self.slowest_guess.map_or(time, |t| t.max(time)));
+        }
+    }
+
+    /// Count a game that took `score` guesses, or was never solved if `None`.
+    pub fn record_score(&mut self, score: Option<usize>) {
+        match score {
+            Some(score) => {
+                if self.histogram.len() < score {
+                    self.histogram.resize(score, 0);
+                }
+                self.histogram[score - 1] += 1;
+            }
+            None => self.dnf += 1,
+        }
+    }
+
+    /// Add in the games counted by `other`.
+    pub fn merge(&mut self, other: &Self) {
+        if self.histogram.len() < other.histogram.len() {
+            self.histogram.resize(other.histogram.len(), 0);
+        }
+        for (n, m) in self.histogram.iter_mut().zip(&other.histogram) {
+            *n += m;
+        }
+        self.dnf += other.dnf;
+        self.timed_guesses += other.timed_guesses;
+        self.guess_time += other.guess_time;
+        self.fastest_guess = match (self.fastest_guess, other.fastest_guess) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.slowest_guess = self.slowest_guess.max(other.slowest_guess);
+    }
+
+    /// `histogram()[i]` is the number of games solved in `i + 1` guesses.
+    pub fn histogram(&self) -> &[usize] {
+        &self.histogram
+    }
+
+    /// Games the guesser never solved.
+    pub fn dnf(&self) -> usize {
+        self.dnf
+    }
+
+    pub fn solved(&self) -> usize {
+        self.histogram.iter().sum()
+    }
+
+    pub fn games(&self) -> usize {
+        self.solved() + self.dnf
+    }
+
+    /// The mean number of guesses.
+    pub fn mean(&self) -> Option<f64> {
+        let total: usize = (1..).zip(&self.histogram).map(|(s, &n)| s * n).sum();
+        (self.solved() > 0).then(|| total as f64 / self.solved() as f64)
+    }
+
+    /// The middle score, or halfway between the two middle ones.
+    pub fn median(&self) -> Option<f64> {
+        let solved = self.solved();
+        let low = self.nth(solved.checked_sub(1)? / 2)?;
+        let high = self.nth(solved / 2)?;
+        Some((low + high) as f64 / 2.0)
+    }
+
+    /// The smallest score at least `share` (between 0 and 1) of games were solved within,
+    /// by the nearest-rank method: `percentile(0.9)` is the 90th percentile.
+    pub fn percentile(&self, share: f64) -> Option<usize> {
+        let rank = libm::ceil(share.clamp(0.0, 1.0) * self.solved() as f64) as usize;
+        self.nth(rank.max(1) - 1)
+    }
+
+    /// The most guesses any game took.
+    pub fn worst(&self) -> Option<usize> {
+        self.histogram.iter().rposition(|&n| n > 0).map(|i| i + 1)
+    }
+
+    /// How spread out the scores are: the mean squared distance from [`GameStats::mean`].
+    pub fn variance(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        let total: f64 = (1..)
+            .zip(&self.histogram)
+            .map(|(s, &n)| n as f64 * (s as f64 - mean) * (s as f64 - mean))
+            .sum();
+        Some(total / self.solved() as f64)
+    }
+
+    /// The share of games that would be lost with only `max_guesses` allowed, as in the daily
+    /// puzzle's 6: those never solved, and those solved in more guesses.
+    pub fn failure_rate(&self, max_guesses: usize) -> Option<f64> {
+        let over: usize = self.histogram.iter().skip(max_guesses).sum();
+        (self.games() > 0).then(|| (self.dnf + over) as f64 / self.games() as f64)
+    }
+
+    /// How many games in a row a streak lasts on average with only `max_guesses` allowed,
+    /// if every game is like one of these; `None` if none would be lost, or there are no
+    /// games.
+    pub fn expected_streak(&self, max_guesses: usize) -> Option<f64> {
+        // the wins before the first loss follow a geometric distribution
+        let failure = self.failure_rate(max_guesses).filter(|&p| p > 0.0)?;
+        Some((1.0 - failure) / failure)
+    }
+
+    /// The quickest any guess was made.
+    pub fn fastest_guess(&self) -> Option<Duration> {
+        self.fastest_guess
+    }
+
+    /// The mean time a guess took.
+    pub fn mean_guess_time(&self) -> Option<Duration> {
+        (self.timed_guesses > 0).then(|| self.guess_time.div_f64(self.timed_guesses as f64))
+    }
+
+    /// The longest any guess took.
+    pub fn slowest_guess(&self) -> Option<Duration> {
+        self.slowest_guess
+    }
+
+    /// The score of the `n`th (0-based) solved game in order of score.
+    fn nth(&self, mut n: usize) -> Option<usize> {
+        for (i, &count) in self.histogram.iter().enumerate() {
+            if n < count {
+                return Some(i + 1);
+            }
+            n -= count;
+        }
+        None
+    }
+}
+
+impl<'a> Extend<&'a GameResult> for GameStats {
+    fn extend<I: IntoIterator<Item = &'a GameResult>>(&mut self, results: I) {
+        for result in results {
+            self.record(result);
+        }
+    }
+}
+
+impl Extend<GameResult> for GameStats {
+    fn extend<I: IntoIterator<Item = GameResult>>(&mut self, results: I) {
+        for result in results {
+            self.record(&result);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a GameResult> for GameStats {
+    fn from_iter<I: IntoIterator<Item = &'a GameResult>>(results: I) -> Self {
+        let mut stats = Self::new();
+        stats.extend(results);
+        stats
+    }
+}
+
+impl FromIterator<GameResult> for GameStats {
+    fn from_iter<I: IntoIterator<Item = GameResult>>(results: I) -> Self {
+        let mut stats = Self::new();
+        stats.extend(results);
+        stats
+    }
+}
+
+/// Two algorithms played on the same answers, compared game by game so that how hard each
+/// answer is cancels out.
+///
+/// A difference is `second` minus `first`, so a positive mean means `second` takes more
+/// guesses. Games either algorithm did not solve are left out of the mean but count in the
+/// sign test, where any solved game beats an unsolved one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Paired {
+    /// The answers both algorithms solved.
+    pub pairs: usize,
+    /// The mean difference over those answers.
+    pub mean_difference: f64,
+    /// A 95% confidence interval for the mean difference, by the normal approximation.
+    pub interval: (f64, f64),
+    /// Answers `first` did better on.
+    pub wins: usize,
+    /// Answers `second` did better on.
+    pub losses: usize,
+    pub ties: usize,
+    /// The two-sided sign test's p-value: how likely a split at least this lopsided between
+    /// wins and losses would be if neither algorithm were better.
+    pub p_value: f64,
+}
+
+impl Paired {
+    /// Compare the scores two algorithms got on the same answers, in the same order.
+    ///
+    /// # Panics
+    ///
+    /// If the two do not have a score for every answer.
+    pub fn new(first: &[Option<usize>], second: &[Option<usize>]) -> Self {
+        assert_eq!(first.len(), second.len(), "scores for the same answers");
+        let (mut wins, mut losses, mut ties) = (0, 0, 0);
+        let mut differences = Vec::new();
+        for (&a, &b) in first.iter().zip(second) {
+            if let (Some(a), Some(b)) = (a, b) {
+                differences.push(b as f64 - a as f64);
+            }
+            // an unsolved game scores worse than any solved one
+            let worse = |score: Option<usize>| score.unwrap_or(usize::MAX);
+            match worse(a).cmp(&worse(b)) {
+                core::cmp::Ordering::Less => wins += 1,
+                core::cmp::Ordering::Greater => losses += 1,
+                core::cmp::Ordering::Equal => ties += 1,
+            }
+        }
+
+        let n = differences.len() as f64;
+        let mean = differences.iter().sum::<f64>() / n.max(1.0);
+        let variance = match differences.len() {
+            0 | 1 => 0.0,
+            _ => {
+                differences
+                    .iter()
+                    .map(|d| (d - mean) * (d - mean))
+                    .sum::<f64>()
+                    / (n - 1.0)
+            }
+        };
+        let margin = 1.96 * libm::sqrt(variance / n.max(1.0));
+        Self {
+            pairs: differences.len(),
+            mean_difference: mean,
+            interval: (mean - margin, mean + margin),
+            wins,
+            losses,
+            ties,
+            p_value: sign_test(wins, losses),
+        }
+    }
+
+    /// Whether the confidence interval leaves out zero, so the difference is unlikely to be
+    /// noise.
+    pub fn is_significant(&self) -> bool {
+        self.interval.0 > 0.0 || self.interval.1 < 0.0
+    }
+
+    /// The share of answers `first` won, counting ties as half: 1 if it won every one, 0
+    /// if it lost every one. With no answers, 0.5.
+    pub fn score(&self) -> f64 {
+        let games = self.wins + self.losses + self.ties;
+        match games {
+            0 => 0.5,
+            _ => (self.wins as f64 + self.ties as f64 / 2.0) / games as f64,
+        }
+    }
+}
+
+/// An Elo rating, for tracking how algorithms do against each other across many runs.
+///
+/// Each pairing of two algorithms over the same answers is one match, scored by
+/// [`Paired::score`], so a run that wins most answers against a higher-rated algorithm
+/// gains more than one that beats a lower-rated one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Elo {
+    pub rating: f64,
+    /// How many matches the rating is based on.
+    pub matches: usize,
+}
+
+impl Default for Elo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Elo {
+    /// The rating of an algorithm that has not played yet.
+    pub const INITIAL: f64 = 1500.0;
+
+    /// How far one match can move a rating.
+    pub const K: f64 = 32.0;
+
+    pub fn new() -> Self {
+        Self {
+            rating: Self::INITIAL,
+            matches: 0,
+        }
+    }
+
+    /// The score this rating predicts for a match against `other`, from 0 to 1.
+    pub fn expected(&self, other: &Self) -> f64 {
+        1.0 / (1.0 + libm::pow(10.0, (other.rating - self.rating) / 400.0))
+    }
+
+    /// How much this rating moves after scoring `score` in a match against `other`.
+    ///
+    /// Rate every match of a round against the ratings from before it, then
+    /// [`Elo::apply`] the changes, so the order the matches were played in does not
+    /// matter.
+    pub fn change(&self, other: &Self, score: f64) -> f64 {
+        Self::K * (score - self.expected(other))
+    }
+
+    /// Move the rating by `change`, the sum of [`Elo::change`] over `matches` matches.
+    pub fn apply(&mut self, change: f64, matches: usize) {
+        self.rating += change;
+        self.matches += matches;
+    }
+}
+
+/// The exact two-sided binomial test of `wins` against `losses` at even odds.
+fn sign_test(wins: usize, losses: usize) -> f64 {
+    let n = wins + losses;
+    if n == 0 {
+        return 1.0;
+    }
+    // the chance of a split at least as uneven on the side it fell, doubled for both sides
+    let ln_choose = |k: usize| {
+        libm::lgamma(n as f64 + 1.0)
+            - libm::lgamma(k as f64 + 1.0)
+            - libm::lgamma((n - k) as f64 + 1.0)
+    };
+    let tail: f64 = (0..=wins.min(losses))
+        .map(|k| libm::exp(ln_choose(k) - n as f64 * core::f64::consts::LN_2))
+        .sum();
+    (2.0 * tail).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::time::Duration;
+
+    use super::{sign_test, Elo, GameStats, Paired};
+    use crate::{GameResult, Word};
+
+    fn stats(scores: &[Option<usize>]) -> GameStats {
+        let mut stats = GameStats::new();
+        for &score in scores {
+            stats.record_score(score);
+        }
+        stats
+    }
+
+    #[test]
+    fn summary() {
+        let stats = stats(&[Some(3), Some(4), Some(4), Some(6), None]);
+        assert_eq!(stats.histogram(), [0, 0, 1, 2, 0, 1]);
+        assert_eq!(stats.games(), 5);
+        assert_eq!(stats.solved(), 4);
+        assert_eq!(stats.dnf(), 1);
+        assert_eq!(stats.mean(), Some(4.25));
+        assert_eq!(stats.median(), Some(4.0));
+        assert_eq!(stats.percentile(0.25), Some(3));
+        assert_eq!(stats.percentile(0.9), Some(6));
+        assert_eq!(stats.percentile(0.0), Some(3));
+        assert_eq!(stats.worst(), Some(6));
+    }
+
+    #[test]
+    fn spread_and_failures() {
+        let stats = stats(&[Some(3), Some(5), Some(7), None]);
+        assert_eq!(stats.variance(), Some(8.0 / 3.0));
+        assert_eq!(stats.failure_rate(6), Some(0.5));
+        assert_eq!(stats.failure_rate(7), Some(0.25));
+        assert_eq!(stats.expected_streak(6), Some(1.0));
+        assert_eq!(stats.expected_streak(7), Some(3.0));
+
+        assert_eq!(GameStats::new().failure_rate(6), None);
+        let perfect = self::stats(&[Some(4)]);
+        assert_eq!(perfect.failure_rate(6), Some(0.0));
+        assert_eq!(perfect.expected_streak(6), None);
+    }
+
+    #[test]
+    fn even_median() {
+        assert_eq!(stats(&[Some(3), Some(4)]).median(), Some(3.5));
+    }
+
+    #[test]
+    fn empty() {
+        let stats = stats(&[None]);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.median(), None);
+        assert_eq!(stats.percentile(0.5), None);
+        assert_eq!(stats.worst(), None);
+    }
+
+    #[test]
+    fn merge() {
+        let mut all = stats(&[Some(2), None]);
+        all.merge(&stats(&[Some(5), Some(2)]));
+        assert_eq!(all, stats(&[Some(2), None, Some(5), Some(2)]));
+    }
+
+    #[test]
+    fn guess_times() {
+        let game = |times: &[u64]| GameResult {
+            answer: Word::new("crane"),
+            guesses: Vec::new(),
+            score: Some(times.len()),
+            progress: Vec::new(),
+            candidates: Vec::new(),
+            guess_times: times.iter().map(|&ms| Duration::from_millis(ms)).collect(),
+        };
+        let mut timed: GameStats = [game(&[4, 2])].iter().collect();
+        assert_eq!(timed.mean_guess_time(), Some(Duration::from_millis(3)));
+        timed.merge(&[game(&[9, 1, 5])].iter().collect());
+        assert_eq!(timed.fastest_guess(), Some(Duration::from_millis(1)));
+        assert_eq!(timed.mean_guess_time(), Some(Duration::from_millis(21) / 5));
+        assert_eq!(timed.slowest_guess(), Some(Duration::from_millis(9)));
+
+        // games recorded by score alone are not timed
+        let untimed = stats(&[Some(3)]);
+        assert_eq!(untimed.mean_guess_time(), None);
+        assert_eq!(untimed.fastest_guess(), None);
+    }
+
+    #[test]
+    fn differences() {
+        let first = [Some(3), Some(4), Some(5), None];
+        let second = [Some(4), Some(4), Some(6), Some(7)];
+        let paired = Paired::new(&first, &second);
+        assert_eq!(paired.pairs, 3);
+        assert!((paired.mean_difference - 2.0 / 3.0).abs() < 1e-12);
+        assert!(paired.interval.0 < paired.mean_difference);
+        assert!(paired.interval.1 > paired.mean_difference);
+        // the unsolved game goes to `second`
+        assert_eq!((paired.wins, paired.losses, paired.ties), (2, 1, 1));
+    }
+
+    #[test]
+    fn significance() {
+        // the same answers, always one guess apart
+        let first = [Some(3); 50];
+        let second = [Some(4); 50];
+        let paired = Paired::new(&first, &second);
+        assert_eq!(paired.interval, (1.0, 1.0));
+        assert!(paired.is_significant());
+        assert!(paired.p_value < 1e-10);
+
+        let noise = Paired::new(&[Some(3), Some(4)], &[Some(4), Some(3)]);
+        assert!(!noise.is_significant());
+        assert_eq!(noise.p_value, 1.0);
+    }
+
+    #[test]
+    fn sign_test_values() {
+        assert_eq!(sign_test(0, 0), 1.0);
+        // 2 * (1 + 10) / 1024
+        assert!((sign_test(1, 9) - 22.0 / 1024.0).abs() < 1e-12);
+        assert!((sign_test(9, 1) - 22.0 / 1024.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn elo() {
+        let (a, b) = (Elo::new(), Elo::new());
+        assert_eq!(a.expected(&b), 0.5);
+        assert_eq!(a.change(&b, 1.0), Elo::K / 2.0);
+        assert_eq!(a.change(&b, 0.5), 0.0);
+
+        let strong = Elo {
+            rating: 1900.0,
+            matches: 10,
+        };
+        // 400 points ahead expects to score ten times what the weaker side does
+        assert!((strong.expected(&a) - 10.0 / 11.0).abs() < 1e-12);
+        // so beating it is worth more than beating an equal
+        assert!(a.change(&strong, 1.0) > a.change(&b, 1.0));
+
+        let mut c = a;
+        c.apply(a.change(&b, 1.0) + a.change(&strong, 0.0), 2);
+        assert_eq!(c.matches, 2);
+        // losing to a much stronger side costs less than beating an equal gains
+        assert!(c.rating > Elo::INITIAL);
+    }
+
+    #[test]
+    fn paired_score() {
+        let paired = Paired::new(&[Some(3), Some(4), Some(5)], &[Some(4), Some(4), Some(4)]);
+        assert_eq!(paired.score(), 0.5);
+        assert_eq!(Paired::new(&[], &[]).score(), 0.5);
+        assert_eq!(Paired::new(&[Some(2)], &[None]).score(), 1.0);
+    }
+}
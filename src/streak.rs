@@ -0,0 +1,136 @@
+use alloc::vec::Vec;
+
+use crate::rng::SplitMix64;
+use crate::GameStats;
+
+/// The streaks of a player who plays the daily puzzle every day, simulated from how their
+/// games go; see [`Streaks::simulate`].
+///
+/// [`GameStats::expected_streak`] gives the average streak directly. A simulation also
+/// shows how long the longest streak over a stretch of days tends to be, and how much the
+/// streaks vary.
+///
+/// ```
+/// use roget::{GameStats, Streaks};
+///
+/// let mut stats = GameStats::new();
+/// for score in [Some(3), Some(4), Some(4), Some(5), Some(7)] {
+///     stats.record_score(score);
+/// }
+/// let streaks = Streaks::simulate(&stats, 6, 10_000, 1);
+/// // one game in five is lost, so a streak lasts 4 games on average
+/// assert!((streaks.mean().unwrap() - 4.0).abs() < 0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Streaks {
+    /// The length of every streak a loss ended, in order.
+    pub ended: Vec<usize>,
+    /// The streak still going on the last day.
+    pub current: usize,
+    pub days: usize,
+}
+
+impl Streaks {
+    /// Play `days` daily puzzles, each going like a game drawn at random from `stats`, and
+    /// lost if it is not solved within `max_guesses`. The same `seed` plays the same days.
+    ///
+    /// Panics if `stats` has no games.
+    pub fn simulate(stats: &GameStats, max_guesses: usize, days: usize, seed: u64) -> Self {
+        assert!(stats.games() > 0, "there are no games to draw from");
+        // the games from solved in 1 onwards, then the unsolved ones, so a drawn game's
+        // position tells its score
+        let wins: usize = stats.histogram().iter().take(max_guesses).sum();
+        let mut rng = SplitMix64::new(seed);
+        let mut streaks = Self {
+            ended: Vec::new(),
+            current: 0,
+            days,
+        };
+        for _ in 0..days {
+            match rng.below(stats.games()) < wins {
+                true => streaks.current += 1,
+                false => streaks.ended.push(core::mem::take(&mut streaks.current)),
+            }
+        }
+        streaks
+    }
+
+    /// The games lost.
+    pub fn losses(&self) -> usize {
+        self.ended.len()
+    }
+
+    /// The longest streak, including the one still going.
+    pub fn longest(&self) -> usize {
+        self.ended
+            .iter()
+            .copied()
+            .chain([self.current])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The average length of the streaks a loss ended; `None` if no game was lost.
+    pub fn mean(&self) -> Option<f64> {
+        let total: usize = self.ended.iter().sum();
+        (!self.ended.is_empty()).then(|| total as f64 / self.ended.len() as f64)
+    }
+
+    /// How spread out the lengths of the streaks a loss ended are, as a variance.
+    pub fn variance(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        let total: f64 = self
+            .ended
+            .iter()
+            .map(|&n| (n as f64 - mean) * (n as f64 - mean))
+            .sum();
+        Some(total / self.ended.len() as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Streaks;
+    use crate::GameStats;
+
+    fn stats(scores: &[Option<usize>]) -> GameStats {
+        let mut stats = GameStats::new();
+        for &score in scores {
+            stats.record_score(score);
+        }
+        stats
+    }
+
+    #[test]
+    fn never_losing() {
+        let streaks = Streaks::simulate(&stats(&[Some(3), Some(6)]), 6, 100, 0);
+        assert_eq!(streaks.losses(), 0);
+        assert_eq!(streaks.current, 100);
+        assert_eq!(streaks.longest(), 100);
+        assert_eq!(streaks.mean(), None);
+    }
+
+    #[test]
+    fn always_losing() {
+        // solved, but never within 6
+        let streaks = Streaks::simulate(&stats(&[Some(7), None]), 6, 50, 0);
+        assert_eq!(streaks.losses(), 50);
+        assert_eq!(streaks.longest(), 0);
+        assert_eq!(streaks.mean(), Some(0.0));
+        assert_eq!(streaks.variance(), Some(0.0));
+    }
+
+    #[test]
+    fn matches_the_expected_streak() {
+        let stats = stats(&[Some(4), Some(4), Some(5), Some(8)]);
+        let streaks = Streaks::simulate(&stats, 6, 100_000, 7);
+        let expected = stats.expected_streak(6).unwrap();
+        assert!((streaks.mean().unwrap() - expected).abs() < 0.1);
+        let failures = streaks.losses() as f64 / streaks.days as f64;
+        assert!((failures - stats.failure_rate(6).unwrap()).abs() < 0.01);
+        assert_eq!(
+            Streaks::simulate(&stats, 6, 1000, 7),
+            Streaks::simulate(&stats, 6, 1000, 7)
+        );
+    }
+}
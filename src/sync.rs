@@ -0,0 +1,39 @@
+//! Lazily-initialized statics that work with and without `std`.
+
+#[cfg(feature = "std")]
+pub(crate) use once_cell::sync::Lazy;
+
+#[cfg(not(feature = "std"))]
+pub(crate) use self::race::Lazy;
+
+#[cfg(not(feature = "std"))]
+mod race {
+    use alloc::boxed::Box;
+    use core::ops::Deref;
+    use once_cell::race::OnceBox;
+
+    /// A `Lazy` built on `OnceBox`, since `once_cell::sync` needs `std`.
+    ///
+    /// Concurrent first accesses may each run `init`, but only one result is kept.
+    pub(crate) struct Lazy<T> {
+        cell: OnceBox<T>,
+        init: fn() -> T,
+    }
+
+    impl<T> Lazy<T> {
+        pub(crate) const fn new(init: fn() -> T) -> Self {
+            Self {
+                cell: OnceBox::new(),
+                init,
+            }
+        }
+    }
+
+    impl<T> Deref for Lazy<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            self.cell.get_or_init(|| Box::new((self.init)()))
+        }
+    }
+}
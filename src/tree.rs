@@ -0,0 +1,464 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{Correctness, GameResult, Guess, Word};
+
+/// A strategy written out in full: the guess to make, and where to go after each mask it can
+/// show.
+///
+/// Any guesser that always makes the same guess for the same history has one, which
+/// [`DecisionTree::from_games`] recovers from a game against every answer. Published
+/// strategies can be parsed from text instead; see the [`FromStr`] impl.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionTree {
+    pub guess: Word,
+    /// Whether `guess` is itself one of the answers that lead here, so a game can end at
+    /// this node.
+    pub solves: bool,
+    /// The subtree for every mask `guess` showed other than all green.
+    pub children: BTreeMap<[Correctness; 5], DecisionTree>,
+}
+
+impl DecisionTree {
+    /// A tree that makes `guess` and has not seen what follows yet.
+    pub fn leaf(guess: Word) -> Self {
+        Self {
+            guess,
+            solves: false,
+            children: BTreeMap::new(),
+        }
+    }
+
+    /// The tree that `games`, all played by the same deterministic guesser, walked through.
+    ///
+    /// Unsolved games contribute every guess they made.
+    pub fn from_games<'a>(
+        games: impl IntoIterator<Item = &'a GameResult>,
+    ) -> Result<Self, TreeError> {
+        let mut games = games.into_iter().filter(|g| !g.guesses.is_empty());
+        let first = games.next().ok_or(TreeError::Empty)?;
+        let mut tree = Self::leaf(first.guesses[0].word);
+        for game in core::iter::once(first).chain(games) {
+            tree.insert(game)?;
+        }
+        Ok(tree)
+    }
+
+    /// Add the path `game` took, which must agree with the guesses already in the tree.
+    pub fn insert(&mut self, game: &GameResult) -> Result<(), TreeError> {
+        let diverges = |turn| TreeError::Diverges {
+            answer: game.answer,
+            turn,
+        };
+        let Some(first) = game.guesses.first() else {
+            return Ok(());
+        };
+        if first.word != self.guess {
+            return Err(diverges(1));
+        }
+
+        let mut node = self;
+        for (turn, pair) in (2..).zip(game.guesses.windows(2)) {
+            let (guess, next) = (&pair[0], &pair[1]);
+            node = node
+                .children
+                .entry(guess.mask)
+                .or_insert_with(|| Self::leaf(next.word));
+            if node.guess != next.word {
+                return Err(diverges(turn));
+            }
+        }
+        let last = game.guesses.last().expect("checked above");
+        if last.mask == [Correctness::Correct; 5] {
+            node.solves = true;
+        }
+        Ok(())
+    }
+
+    /// Where the tree is after `history`, or `None` if the game made a guess the tree would
+    /// not have or saw a mask it has no plan for.
+    pub fn follow(&self, history: &[Guess]) -> Option<&Self> {
+        let mut node = self;
+        for guess in history {
+            if guess.word != node.guess {
+                return None;
+            }
+            node = node.children.get(&guess.mask)?;
+        }
+        Some(node)
+    }
+
+    /// How many guesses the tree holds.
+    pub fn size(&self) -> usize {
+        1 + self.children.values().map(Self::size).sum::<usize>()
+    }
+
+    /// The most guesses any path through the tree makes.
+    pub fn depth(&self) -> usize {
+        1 + self.children.values().map(Self::depth).max().unwrap_or(0)
+    }
+
+    /// The tree as a Graphviz graph, with a node per guess and an edge per mask; nodes where
+    /// a game can end are drawn with a double border.
+    ///
+    /// ```
+    /// use roget::{parse_mask, DecisionTree, Word};
+    ///
+    /// let mut tree = DecisionTree::leaf(Word::new("crane"));
+    /// tree.solves = true;
+    /// let mut trace = DecisionTree::leaf(Word::new("trace"));
+    /// trace.solves = true;
+    /// tree.children.insert(parse_mask("yyggy").unwrap(), trace);
+    ///
+    /// let dot = tree.dot().to_string();
+    /// assert!(dot.contains("n0 -> n1 [label=\"yyggy\"];"));
+    /// ```
+    pub fn dot(&self) -> Dot<'_> {
+        Dot(self)
+    }
+}
+
+/// Writes a [`DecisionTree`] in the DOT language; see [`DecisionTree::dot`].
+#[derive(Debug, Clone, Copy)]
+pub struct Dot<'a>(&'a DecisionTree);
+
+impl fmt::Display for Dot<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph strategy {{")?;
+        writeln!(f, "    node [shape=box, fontname=\"monospace\"];")?;
+        writeln!(f, "    edge [fontname=\"monospace\"];")?;
+        let mut next = 0;
+        write_node(f, self.0, &mut next)?;
+        writeln!(f, "}}")
+    }
+}
+
+/// Write `node` and everything under it, numbering nodes in preorder from `next`.
+fn write_node(f: &mut fmt::Formatter<'_>, node: &DecisionTree, next: &mut usize) -> fmt::Result {
+    let id = *next;
+    *next += 1;
+    let border = if node.solves { ", peripheries=2" } else { "" };
+    writeln!(f, "    n{id} [label=\"{}\"{border}];", node.guess)?;
+    for (mask, child) in &node.children {
+        write!(f, "    n{id} -> n{} [label=\"", *next)?;
+        for c in mask {
+            write!(f, "{c:#}")?;
+        }
+        writeln!(f, "\"];")?;
+        write_node(f, child, next)?;
+    }
+    Ok(())
+}
+
+/// Reads the plain-text format optimal strategies are commonly published in: one line per
+/// answer, alternating each guess with the pattern it showed, optionally followed by the
+/// turn number.
+///
+/// ```text
+/// salet BBBBB1 courd BBBBB2 nymph BYBBB3 whump GGGGG4
+/// ```
+///
+/// Patterns use `G` (green), `Y` (yellow), and `B` or `X` (gray) in either case. A line
+/// that starts with whitespace shares every word and pattern that begins to the left of its
+/// first one with the line above, as in trees that only write out where paths branch:
+///
+/// ```
+/// use roget::{DecisionTree, Word};
+///
+/// let tree: DecisionTree = "\
+/// crane GGGGG1
+///       YGGBG1 trace GGGGG2
+///                    BGGGG2 grace GGGGG3"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(tree.size(), 3);
+/// assert_eq!(tree.depth(), 3);
+/// ```
+///
+/// Every pattern must be what its guess shows for the answer the line ends at.
+impl FromStr for DecisionTree {
+    type Err = ParseTreeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tree: Option<Self> = None;
+        // the tokens of the line above with the columns they start at
+        let mut above: Vec<(usize, &str)> = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            let number = i + 1;
+            let mut tokens = tokens(line);
+            let Some(&(first, _)) = tokens.first() else {
+                continue;
+            };
+            let shared = above.iter().take_while(|&&(column, _)| column < first);
+            tokens.splice(0..0, shared.copied());
+
+            let game = path(&tokens).ok_or(ParseTreeError::Syntax(number))?;
+            if game.score.is_none() {
+                return Err(ParseTreeError::Unsolved(number));
+            }
+            if !game.guesses.iter().all(|g| g.matches(game.answer)) {
+                return Err(ParseTreeError::Mismatch(number));
+            }
+            tree.get_or_insert_with(|| Self::leaf(game.guesses[0].word))
+                .insert(&game)
+                .map_err(|_| ParseTreeError::Conflict(number))?;
+            above = tokens;
+        }
+        tree.ok_or(ParseTreeError::Empty)
+    }
+}
+
+/// The words of `line` with the column each starts at.
+fn tokens(line: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (column, (i, c)) in line.char_indices().enumerate() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some((column, i)),
+            (true, Some((column, from))) => {
+                tokens.push((column, &line[from..i]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some((column, from)) = start {
+        tokens.push((column, &line[from..]));
+    }
+    tokens
+}
+
+/// One line's alternating guesses and patterns as the game they describe, or `None` if they
+/// do not alternate properly.
+fn path(tokens: &[(usize, &str)]) -> Option<GameResult> {
+    if tokens.is_empty() || !tokens.len().is_multiple_of(2) {
+        return None;
+    }
+    let guesses = tokens
+        .chunks(2)
+        .map(|pair| {
+            Some(Guess {
+                word: pair[0].1.parse().ok()?,
+                mask: pattern(pair[1].1)?,
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let last = guesses.last()?;
+    let solved = last.mask == [Correctness::Correct; 5];
+    Some(GameResult {
+        answer: last.word,
+        score: solved.then_some(guesses.len()),
+        guesses,
+        progress: Vec::new(),
+        candidates: Vec::new(),
+        guess_times: Vec::new(),
+    })
+}
+
+/// A pattern like `BYBBG` or `bybbg3`.
+fn pattern(token: &str) -> Option<[Correctness; 5]> {
+    let tiles = token.trim_end_matches(|c: char| c.is_ascii_digit());
+    let mut mask = [Correctness::Wrong; 5];
+    let mut chars = tiles.chars();
+    for slot in &mut mask {
+        *slot = match chars.next()?.to_ascii_lowercase() {
+            'g' => Correctness::Correct,
+            'y' => Correctness::Misplaced,
+            'b' | 'x' => Correctness::Wrong,
+            _ => return None,
+        };
+    }
+    chars.next().is_none().then_some(mask)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeError {
+    /// There were no games to build a tree from.
+    Empty,
+    /// The game against `answer` made a different guess on this turn (1-based) than an
+    /// earlier game with the same history did.
+    Diverges { answer: Word, turn: usize },
+}
+
+impl fmt::Display for TreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("no games to build a tree from"),
+            Self::Diverges { answer, turn } => write!(
+                f,
+                "the game against {answer} guessed differently on turn {turn} than an \
+                 earlier game in the same position"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for TreeError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseTreeError {
+    /// There were no lines to build a tree from.
+    Empty,
+    /// This line (1-based) does not alternate five-letter words and five-tile patterns.
+    Syntax(usize),
+    /// This line does not end with an all-green pattern.
+    Unsolved(usize),
+    /// A pattern on this line is not what its guess shows for the answer the line ends at.
+    Mismatch(usize),
+    /// This line guesses differently than an earlier line in the same position.
+    Conflict(usize),
+}
+
+impl fmt::Display for ParseTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("the tree has no lines"),
+            Self::Syntax(n) => write!(f, "line {n} is not alternating guesses and patterns"),
+            Self::Unsolved(n) => write!(f, "line {n} does not end with an all-green pattern"),
+            Self::Mismatch(n) => write!(f, "line {n} has a pattern its guess would not show"),
+            Self::Conflict(n) => write!(f, "line {n} disagrees with an earlier line"),
+        }
+    }
+}
+
+impl core::error::Error for ParseTreeError {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use super::{DecisionTree, ParseTreeError, TreeError};
+    use crate::{Correctness, GameResult, Guess, Word};
+
+    fn game(answer: &str, guesses: &[&str]) -> GameResult {
+        let answer = Word::new(answer);
+        let guesses: Vec<_> = guesses
+            .iter()
+            .map(|&g| Guess {
+                word: Word::new(g),
+                mask: Correctness::compute(answer, Word::new(g)),
+            })
+            .collect();
+        let score = (guesses.last().map(|g| g.word) == Some(answer)).then_some(guesses.len());
+        GameResult {
+            answer,
+            guesses,
+            score,
+            progress: Vec::new(),
+            candidates: Vec::new(),
+            guess_times: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn from_games() {
+        let games = [
+            game("crane", &["crane"]),
+            game("trace", &["crane", "trace"]),
+            game("grace", &["crane", "trace", "grace"]),
+        ];
+        let tree = DecisionTree::from_games(&games).unwrap();
+        assert_eq!(tree.guess, Word::new("crane"));
+        assert!(tree.solves);
+        assert_eq!(tree.size(), 3);
+        assert_eq!(tree.depth(), 3);
+
+        let trace = &tree.children[&games[1].guesses[0].mask];
+        assert!(trace.solves);
+        assert_eq!(trace.children.len(), 1);
+    }
+
+    #[test]
+    fn diverges() {
+        let games = [
+            game("trace", &["crane", "trace"]),
+            game("trace", &["crane", "react", "trace"]),
+        ];
+        assert_eq!(
+            DecisionTree::from_games(&games),
+            Err(TreeError::Diverges {
+                answer: Word::new("trace"),
+                turn: 2
+            })
+        );
+        assert_eq!(
+            DecisionTree::from_games(&[game("trace", &["slate"]), game("trace", &["crane"])]),
+            Err(TreeError::Diverges {
+                answer: Word::new("trace"),
+                turn: 1
+            })
+        );
+        assert_eq!(DecisionTree::from_games(&[]), Err(TreeError::Empty));
+    }
+
+    #[test]
+    fn dot() {
+        let games = [
+            game("crane", &["crane"]),
+            game("trace", &["crane", "trace"]),
+        ];
+        let dot = DecisionTree::from_games(&games).unwrap().dot().to_string();
+        assert_eq!(
+            dot,
+            "digraph strategy {\n    node [shape=box, fontname=\"monospace\"];\n    \
+             edge [fontname=\"monospace\"];\n    n0 [label=\"crane\", peripheries=2];\n    \
+             n0 -> n1 [label=\"yggxg\"];\n    n1 [label=\"trace\", peripheries=2];\n}\n"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let flat = "crane GGGGG1\n\
+                    crane YGGBG1 trace GGGGG2\n\
+                    crane ygGbg1 trace bgggg2 grace ggggg3\n";
+        let tree: DecisionTree = flat.parse().unwrap();
+        let games = [
+            game("crane", &["crane"]),
+            game("trace", &["crane", "trace"]),
+            game("grace", &["crane", "trace", "grace"]),
+        ];
+        assert_eq!(tree, DecisionTree::from_games(&games).unwrap());
+
+        let indented = "crane YGGBG1 trace GGGGG2\n\
+                        \x20                  BGGGG2 grace GGGGG3\n\
+                        \x20     GGGGG1";
+        assert_eq!(indented.parse::<DecisionTree>(), Ok(tree));
+    }
+
+    #[test]
+    fn follow() {
+        let games = [
+            game("trace", &["crane", "trace"]),
+            game("grace", &["crane", "trace", "grace"]),
+        ];
+        let tree = DecisionTree::from_games(&games).unwrap();
+        let history = &games[1].guesses;
+        assert_eq!(tree.follow(&[]).map(|n| n.guess), Some(Word::new("crane")));
+        assert_eq!(
+            tree.follow(&history[..2]).map(|n| n.guess),
+            Some(Word::new("grace"))
+        );
+        // played off the tree
+        assert_eq!(tree.follow(&games[0].guesses[1..]), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let parse = |s: &str| s.parse::<DecisionTree>().unwrap_err();
+        assert_eq!(parse("\n"), ParseTreeError::Empty);
+        assert_eq!(parse("crane GGGGG1\ncrane"), ParseTreeError::Syntax(2));
+        assert_eq!(parse("crane GGGG1"), ParseTreeError::Syntax(1));
+        assert_eq!(parse("crane YGGBG1"), ParseTreeError::Unsolved(1));
+        assert_eq!(
+            parse("crane GGGGB1 trace GGGGG2"),
+            ParseTreeError::Mismatch(1)
+        );
+        assert_eq!(
+            parse("crane YGGBG1 trace GGGGG2\nslate BBGYG1 trace GGGGG2"),
+            ParseTreeError::Conflict(2)
+        );
+    }
+}
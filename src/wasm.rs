@@ -0,0 +1,105 @@
+//! Bindings for driving the solver from JavaScript, as a Wordle helper page would.
+//!
+//! ```js
+//! const solver = new Solver();
+//! solver.suggestion();        // "which"
+//! solver.submit("which", "xxyxx");
+//! solver.candidates(10);      // the ten likeliest answers left
+//! ```
+//!
+//! Words cross the boundary as strings and masks as the `g`/`y`/`x` letters
+//! [`parse_mask`] reads; everything else stays on the Rust side.
+//!
+//! The crate is not a `cdylib` by default, since `no_std` builds could not link one, so
+//! build the module with
+//!
+//! ```sh
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target web target/wasm32-unknown-unknown/release/roget.wasm --out-dir pkg
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use wasm_bindgen::prelude::*;
+
+use crate::algorithms::{Candidates, Naive, Normalization};
+use crate::{parse_mask, Dictionary, Guess, Guesser, Word};
+
+/// One game in progress against the embedded dictionary and its official answers.
+#[wasm_bindgen]
+pub struct Solver {
+    guesser: Naive,
+    candidates: Candidates,
+    history: Vec<Guess>,
+    suggestion: Word,
+}
+
+#[wasm_bindgen]
+impl Solver {
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        let dictionary = Dictionary::official();
+        let mut guesser = Naive::with_dictionary(&dictionary);
+        let suggestion = guesser.guess(&[]);
+        Self {
+            guesser,
+            candidates: Candidates::from_priors(
+                &Normalization::default().weights(dictionary.answers()),
+            ),
+            history: Vec::new(),
+            suggestion,
+        }
+    }
+
+    /// The word to play next.
+    pub fn suggestion(&self) -> String {
+        self.suggestion.to_string()
+    }
+
+    /// Record that `word` was played and got `mask`, such as `gxxyy`.
+    pub fn submit(&mut self, word: &str, mask: &str) -> Result<(), JsError> {
+        let word: Word = word
+            .parse()
+            .map_err(|e| JsError::new(&format!("{word}: {e}")))?;
+        let mask = parse_mask(mask).map_err(|e| JsError::new(&e.to_string()))?;
+        let guess = Guess { word, mask };
+        self.candidates.filter(&guess);
+        self.history.push(guess);
+        if !self.candidates.is_empty() {
+            self.suggestion = self.guesser.guess(&self.history);
+        }
+        Ok(())
+    }
+
+    /// How many answers are still possible.
+    pub fn remaining(&self) -> usize {
+        self.candidates.len()
+    }
+
+    /// Up to `limit` of the answers still possible, most likely first.
+    pub fn candidates(&self, limit: usize) -> Vec<String> {
+        self.candidates
+            .iter()
+            .take(limit)
+            .map(|(word, _)| word.to_string())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solver;
+
+    #[test]
+    fn narrows_down() {
+        let mut solver = Solver::new();
+        assert_eq!(solver.remaining(), 2309);
+        let opener = solver.suggestion();
+        solver.submit(&opener, "xxxxx").unwrap();
+        assert!(solver.remaining() < 2309);
+        assert!(!solver.candidates(3).contains(&opener));
+        assert_eq!(solver.candidates(3).len(), 3);
+    }
+}
@@ -0,0 +1,208 @@
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+/// A five-letter word stored as one byte per letter.
+///
+/// Each byte is the letter's Latin-1 code point, which covers the accented letters of
+/// Spanish, German, French and the like while keeping every letter a single byte. Words are
+/// validated once on construction, so the game and algorithms can compare and index
+/// letters without re-checking lengths or decoding UTF-8.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Word([u8; 5]);
+
+impl Word {
+    /// How many letters every word has.
+    pub const LEN: usize = 5;
+
+    /// Build a word from a five-letter string, lowercasing it.
+    ///
+    /// Panics if `s` is not exactly five Latin-1 letters, which makes it a compile error when
+    /// used in a `const`. Use [`str::parse`] for untrusted input, which also trims
+    /// surrounding whitespace.
+    pub const fn new(s: &str) -> Self {
+        match Self::from_utf8(s.as_bytes()) {
+            Ok(word) => word,
+            Err(_) => panic!("a word is exactly five Latin-1 letters"),
+        }
+    }
+
+    const fn from_utf8(bytes: &[u8]) -> Result<Self, ParseWordError> {
+        let mut word = [0; 5];
+        let mut letters = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            // Latin-1 is U+0000..=U+00FF, which UTF-8 encodes in at most two bytes
+            let (letter, width) = match bytes[i] {
+                b @ 0x00..=0x7f => (b, 1),
+                b @ 0xc2..=0xc3 if i + 1 < bytes.len() && bytes[i + 1] & 0xc0 == 0x80 => {
+                    ((b & 0x1f) << 6 | (bytes[i + 1] & 0x3f), 2)
+                }
+                _ => return Err(ParseWordError::Unsupported),
+            };
+            let letter = match letter {
+                b'A'..=b'Z' | 0xc0..=0xde if letter != 0xd7 => letter + 0x20,
+                b'a'..=b'z' | 0xdf..=0xff if letter != 0xf7 => letter,
+                _ => return Err(ParseWordError::NotALetter(letter as char)),
+            };
+            if letters < 5 {
+                word[letters] = letter;
+            }
+            letters += 1;
+            i += width;
+        }
+        if letters != 5 {
+            return Err(ParseWordError::Length(letters));
+        }
+        Ok(Self(word))
+    }
+
+    /// A word from already-encoded letters, as stored by [`Word::as_bytes`].
+    #[cfg(feature = "embedded-dictionary")]
+    pub(crate) const fn from_latin1(bytes: [u8; 5]) -> Self {
+        Self(bytes)
+    }
+
+    /// The Latin-1 code point of each letter.
+    pub fn as_bytes(&self) -> &[u8; 5] {
+        &self.0
+    }
+
+    pub fn letters(&self) -> impl Iterator<Item = char> + '_ {
+        self.0.iter().map(|&b| char::from(b))
+    }
+}
+
+impl FromStr for Word {
+    type Err = ParseWordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_utf8(s.trim().as_bytes())
+    }
+}
+
+impl TryFrom<&str> for Word {
+    type Error = ParseWordError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl PartialEq<str> for Word {
+    fn eq(&self, other: &str) -> bool {
+        self.letters().eq(other.chars())
+    }
+}
+
+impl PartialEq<&str> for Word {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl fmt::Display for Word {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.letters().try_for_each(|letter| f.write_char(letter))
+    }
+}
+
+impl fmt::Debug for Word {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Word(\"{self}\")")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Word {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Word {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = alloc::string::String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseWordError {
+    /// The input was not five letters long.
+    Length(usize),
+    /// The input contained characters outside Latin-1.
+    Unsupported,
+    /// The input contained something other than a letter, like a digit or punctuation.
+    NotALetter(char),
+}
+
+impl fmt::Display for ParseWordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length(n) => write!(f, "expected a five-letter word, got {n} letters"),
+            Self::Unsupported => f.write_str("words may only contain Latin-1 letters"),
+            Self::NotALetter(c) => write!(f, "{c:?} is not a letter"),
+        }
+    }
+}
+
+impl core::error::Error for ParseWordError {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::{ParseWordError, Word};
+
+    #[test]
+    fn round_trip() {
+        let word: Word = "crane".parse().unwrap();
+        assert_eq!(word, Word::new("crane"));
+        assert_eq!(word.to_string(), "crane");
+        assert_eq!(word, "crane");
+    }
+
+    #[test]
+    fn wrong_length() {
+        assert_eq!("cranes".parse::<Word>(), Err(ParseWordError::Length(6)));
+        assert_eq!("".parse::<Word>(), Err(ParseWordError::Length(0)));
+    }
+
+    #[test]
+    fn accented() {
+        let word = Word::new("größe");
+        assert_eq!(word.as_bytes(), &[b'g', b'r', 0xf6, 0xdf, b'e']);
+        assert_eq!(word.to_string(), "größe");
+        assert_eq!("niño".parse::<Word>(), Err(ParseWordError::Length(4)));
+    }
+
+    #[test]
+    fn normalized() {
+        assert_eq!("CRANE".parse(), Ok(Word::new("crane")));
+        assert_eq!("  Crane\n".parse(), Ok(Word::new("crane")));
+        assert_eq!(Word::new("ÄPFEL"), Word::new("äpfel"));
+    }
+
+    #[test]
+    fn not_letters() {
+        assert_eq!(
+            "cr4ne".parse::<Word>(),
+            Err(ParseWordError::NotALetter('4'))
+        );
+        assert_eq!(
+            "cr ne".parse::<Word>(),
+            Err(ParseWordError::NotALetter(' '))
+        );
+        assert_eq!(
+            "a×b÷c".parse::<Word>(),
+            Err(ParseWordError::NotALetter('×'))
+        );
+    }
+
+    #[test]
+    fn outside_latin1() {
+        assert_eq!("cr€ne".parse::<Word>(), Err(ParseWordError::Unsupported));
+        assert_eq!("вода!".parse::<Word>(), Err(ParseWordError::Unsupported));
+    }
+}